@@ -1,36 +1,70 @@
-use futures::StreamExt;
+use chrono::Local;
 use ratatui::{
+    crossterm::event::KeyEvent,
     layout::{Constraint, Layout, Rect},
-    style::Stylize,
+    style::{Modifier, Style, Stylize},
+    text::Line,
     widgets::Block,
     Frame,
 };
 use std::{
     io::{BufWriter, Write},
-    path::PathBuf,
     rc::Rc,
     sync::Arc,
+    time::Duration,
 };
 use tokio::spawn;
 
 use crate::{
-    client::Client,
+    archive::{
+        decompress_zip_entry, entry_extract_path, parse_zip_central_directory,
+        zip_central_directory_range, zip_entry_data_range, ArchiveEntry, ArchiveFormat,
+        ArchiveSource, EntryLocation, ZIP_LOCAL_HEADER_SIZE, ZIP_TAIL_SIZE,
+    },
+    bookmark::{Bookmark, Bookmarks},
+    client::{retry_item, run_bulk_operation, Client},
     color::ColorTheme,
+    command::{
+        complete_command, complete_go_to_path, find_objects_to_get, parse_command, resolve_path,
+        BookmarkAction, Command, GoToPathCompletion, SortKey,
+    },
+    compression::{Compression, Decompressed},
     config::Config,
     environment::Environment,
-    error::{AppError, Result},
+    error::{AppError, ErrorKind, Result},
     event::{
-        AppEventType, CompleteDownloadObjectResult, CompleteDownloadObjectsResult,
-        CompleteInitializeResult, CompleteLoadAllDownloadObjectListResult,
+        AppEventType, BulkOperation, CompleteDiffObjectsResult, CompleteDownloadObjectResult,
+        CompleteDownloadObjectsResult, CompleteExtractArchiveEntriesResult,
+        CompleteFollowPreviewObjectResult, CompleteInitializeResult,
+        CompleteLoadAllDownloadObjectListResult, CompleteLoadGoToPathSuggestionsResult,
         CompleteLoadObjectDetailResult, CompleteLoadObjectVersionsResult,
-        CompleteLoadObjectsResult, CompletePreviewObjectResult, CompleteReloadBucketsResult,
-        CompleteReloadObjectsResult, CompleteSaveObjectResult, Sender,
+        CompleteLoadObjectsResult, CompleteLoadPreviewRangeResult, CompleteOpenArchiveEntryResult,
+        CompletePreviewArchiveResult, CompletePreviewObjectResult, CompletePreviewTableResult,
+        CompleteReloadBucketsResult, CompleteReloadCredentialsResult, CompleteReloadObjectsResult,
+        CompleteSaveObjectResult, CompleteSwitchProfileResult, DiffSpec, DiffTarget,
+        FollowPreviewSpec, RequestError, RequestResult, Sender,
+    },
+    file::{copy_to_clipboard, create_binary_file, save_bulk_report, save_error_log},
+    format::format_size_byte,
+    handle_user_events, handle_user_events_with_default,
+    help::{build_short_help_spans, BuildShortHelpsItem},
+    history::{Location, NavigationHistory, RecentLocations},
+    keys::{key_sequence_to_string, KeyInput, PendingKeys, UserEvent, UserEventMapper},
+    object::{
+        AppObjects, BulkReport, ByteRange, DownloadObjectInfo, FileDetail, ObjectItem, ObjectKey,
+        RawObject,
     },
-    file::{copy_to_clipboard, create_binary_file, save_error_log},
-    keys::UserEventMapper,
-    object::{AppObjects, DownloadObjectInfo, FileDetail, ObjectItem, ObjectKey, RawObject},
     pages::page::{Page, PageStack},
-    widget::{Header, LoadingDialog, Status, StatusType},
+    table::{
+        parquet_metadata_range, parquet_row_groups_range, parse_parquet_metadata,
+        read_parquet_table, TableData, TableFormat, PARQUET_TAIL_SIZE,
+    },
+    util::extension_from_file_name,
+    widget::{
+        BucketListSortType, BulkReportDialog, BulkReportDialogState, CommandPalette,
+        CommandPaletteItem, CommandPaletteState, ConfirmDialog, ConfirmDialogState, Header,
+        InputDialog, InputDialogState, LoadingDialog, ObjectListSortType, Status, StatusType,
+    },
 };
 
 #[derive(Debug)]
@@ -59,6 +93,7 @@ impl AppContext {
 pub struct App<C: Client> {
     pub page_stack: PageStack,
     pub mapper: UserEventMapper,
+    pending_keys: PendingKeys,
     app_objects: AppObjects,
     client: Arc<C>,
     ctx: Rc<AppContext>,
@@ -68,6 +103,74 @@ pub struct App<C: Client> {
     is_loading: bool,
 
     clipboard: Option<(ObjectKey, ObjectItem)>,
+
+    retry_request: Option<AppEventType>,
+    reauth_dialog: Option<ReauthDialog>,
+    mfa_token_code_dialog: Option<InputDialogState>,
+    bulk_report_dialog: Option<BulkSummary>,
+    command_palette: Option<CommandPaletteState>,
+    command_line: Option<CommandLine>,
+    command_history: Vec<String>,
+    history: NavigationHistory,
+    // locations visited in this session, merged into the saved ones
+    recent_locations: RecentLocations,
+    initial_object: Option<InitialObject>,
+    // directories to open after the current one is loaded, the last one first
+    pending_dir_keys: Vec<ObjectKey>,
+    dual_pane: Option<DualPane>,
+}
+
+// object specified on the command line, opened after its directory and detail are loaded
+#[derive(Debug)]
+struct InitialObject {
+    object_key: ObjectKey,
+    version_id: Option<String>,
+    preview: bool,
+}
+
+impl InitialObject {
+    fn dir_key(&self) -> ObjectKey {
+        let mut key = self.object_key.clone();
+        key.object_path.pop();
+        key
+    }
+
+    fn name(&self) -> &str {
+        self.object_key
+            .object_path
+            .last()
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct ReauthDialog {
+    error_msg: String,
+    state: ConfirmDialogState,
+}
+
+#[derive(Debug, Default)]
+struct CommandLine {
+    state: InputDialogState,
+    // index of the history entry being shown
+    history_index: Option<usize>,
+    // candidates and the index of the current one while cycling through them
+    completion: Option<(Vec<String>, usize)>,
+}
+
+// `App::page_stack` is always the active pane, and this holds the inactive one
+#[derive(Debug)]
+struct DualPane {
+    page_stack: PageStack,
+    active_left: bool,
+}
+
+#[derive(Debug)]
+struct BulkSummary {
+    operation: BulkOperation,
+    report: BulkReport,
+    state: BulkReportDialogState,
 }
 
 impl<C: Client> App<C> {
@@ -77,16 +180,83 @@ impl<C: Client> App<C> {
             app_objects: AppObjects::default(),
             page_stack: PageStack::new(Rc::clone(&ctx), tx.clone()),
             mapper,
+            pending_keys: PendingKeys::default(),
             client: Arc::new(client),
             ctx,
             tx,
             notification: Notification::None,
             is_loading: true,
             clipboard: None,
+            retry_request: None,
+            reauth_dialog: None,
+            mfa_token_code_dialog: None,
+            bulk_report_dialog: None,
+            command_palette: None,
+            command_line: None,
+            command_history: Vec::new(),
+            history: NavigationHistory::default(),
+            recent_locations: RecentLocations::default(),
+            initial_object: None,
+            pending_dir_keys: Vec::new(),
+            dual_pane: None,
+        }
+    }
+
+    pub fn set_initial_object(
+        &mut self,
+        object_key: ObjectKey,
+        version_id: Option<String>,
+        preview: bool,
+    ) {
+        self.initial_object = Some(InitialObject {
+            object_key,
+            version_id,
+            preview,
+        });
+    }
+
+    pub fn resolve_key_event(&mut self, key_event: KeyEvent) -> Vec<KeyInput> {
+        let section = self.key_section();
+        let inputs = self.pending_keys.push(&self.mapper, section, key_event);
+        if !self.pending_keys.is_empty() {
+            let id = self.pending_keys.id();
+            let timeout = Duration::from_millis(self.ctx.config.ui.key_sequence_timeout_millis);
+            let tx = self.tx.clone();
+            spawn(async move {
+                tokio::time::sleep(timeout).await;
+                tx.send(AppEventType::KeySequenceTimeout(id));
+            });
+        }
+        inputs
+    }
+
+    pub fn key_sequence_timeout(&mut self, id: usize) -> Vec<KeyInput> {
+        if self.pending_keys.id() != id {
+            // more keys have been typed since
+            return Vec::new();
+        }
+        let section = self.key_section();
+        self.pending_keys.flush(&self.mapper, section)
+    }
+
+    // the key binding section of the focused dialog or page
+    fn key_section(&self) -> &'static str {
+        if self.is_showing_mfa_token_code_dialog() {
+            "input_dialog"
+        } else if self.is_showing_reauth_dialog() || self.is_showing_bulk_report_dialog() {
+            "select_dialog"
+        } else if self.is_showing_command_line() {
+            "command_line"
+        } else if self.is_showing_command_palette() {
+            "command_palette"
+        } else {
+            self.page_stack.current_page().key_section()
         }
     }
 
     pub fn initialize(&mut self, bucket: Option<String>, prefix: Option<String>) {
+        let request = AppEventType::Initialize(bucket.clone(), prefix.clone());
+
         let client = self.client.clone();
         let tx = self.tx.clone();
         spawn(async move {
@@ -94,12 +264,60 @@ impl<C: Client> App<C> {
                 Some(name) => client.load_bucket(&name).await,
                 None => client.load_all_buckets().await,
             };
-            let result = CompleteInitializeResult::new(buckets, prefix);
+            let result = CompleteInitializeResult::new(buckets, prefix)
+                .map_err(|e| RequestError::new(e, request));
             tx.send(AppEventType::CompleteInitialize(result));
         });
     }
 
-    pub fn complete_initialize(&mut self, result: Result<CompleteInitializeResult>) {
+    pub fn open_mfa_token_code_dialog(&mut self, bucket: Option<String>, prefix: Option<String>) {
+        self.retry_request = Some(AppEventType::Initialize(bucket, prefix));
+        self.mfa_token_code_dialog = Some(InputDialogState::default());
+        self.is_loading = false;
+    }
+
+    pub fn is_showing_mfa_token_code_dialog(&self) -> bool {
+        self.mfa_token_code_dialog.is_some()
+    }
+
+    pub fn handle_mfa_token_code_dialog_events(
+        &mut self,
+        user_events: Vec<UserEvent>,
+        key_event: KeyEvent,
+    ) {
+        let Some(state) = &mut self.mfa_token_code_dialog else {
+            return;
+        };
+        self.notification = Notification::None;
+        handle_user_events_with_default! { user_events =>
+            UserEvent::InputDialogClose => {
+                self.mfa_token_code_dialog = None;
+                self.retry_request = None;
+            }
+            UserEvent::InputDialogApply => {
+                let code = state.input().trim().to_string();
+                if !code.is_empty() {
+                    self.mfa_token_code_dialog = None;
+                    self.tx.send(AppEventType::InputMfaTokenCode(code));
+                }
+            }
+            => {
+                state.handle_key_event(key_event);
+            }
+        }
+    }
+
+    pub fn input_mfa_token_code(&mut self, code: String) {
+        self.client.set_mfa_token_code(code);
+        if let Some(request) = self.retry_request.take() {
+            // send the request that needed the MFA session again (the initialization at startup)
+            // loading state will be reset when the request is completed
+            self.tx.send(request);
+            self.is_loading = true;
+        }
+    }
+
+    pub fn complete_initialize(&mut self, result: RequestResult<CompleteInitializeResult>) {
         let object_path_prefix: Option<String> = match result {
             Ok(CompleteInitializeResult { buckets, prefix }) => {
                 self.app_objects.set_bucket_items(buckets);
@@ -116,7 +334,7 @@ impl<C: Client> App<C> {
                 prefix
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
                 self.is_loading = false;
                 return;
             }
@@ -148,12 +366,13 @@ impl<C: Client> App<C> {
         let tx = self.tx.clone();
         spawn(async move {
             let buckets = client.load_all_buckets().await;
-            let result = CompleteReloadBucketsResult::new(buckets);
+            let result = CompleteReloadBucketsResult::new(buckets)
+                .map_err(|e| RequestError::new(e, AppEventType::ReloadBuckets));
             tx.send(AppEventType::CompleteReloadBuckets(result));
         });
     }
 
-    pub fn complete_reload_buckets(&mut self, result: Result<CompleteReloadBucketsResult>) {
+    pub fn complete_reload_buckets(&mut self, result: RequestResult<CompleteReloadBucketsResult>) {
         // current bucket list page is popped inside complete_initialize
         self.complete_initialize(result.map(|r| r.into()));
     }
@@ -161,6 +380,7 @@ impl<C: Client> App<C> {
     pub fn bucket_list_move_down(&mut self, object_key: ObjectKey) {
         if let Some(current_object_items) = self.app_objects.get_object_items(&object_key) {
             // object list has been already loaded
+            self.visit_location(&object_key);
             let object_list_page = Page::of_object_list(
                 current_object_items,
                 object_key,
@@ -210,6 +430,7 @@ impl<C: Client> App<C> {
                 let object_key = object_list_page.current_selected_object_key();
                 if let Some(current_object_items) = self.app_objects.get_object_items(&object_key) {
                     // object list has been already loaded
+                    self.visit_location(&object_key);
                     let new_object_list_page = Page::of_object_list(
                         current_object_items,
                         object_key,
@@ -226,10 +447,15 @@ impl<C: Client> App<C> {
     }
 
     pub fn object_list_move_up(&mut self) {
-        if self.page_stack.len() == 1 && self.app_objects.get_bucket_items().len() == 1 {
+        // the first page is kept (e.g. opened with a single bucket)
+        if self.page_stack.len() == 1 {
             return;
         }
         self.page_stack.pop();
+        if let Page::ObjectList(page) = self.page_stack.current_page() {
+            let object_key = page.current_dir_object_key().clone();
+            self.visit_location(&object_key);
+        }
     }
 
     pub fn object_list_refresh(&mut self) {
@@ -248,6 +474,188 @@ impl<C: Client> App<C> {
         self.is_loading = true;
     }
 
+    pub fn load_go_to_path_suggestions(&mut self, input: String) {
+        let Some(current_dir) = self.page_stack.current_dir_object_key() else {
+            return;
+        };
+        match complete_go_to_path(&input, &current_dir.bucket_name, &self.app_objects) {
+            GoToPathCompletion::Candidates(suggestions) => {
+                self.set_go_to_path_suggestions(input, suggestions);
+            }
+            GoToPathCompletion::List {
+                dir,
+                bucket,
+                prefix,
+            } => {
+                let client = self.client.clone();
+                let tx = self.tx.clone();
+                spawn(async move {
+                    let names = client.load_dir_names(&bucket, &prefix).await;
+                    let result = CompleteLoadGoToPathSuggestionsResult::new(names, input, dir);
+                    tx.send(AppEventType::CompleteLoadGoToPathSuggestions(result));
+                });
+            }
+        }
+    }
+
+    pub fn complete_load_go_to_path_suggestions(
+        &mut self,
+        result: Result<CompleteLoadGoToPathSuggestionsResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadGoToPathSuggestionsResult { input, suggestions }) => {
+                self.set_go_to_path_suggestions(input, suggestions);
+            }
+            Err(e) => {
+                // suggestions are optional, so do not interrupt the input with the error
+                tracing::debug!("Failed to load go to path suggestions: {:?}", e);
+            }
+        }
+    }
+
+    fn set_go_to_path_suggestions(&mut self, input: String, suggestions: Vec<String>) {
+        if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+            page.set_go_to_path_suggestions(&input, suggestions);
+        }
+    }
+
+    fn visit_location(&mut self, object_key: &ObjectKey) {
+        let location = Location {
+            profile: self.client.profile(),
+            object_key: object_key.clone(),
+        };
+        self.history.visit(location.clone());
+        self.recent_locations.visit(location);
+    }
+
+    pub fn history_back(&mut self) {
+        if let Some(location) = self.history.back().cloned() {
+            self.open_location(location);
+        }
+    }
+
+    pub fn history_forward(&mut self) {
+        if let Some(location) = self.history.forward().cloned() {
+            self.open_location(location);
+        }
+    }
+
+    pub fn toggle_dual_pane(&mut self) {
+        if self.dual_pane.take().is_some() {
+            return;
+        }
+        // the new pane is opened on the right with the focus
+        let new_page_stack = PageStack::new(Rc::clone(&self.ctx), self.tx.clone());
+        let page_stack = std::mem::replace(&mut self.page_stack, new_page_stack);
+        self.open_pages_of(&page_stack);
+        self.dual_pane = Some(DualPane {
+            page_stack,
+            active_left: false,
+        });
+    }
+
+    // opens the same location as the pages in the active pane,
+    // the objects that have not been loaded are loaded again instead of being dropped
+    fn open_pages_of(&mut self, page_stack: &PageStack) {
+        let mut dir_keys = Vec::new();
+        let mut initial_object = None;
+        for page in page_stack.iter() {
+            match page {
+                Page::BucketList(_) => {
+                    let bucket_list_page = Page::of_bucket_list(
+                        self.app_objects.get_bucket_items(),
+                        Rc::clone(&self.ctx),
+                        self.tx.clone(),
+                    );
+                    self.page_stack.push(bucket_list_page);
+                }
+                Page::ObjectList(page) => {
+                    dir_keys.push(page.current_dir_object_key().clone());
+                }
+                Page::ObjectDetail(page) => {
+                    initial_object = Some(InitialObject {
+                        object_key: page.current_object_key().clone(),
+                        version_id: None,
+                        preview: false,
+                    });
+                }
+                Page::ObjectPreview(page) => {
+                    if let Some((object_key, version_id)) = page.previewing_object() {
+                        initial_object = Some(InitialObject {
+                            object_key: object_key.clone(),
+                            version_id: version_id.clone(),
+                            preview: true,
+                        });
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+        // popped from the root directory
+        dir_keys.reverse();
+        self.pending_dir_keys = dir_keys;
+        self.initial_object = initial_object;
+        self.open_pending_dirs();
+    }
+
+    pub fn switch_pane(&mut self) {
+        let Some(dual_pane) = &mut self.dual_pane else {
+            self.warn_notification("Dual pane is not open".to_string());
+            return;
+        };
+        std::mem::swap(&mut self.page_stack, &mut dual_pane.page_stack);
+        dual_pane.active_left = !dual_pane.active_left;
+
+        // the objects may have been changed while the pane was inactive
+        if let Page::ObjectList(_) = self.page_stack.current_page() {
+            self.object_list_refresh();
+        }
+    }
+
+    pub fn open_location(&mut self, location: Location) {
+        if location.profile == self.client.profile() {
+            self.go_to_path(location.object_key);
+        } else {
+            self.switch_profile(location.profile, Some(location.object_key));
+        }
+    }
+
+    pub fn open_recent_locations(&mut self) {
+        let recent_locations = match self.load_recent_locations() {
+            Ok(recent_locations) => recent_locations,
+            Err(e) => {
+                let e = AppError::msg(format!("Failed to load recent locations: {e}"));
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
+        };
+        if recent_locations.is_empty() {
+            let msg = "No recent locations".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let recent_location_list_page = Page::of_recent_location_list(
+            recent_locations.list().to_vec(),
+            Rc::clone(&self.ctx),
+            self.tx.clone(),
+        );
+        self.page_stack.push(recent_location_list_page);
+    }
+
+    pub fn save_recent_locations(&self) -> anyhow::Result<()> {
+        if self.recent_locations.is_empty() {
+            return Ok(());
+        }
+        self.load_recent_locations()?.save()
+    }
+
+    fn load_recent_locations(&self) -> anyhow::Result<RecentLocations> {
+        let mut recent_locations = RecentLocations::load()?;
+        recent_locations.merge(&self.recent_locations);
+        Ok(recent_locations)
+    }
+
     pub fn back_to_bucket_list(&mut self) {
         if self.app_objects.get_bucket_items().len() == 1 {
             return;
@@ -258,33 +666,102 @@ impl<C: Client> App<C> {
     pub fn load_objects(&self, current_object_key: ObjectKey) {
         let bucket = current_object_key.bucket_name.clone();
         let prefix = current_object_key.joined_object_path(false);
+        let request = AppEventType::LoadObjects(current_object_key.clone());
 
         let client = self.client.clone();
         let tx = self.tx.clone();
         spawn(async move {
             let items = client.load_objects(&bucket, &prefix).await;
-            let result = CompleteLoadObjectsResult::new(items, current_object_key);
+            let result = CompleteLoadObjectsResult::new(items, current_object_key)
+                .map_err(|e| RequestError::new(e, request));
             tx.send(AppEventType::CompleteLoadObjects(result));
         });
     }
 
-    pub fn complete_load_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+    pub fn complete_load_objects(&mut self, result: RequestResult<CompleteLoadObjectsResult>) {
         match result {
             Ok(CompleteLoadObjectsResult { items, object_key }) => {
                 self.app_objects
                     .set_object_items(object_key.clone(), items.clone());
+                self.visit_location(&object_key);
+
+                let initial_object = self
+                    .initial_object
+                    .take_if(|initial| initial.dir_key() == object_key);
 
                 let object_list_page =
                     Page::of_object_list(items, object_key, Rc::clone(&self.ctx), self.tx.clone());
                 self.page_stack.push(object_list_page);
+
+                if !self.pending_dir_keys.is_empty() {
+                    self.is_loading = false;
+                    self.open_pending_dirs();
+                    return;
+                }
+                if let Some(initial) = initial_object {
+                    self.is_loading = false;
+                    self.open_initial_object(initial);
+                    return;
+                }
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.pending_dir_keys.clear();
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         }
         self.is_loading = false;
     }
 
+    // opens the directories in order, loading the objects that have not been loaded,
+    // and then the initial object in the last one
+    fn open_pending_dirs(&mut self) {
+        while let Some(object_key) = self.pending_dir_keys.pop() {
+            let Some(items) = self.app_objects.get_object_items(&object_key) else {
+                self.tx.send(AppEventType::LoadObjects(object_key));
+                self.is_loading = true;
+                return;
+            };
+            let object_list_page =
+                Page::of_object_list(items, object_key, Rc::clone(&self.ctx), self.tx.clone());
+            self.page_stack.push(object_list_page);
+        }
+        if let Some(initial) = self.initial_object.take() {
+            self.open_initial_object(initial);
+        }
+    }
+
+    fn open_initial_object(&mut self, initial: InitialObject) {
+        let object_list_page = self.page_stack.current_page_mut().as_mut_object_list();
+        if !object_list_page.select_item(initial.name()) {
+            let uri = format!(
+                "s3://{}/{}",
+                initial.object_key.bucket_name,
+                initial.object_key.joined_object_path(true)
+            );
+            self.tx
+                .send(AppEventType::NotifyWarn(format!("Object not found: {uri}")));
+            return;
+        }
+        if let ObjectItem::File { .. } = object_list_page.current_selected_item() {
+            // the detail is opened next, and then the preview or the version
+            self.initial_object = Some(initial);
+        }
+        // the target without the trailing slash may be a directory
+        self.object_list_move_down();
+
+        // the detail is opened without loading if it has been already loaded
+        if let Page::ObjectDetail(page) = self.page_stack.current_page() {
+            let object_key = page.current_object_key();
+            let initial_object = self
+                .initial_object
+                .take_if(|initial| initial.object_key == *object_key);
+            let detail = self.app_objects.get_object_detail(object_key).cloned();
+            if let (Some(initial), Some(detail)) = (initial_object, detail) {
+                self.open_initial_object_detail(initial, detail);
+            }
+        }
+    }
+
     pub fn reload_objects(&self) {
         let object_list_page = self.page_stack.current_page().as_object_list();
         let object_key = object_list_page.current_dir_object_key().clone();
@@ -295,13 +772,17 @@ impl<C: Client> App<C> {
         let tx = self.tx.clone();
         spawn(async move {
             let items = client.load_objects(&bucket, &prefix).await;
-            let result = CompleteReloadObjectsResult::new(items, object_key);
+            let result = CompleteReloadObjectsResult::new(items, object_key)
+                .map_err(|e| RequestError::new(e, AppEventType::ReloadObjects));
             tx.send(AppEventType::CompleteReloadObjects(result));
         });
     }
 
-    pub fn complete_reload_objects(&mut self, result: Result<CompleteReloadObjectsResult>) {
-        self.page_stack.pop();
+    pub fn complete_reload_objects(&mut self, result: RequestResult<CompleteReloadObjectsResult>) {
+        // keep the current page on error so that the request can be retried
+        if result.is_ok() {
+            self.page_stack.pop();
+        }
         self.complete_load_objects(result.map(|r| r.into()));
     }
 
@@ -319,37 +800,63 @@ impl<C: Client> App<C> {
             let tx = self.tx.clone();
             spawn(async move {
                 let detail = client.load_object_detail(&bucket, &key, &name).await;
-                let result = CompleteLoadObjectDetailResult::new(detail, map_key);
+                let result = CompleteLoadObjectDetailResult::new(detail, map_key)
+                    .map_err(|e| RequestError::new(e, AppEventType::LoadObjectDetail));
                 tx.send(AppEventType::CompleteLoadObjectDetail(result));
             });
         }
     }
 
-    pub fn complete_load_object_detail(&mut self, result: Result<CompleteLoadObjectDetailResult>) {
+    pub fn complete_load_object_detail(
+        &mut self,
+        result: RequestResult<CompleteLoadObjectDetailResult>,
+    ) {
         match result {
             Ok(CompleteLoadObjectDetailResult { detail, map_key }) => {
                 self.app_objects
                     .set_object_detail(map_key.clone(), *detail.clone());
 
+                let initial_object = self
+                    .initial_object
+                    .take_if(|initial| initial.object_key == map_key);
+
                 let object_page = self.page_stack.current_page().as_object_list();
 
                 let object_detail_page = Page::of_object_detail(
                     *detail.clone(),
                     object_page.object_list(),
-                    map_key,
+                    map_key.clone(),
                     object_page.list_state(),
                     Rc::clone(&self.ctx),
                     self.tx.clone(),
                 );
                 self.page_stack.push(object_detail_page);
+
+                if let Some(initial) = initial_object {
+                    self.open_initial_object_detail(initial, *detail);
+                }
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         }
         self.is_loading = false;
     }
 
+    fn open_initial_object_detail(&mut self, initial: InitialObject, detail: FileDetail) {
+        if initial.preview {
+            self.tx.send(AppEventType::OpenPreview(
+                initial.object_key,
+                detail,
+                initial.version_id,
+            ));
+        } else if initial.version_id.is_some() {
+            // select the version after the versions are loaded
+            self.initial_object = Some(initial);
+            self.tx.send(AppEventType::OpenObjectVersionsTab);
+        }
+    }
+
     pub fn open_object_versions_tab(&mut self) {
         let object_detail_page = self.page_stack.current_page().as_object_detail();
 
@@ -359,7 +866,8 @@ impl<C: Client> App<C> {
         if let Some(versions) = versions {
             // object versions has been already loaded
             let result =
-                CompleteLoadObjectVersionsResult::new(Ok(versions.clone()), current_object_key);
+                CompleteLoadObjectVersionsResult::new(Ok(versions.clone()), current_object_key)
+                    .map_err(|e| RequestError::new(e, AppEventType::LoadObjectVersions));
             self.tx
                 .send(AppEventType::CompleteLoadObjectVersions(result));
         } else {
@@ -379,26 +887,38 @@ impl<C: Client> App<C> {
         let tx = self.tx.clone();
         spawn(async move {
             let versions = client.load_object_versions(&bucket, &key).await;
-            let result = CompleteLoadObjectVersionsResult::new(versions, map_key);
+            let result = CompleteLoadObjectVersionsResult::new(versions, map_key)
+                .map_err(|e| RequestError::new(e, AppEventType::LoadObjectVersions));
             tx.send(AppEventType::CompleteLoadObjectVersions(result));
         });
     }
 
     pub fn complete_load_object_versions(
         &mut self,
-        result: Result<CompleteLoadObjectVersionsResult>,
+        result: RequestResult<CompleteLoadObjectVersionsResult>,
     ) {
         match result {
             Ok(CompleteLoadObjectVersionsResult { versions, map_key }) => {
+                let initial_object = self
+                    .initial_object
+                    .take_if(|initial| initial.object_key == map_key);
+
                 self.app_objects
                     .set_object_versions(map_key, versions.clone());
 
                 let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
                 object_detail_page.set_versions(versions);
                 object_detail_page.select_versions_tab();
+
+                if let Some(version_id) = initial_object.and_then(|initial| initial.version_id) {
+                    if !object_detail_page.select_version(&version_id) {
+                        let msg = format!("Version not found: {version_id}");
+                        self.tx.send(AppEventType::NotifyWarn(msg));
+                    }
+                }
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         }
         self.is_loading = false;
@@ -413,19 +933,21 @@ impl<C: Client> App<C> {
     pub fn load_all_download_objects(&self, key: ObjectKey, download_as: bool) {
         let bucket = key.bucket_name.clone();
         let prefix = key.joined_object_path(false);
+        let request = AppEventType::LoadAllDownloadObjectList(key, download_as);
 
         let client = self.client.clone();
         let tx = self.tx.clone();
         spawn(async move {
             let objects = client.list_all_download_objects(&bucket, &prefix).await;
-            let result = CompleteLoadAllDownloadObjectListResult::new(objects, download_as);
+            let result = CompleteLoadAllDownloadObjectListResult::new(objects, download_as)
+                .map_err(|e| RequestError::new(e, request));
             tx.send(AppEventType::CompleteLoadAllDownloadObjectList(result));
         });
     }
 
     pub fn complete_load_all_download_objects(
         &mut self,
-        result: Result<CompleteLoadAllDownloadObjectListResult>,
+        result: RequestResult<CompleteLoadAllDownloadObjectListResult>,
     ) {
         match result {
             Ok(CompleteLoadAllDownloadObjectListResult { objs, download_as }) => {
@@ -440,7 +962,7 @@ impl<C: Client> App<C> {
                 }
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         }
         self.is_loading = false;
@@ -473,6 +995,53 @@ impl<C: Client> App<C> {
         self.is_loading = true;
     }
 
+    pub fn open_diff(&mut self, spec: DiffSpec) {
+        self.tx.send(AppEventType::DiffObjects(spec));
+        self.is_loading = true;
+    }
+
+    pub fn diff_objects(&self, spec: DiffSpec) {
+        let limit = self.ctx.config.preview.max_size_byte;
+        let request = AppEventType::DiffObjects(spec.clone());
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let truncated = limit > 0 && (spec.old.size_byte > limit || spec.new.size_byte > limit);
+            let old = load_diff_object(&*client, &spec.old, limit).await;
+            let new = load_diff_object(&*client, &spec.new, limit).await;
+            let result = CompleteDiffObjectsResult::new(spec, old, new, truncated)
+                .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompleteDiffObjects(result));
+        });
+    }
+
+    pub fn complete_diff_objects(&mut self, result: RequestResult<CompleteDiffObjectsResult>) {
+        match result {
+            Ok(CompleteDiffObjectsResult {
+                spec,
+                old,
+                new,
+                truncated,
+            }) => {
+                if truncated {
+                    let msg = format!(
+                        "Objects are too large, comparing only the first {}",
+                        format_size_byte(self.ctx.config.preview.max_size_byte)
+                    );
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                let page =
+                    Page::of_object_diff(spec, old, new, Rc::clone(&self.ctx), self.tx.clone());
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
     pub fn start_download_object(
         &mut self,
         object_key: ObjectKey,
@@ -501,6 +1070,8 @@ impl<C: Client> App<C> {
 
         let path = self.ctx.config.download_file_path(&object_name);
         let writer = create_binary_file(&path);
+        let request =
+            AppEventType::DownloadObject(object_key, object_name, size_byte, version_id.clone());
 
         let client = self.client.clone();
         let tx = self.tx.clone();
@@ -512,10 +1083,12 @@ impl<C: Client> App<C> {
                     let result = client
                         .download_object(&bucket, &key, version_id, &mut writer, loading)
                         .await;
-                    let result = CompleteDownloadObjectResult::new(result, path);
+                    let result = CompleteDownloadObjectResult::new(result, path)
+                        .map_err(|e| RequestError::new(e, request));
                     tx.send(AppEventType::CompleteDownloadObject(result));
                 }
                 Err(e) => {
+                    let e = RequestError::new(e, request);
                     tx.send(AppEventType::CompleteDownloadObject(Err(e)));
                 }
             }
@@ -547,6 +1120,8 @@ impl<C: Client> App<C> {
 
         let path = self.ctx.config.download_file_path(&input);
         let writer = create_binary_file(&path);
+        let request =
+            AppEventType::DownloadObjectAs(object_key, size_byte, input, version_id.clone());
 
         let client = self.client.clone();
         let tx = self.tx.clone();
@@ -558,17 +1133,22 @@ impl<C: Client> App<C> {
                     let result = client
                         .download_object(&bucket, &key, version_id, &mut writer, loading)
                         .await;
-                    let result = CompleteDownloadObjectResult::new(result, path);
+                    let result = CompleteDownloadObjectResult::new(result, path)
+                        .map_err(|e| RequestError::new(e, request));
                     tx.send(AppEventType::CompleteDownloadObject(result));
                 }
                 Err(e) => {
+                    let e = RequestError::new(e, request);
                     tx.send(AppEventType::CompleteDownloadObject(Err(e)));
                 }
             }
         });
     }
 
-    pub fn complete_download_object(&mut self, result: Result<CompleteDownloadObjectResult>) {
+    pub fn complete_download_object(
+        &mut self,
+        result: RequestResult<CompleteDownloadObjectResult>,
+    ) {
         match result {
             Ok(CompleteDownloadObjectResult { path }) => {
                 let msg = format!(
@@ -578,7 +1158,7 @@ impl<C: Client> App<C> {
                 self.tx.send(AppEventType::NotifySuccess(msg));
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         }
         self.is_loading = false;
@@ -594,76 +1174,81 @@ impl<C: Client> App<C> {
         self.is_loading = true;
 
         let current_selected_dir_key = key.joined_object_path(false);
-        let mut obj_paths = Vec::with_capacity(objs.len());
-        for obj in objs {
-            let relative_path =
-                PathBuf::from(&dir).join(obj.key.strip_prefix(&current_selected_dir_key).unwrap());
-            let absolute_path = self.ctx.config.download_file_path(relative_path);
-            obj_paths.push((obj, absolute_path));
-        }
         let download_dir = self.ctx.config.download_file_path(&dir);
 
-        let total_count = obj_paths.len();
-        let total_size: usize = obj_paths.iter().map(|(obj, _)| obj.size_byte).sum();
+        let total_count = objs.len();
+        let total_size: usize = objs.iter().map(|obj| obj.size_byte).sum();
         let decimal_places = if total_size > 1_000_000_000 { 1 } else { 0 };
         let format_opt =
             humansize::FormatSizeOptions::from(humansize::DECIMAL).decimal_places(decimal_places);
         let total_size_s = humansize::format_size_i(total_size, format_opt);
 
         let max_concurrent_requests = self.ctx.config.max_concurrent_requests;
+        let item_max_attempts = self.ctx.config.request.item_max_attempts;
+
+        let operation = BulkOperation::Download {
+            bucket: bucket.clone(),
+            key,
+            dir,
+            download_dir: download_dir.clone(),
+        };
 
         let client = self.client.clone();
         let tx = self.tx.clone();
 
         spawn(async move {
-            let mut iter = futures::stream::iter(obj_paths)
-                .map(|(obj, path)| {
-                    let bucket = bucket.clone();
-                    let client = client.clone();
-                    async move {
-                        let mut writer = create_binary_file(path)?;
+            let download = |obj: DownloadObjectInfo| {
+                let bucket = bucket.clone();
+                let client = client.clone();
+                let relative_path = obj.key.strip_prefix(&current_selected_dir_key).unwrap();
+                let path = download_dir.join(relative_path);
+                async move {
+                    let result = retry_item(item_max_attempts, || async {
+                        // recreate the file so that a retry does not append to partial content
+                        let mut writer = create_binary_file(&path)?;
                         client
                             .download_object(&bucket, &obj.key, None, &mut writer, |_| {})
-                            .await?;
-                        Ok(obj.size_byte)
-                    }
-                })
-                .buffered(max_concurrent_requests);
-
-            let mut cur_count = 0;
-            let mut cur_size = 0;
-            while let Some(result) = iter.next().await {
-                match result {
-                    Ok(size) => {
-                        cur_count += 1;
-                        cur_size += size;
-
-                        let cur_size_s = humansize::format_size_i(cur_size, format_opt);
-                        let msg = format!(
-                            "{cur_count}/{total_count} objects downloaded ({cur_size_s} out of {total_size_s} total)"
-                        );
-                        tx.send(AppEventType::NotifyInfo(msg));
-                    }
-                    Err(e) => {
-                        tx.send(AppEventType::CompleteDownloadObjects(Err(e)));
-                        return;
+                            .await
+                    })
+                    .await;
+                    if result.is_err() {
+                        // do not leave a partially written file
+                        let _ = std::fs::remove_file(&path);
                     }
+                    result
                 }
-            }
+            };
+            let progress = |report: &BulkReport| {
+                let cur_count = report.processed_count();
+                let cur_size_s = humansize::format_size_i(report.succeeded_size_byte, format_opt);
+                let msg = format!(
+                    "{cur_count}/{total_count} objects processed ({cur_size_s} out of {total_size_s} total downloaded)"
+                );
+                tx.send(AppEventType::NotifyInfo(msg));
+            };
 
-            let result = CompleteDownloadObjectsResult::new(download_dir);
+            let report =
+                run_bulk_operation(objs, max_concurrent_requests, download, progress).await;
+
+            let result = CompleteDownloadObjectsResult::new(operation, report);
             tx.send(AppEventType::CompleteDownloadObjects(result));
         });
     }
 
     pub fn complete_download_objects(&mut self, result: Result<CompleteDownloadObjectsResult>) {
         match result {
-            Ok(CompleteDownloadObjectsResult { download_dir }) => {
-                let msg = format!(
-                    "Download completed successfully: {}",
-                    download_dir.to_string_lossy()
-                );
-                self.tx.send(AppEventType::NotifySuccess(msg));
+            Ok(CompleteDownloadObjectsResult { operation, report }) => {
+                if report.is_all_succeeded() {
+                    if let BulkOperation::Download { download_dir, .. } = operation {
+                        let msg = format!(
+                            "Download completed successfully: {}",
+                            download_dir.to_string_lossy()
+                        );
+                        self.tx.send(AppEventType::NotifySuccess(msg));
+                    }
+                } else {
+                    self.open_bulk_report_dialog(operation, report);
+                }
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
@@ -678,53 +1263,498 @@ impl<C: Client> App<C> {
         file_detail: FileDetail,
         version_id: Option<String>,
     ) {
+        if ArchiveFormat::from_file_name(&file_detail.name) == Some(ArchiveFormat::Zip) {
+            self.preview_zip_archive(object_key, file_detail, version_id);
+            return;
+        }
+        if TableFormat::from_file_name(&file_detail.name) == Some(TableFormat::Parquet) {
+            self.preview_parquet_table(object_key, file_detail, version_id);
+            return;
+        }
+
         let size_byte = file_detail.size_byte;
+        let range = self.preview_range(&file_detail);
+        let request = AppEventType::PreviewObject(
+            object_key.clone(),
+            file_detail.clone(),
+            version_id.clone(),
+        );
 
         let bucket = object_key.bucket_name.clone();
         let key = object_key.joined_object_path(true);
 
         let client = self.client.clone();
         let tx = self.tx.clone();
-        let loading = self.handle_loading_size(size_byte, tx.clone());
 
-        spawn(async move {
-            let mut bytes = Vec::with_capacity(size_byte);
-            let result = {
-                let mut writer = BufWriter::new(&mut bytes);
-                client
-                    .download_object(&bucket, &key, version_id.clone(), &mut writer, loading)
-                    .await
-            };
-            let obj = result.map(|_| RawObject { bytes });
-            let result = CompletePreviewObjectResult::new(obj, file_detail, version_id);
-            tx.send(AppEventType::CompletePreviewObject(result));
-        });
+        match range {
+            Some(range) => {
+                spawn(async move {
+                    let result = client
+                        .download_object_range(&bucket, &key, version_id.clone(), range)
+                        .await;
+                    let obj = result.map(|bytes| RawObject { bytes });
+                    let result = CompletePreviewObjectResult::new(
+                        object_key,
+                        obj,
+                        Some(range),
+                        file_detail,
+                        version_id,
+                    )
+                    .map_err(|e| RequestError::new(e, request));
+                    tx.send(AppEventType::CompletePreviewObject(result));
+                });
+            }
+            None => {
+                let loading = self.handle_loading_size(size_byte, tx.clone());
+                spawn(async move {
+                    let mut bytes = Vec::with_capacity(size_byte);
+                    let result = {
+                        let mut writer = BufWriter::new(&mut bytes);
+                        client
+                            .download_object(
+                                &bucket,
+                                &key,
+                                version_id.clone(),
+                                &mut writer,
+                                loading,
+                            )
+                            .await
+                    };
+                    let obj = result.map(|_| RawObject { bytes });
+                    let result = CompletePreviewObjectResult::new(
+                        object_key,
+                        obj,
+                        None,
+                        file_detail,
+                        version_id,
+                    )
+                    .map_err(|e| RequestError::new(e, request));
+                    tx.send(AppEventType::CompletePreviewObject(result));
+                });
+            }
+        }
     }
 
-    pub fn complete_preview_object(&mut self, result: Result<CompletePreviewObjectResult>) {
-        match result {
-            Ok(CompletePreviewObjectResult {
-                obj,
-                file_detail,
-                file_version_id,
-            }) => {
-                let object_preview_page = Page::of_object_preview(
-                    file_detail,
-                    file_version_id,
+    // list the entries by loading only the central directory, without loading the entire archive
+    fn preview_zip_archive(
+        &self,
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        version_id: Option<String>,
+    ) {
+        let bucket = object_key.bucket_name.clone();
+        let key = object_key.joined_object_path(true);
+        let size_byte = file_detail.size_byte;
+        let request = AppEventType::PreviewObject(
+            object_key.clone(),
+            file_detail.clone(),
+            version_id.clone(),
+        );
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let entries = async {
+                let tail_range = ByteRange::tail(size_byte, ZIP_TAIL_SIZE);
+                let tail = client
+                    .download_object_range(&bucket, &key, version_id.clone(), tail_range)
+                    .await?;
+                let range = zip_central_directory_range(&tail, tail_range.start)?;
+                if range.start >= tail_range.start && range.end <= tail_range.end {
+                    let start = range.start - tail_range.start;
+                    let end = range.end - tail_range.start;
+                    return parse_zip_central_directory(&tail[start..end]);
+                }
+                let central_directory = client
+                    .download_object_range(&bucket, &key, version_id.clone(), range)
+                    .await?;
+                parse_zip_central_directory(&central_directory)
+            }
+            .await;
+            let result =
+                CompletePreviewArchiveResult::new(object_key, entries, file_detail, version_id)
+                    .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompletePreviewArchive(result));
+        });
+    }
+
+    pub fn complete_preview_archive(
+        &mut self,
+        result: RequestResult<CompletePreviewArchiveResult>,
+    ) {
+        match result {
+            Ok(CompletePreviewArchiveResult {
+                object_key,
+                entries,
+                file_detail,
+                file_version_id,
+            }) => {
+                let page = Page::of_archive_preview(
+                    object_key,
+                    file_detail,
+                    file_version_id,
+                    entries,
+                    Rc::clone(&self.ctx),
+                    self.tx.clone(),
+                );
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        };
+        self.clear_notification();
+        self.is_loading = false;
+    }
+
+    fn preview_parquet_table(
+        &self,
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        version_id: Option<String>,
+    ) {
+        let size_byte = file_detail.size_byte;
+        let max_rows = self.ctx.config.preview.max_table_rows;
+        let request = AppEventType::PreviewObject(
+            object_key.clone(),
+            file_detail.clone(),
+            version_id.clone(),
+        );
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let data = read_parquet_object(
+                &*client,
+                &object_key,
+                version_id.clone(),
+                size_byte,
+                max_rows,
+            )
+            .await;
+            let result = CompletePreviewTableResult::new(object_key, data, file_detail, version_id)
+                .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompletePreviewTable(result));
+        });
+    }
+
+    pub fn complete_preview_table(&mut self, result: RequestResult<CompletePreviewTableResult>) {
+        match result {
+            Ok(CompletePreviewTableResult {
+                object_key,
+                data,
+                file_detail,
+                file_version_id,
+            }) => {
+                let page = Page::of_table_preview(
+                    object_key,
+                    file_detail,
+                    file_version_id,
+                    data,
+                    Rc::clone(&self.ctx),
+                    self.tx.clone(),
+                );
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        };
+        self.clear_notification();
+        self.is_loading = false;
+    }
+
+    pub fn open_archive_entry(
+        &mut self,
+        object_key: ObjectKey,
+        source: ArchiveSource,
+        entry: ArchiveEntry,
+    ) {
+        self.is_loading = true;
+
+        let limit = self.ctx.config.preview.max_decompressed_size_byte;
+        let request =
+            AppEventType::OpenArchiveEntry(object_key.clone(), source.clone(), entry.clone());
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let data = read_archive_entry(&*client, &object_key, &source, &entry, limit).await;
+            let result = CompleteOpenArchiveEntryResult::new(object_key, entry, data)
+                .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompleteOpenArchiveEntry(result));
+        });
+    }
+
+    pub fn complete_open_archive_entry(
+        &mut self,
+        result: RequestResult<CompleteOpenArchiveEntryResult>,
+    ) {
+        match result {
+            Ok(CompleteOpenArchiveEntryResult {
+                object_key,
+                entry,
+                data,
+            }) => {
+                if data.truncated {
+                    let msg = format!(
+                        "Entry is too large, showing only the first {}",
+                        format_size_byte(data.bytes.len())
+                    );
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                let file_detail = FileDetail {
+                    name: entry.name().to_string(),
+                    size_byte: entry.size_byte,
+                    last_modified: entry.last_modified.unwrap_or_default(),
+                    key: entry.path,
+                    ..Default::default()
+                };
+                let object = RawObject { bytes: data.bytes };
+                let page = Page::of_archive_entry_preview(
+                    object_key,
+                    file_detail,
+                    object,
+                    Rc::clone(&self.ctx),
+                    self.tx.clone(),
+                );
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn extract_archive_entries(
+        &mut self,
+        object_key: ObjectKey,
+        source: ArchiveSource,
+        entries: Vec<ArchiveEntry>,
+    ) {
+        self.is_loading = true;
+
+        let dir = self.ctx.config.download_file_path("");
+        let request = AppEventType::ExtractArchiveEntries(
+            object_key.clone(),
+            source.clone(),
+            entries.clone(),
+        );
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let count = async {
+                let mut count = 0;
+                for entry in entries.iter().filter(|e| !e.is_dir) {
+                    let Some(path) = entry_extract_path(&entry.path) else {
+                        let msg = format!("Invalid entry path: {}", entry.path);
+                        return Err(AppError::msg(msg));
+                    };
+                    let data = read_archive_entry(&*client, &object_key, &source, entry, 0).await?;
+                    let mut writer = create_binary_file(dir.join(path))?;
+                    writer
+                        .write_all(&data.bytes)
+                        .map_err(|e| AppError::new("Failed to write file", e))?;
+                    count += 1;
+                }
+                Ok(count)
+            }
+            .await;
+            let result = CompleteExtractArchiveEntriesResult::new(count, dir)
+                .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompleteExtractArchiveEntries(result));
+        });
+    }
+
+    pub fn complete_extract_archive_entries(
+        &mut self,
+        result: RequestResult<CompleteExtractArchiveEntriesResult>,
+    ) {
+        match result {
+            Ok(CompleteExtractArchiveEntriesResult { count, dir }) => {
+                let msg = format!(
+                    "Extracted {} entries successfully: {}",
+                    count,
+                    dir.to_string_lossy()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    // returns the range to load if the object is too large to preview entirely
+    fn preview_range(&self, file_detail: &FileDetail) -> Option<ByteRange> {
+        let config = &self.ctx.config.preview;
+        let size_byte = file_detail.size_byte;
+        if config.max_size_byte == 0 || size_byte <= config.max_size_byte {
+            return None;
+        }
+        // compressed data can be decompressed only from the beginning
+        let compressed = Compression::from_extension(&file_detail.name).is_some()
+            || file_detail
+                .content_encoding
+                .as_deref()
+                .and_then(Compression::from_content_encoding)
+                .is_some();
+        let extension = extension_from_file_name(&file_detail.name);
+        if !compressed && config.tail_extensions.contains(&extension) {
+            Some(ByteRange::tail(size_byte, config.max_size_byte))
+        } else {
+            Some(ByteRange::head(size_byte, config.max_size_byte))
+        }
+    }
+
+    pub fn complete_preview_object(&mut self, result: RequestResult<CompletePreviewObjectResult>) {
+        match result {
+            Ok(CompletePreviewObjectResult {
+                object_key,
+                obj,
+                range,
+                file_detail,
+                file_version_id,
+            }) => {
+                let object_preview_page = Page::of_object_preview(
+                    object_key,
+                    file_detail,
+                    file_version_id,
                     obj,
+                    range,
                     Rc::clone(&self.ctx),
                     self.tx.clone(),
                 );
                 self.page_stack.push(object_preview_page);
             }
             Err(e) => {
-                self.tx.send(AppEventType::NotifyError(e));
+                self.tx.send(AppEventType::NotifyRequestError(e));
             }
         };
         self.clear_notification();
         self.is_loading = false;
     }
 
+    pub fn load_preview_range(
+        &mut self,
+        object_key: ObjectKey,
+        version_id: Option<String>,
+        range: ByteRange,
+    ) {
+        self.is_loading = true;
+
+        let bucket = object_key.bucket_name.clone();
+        let key = object_key.joined_object_path(true);
+        let request = AppEventType::LoadPreviewRange(object_key.clone(), version_id.clone(), range);
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            let result = client
+                .download_object_range(&bucket, &key, version_id.clone(), range)
+                .await;
+            let result = CompleteLoadPreviewRangeResult::new(result, object_key, version_id, range)
+                .map_err(|e| RequestError::new(e, request));
+            tx.send(AppEventType::CompleteLoadPreviewRange(result));
+        });
+    }
+
+    pub fn complete_load_preview_range(
+        &mut self,
+        result: RequestResult<CompleteLoadPreviewRangeResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadPreviewRangeResult {
+                object_key,
+                version_id,
+                range,
+                bytes,
+            }) => {
+                // the preview may have been switched to another object while loading
+                if let Page::ObjectPreview(page) = self.page_stack.current_page_mut() {
+                    if page.is_previewing(&object_key, &version_id) {
+                        page.apply_range(range, bytes);
+                    }
+                }
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn follow_preview_object(&self, spec: FollowPreviewSpec) {
+        let FollowPreviewSpec {
+            id,
+            object_key,
+            file_detail,
+            loaded_range,
+        } = spec;
+        let bucket = object_key.bucket_name.clone();
+        let key = object_key.joined_object_path(true);
+        let interval = Duration::from_secs(self.ctx.config.preview.follow_interval_secs);
+        let max_size_byte = self.ctx.config.preview.max_size_byte;
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        spawn(async move {
+            tokio::time::sleep(interval).await;
+            let current = client
+                .load_object_detail(&bucket, &key, &file_detail.name)
+                .await;
+            let result = match current {
+                Ok(current)
+                    if current.e_tag != file_detail.e_tag
+                        || current.size_byte != file_detail.size_byte =>
+                {
+                    let range = loaded_range.follow(current.size_byte, max_size_byte);
+                    let bytes = client
+                        .download_object_range(&bucket, &key, None, range)
+                        .await;
+                    CompleteFollowPreviewObjectResult::new(id, Ok(current), Some(range), bytes)
+                }
+                current => {
+                    CompleteFollowPreviewObjectResult::new(id, current, None, Ok(Vec::new()))
+                }
+            };
+            tx.send(AppEventType::CompleteFollowPreviewObject(result));
+        });
+    }
+
+    pub fn complete_follow_preview_object(
+        &mut self,
+        result: Result<CompleteFollowPreviewObjectResult>,
+    ) {
+        match result {
+            Ok(result) => {
+                // the preview page may be covered by another page such as help
+                let page = self.page_stack.iter_mut().find_map(|page| match page {
+                    Page::ObjectPreview(page) if page.is_following(result.id) => Some(page),
+                    _ => None,
+                });
+                if let Some(page) = page {
+                    page.complete_follow(result.file_detail, result.range, result.bytes);
+                }
+            }
+            Err(e) => {
+                for page in self.page_stack.iter_mut() {
+                    if let Page::ObjectPreview(page) = page {
+                        page.stop_follow();
+                    }
+                }
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
     pub fn start_save_object(&mut self, name: String, obj: Arc<RawObject>) {
         self.tx.send(AppEventType::SaveObject(name, obj));
         self.is_loading = true;
@@ -837,33 +1867,82 @@ impl<C: Client> App<C> {
         self.success_notification(msg);
     }
 
-    pub fn start_paste_object(&mut self, dest_dir_key: ObjectKey) {
-        let Some((src_key_base, item)) = &self.clipboard else {
+    pub fn start_diff_with_clipboard(&mut self, object_key: ObjectKey, object_item: ObjectItem) {
+        let Some((src_key, src_item)) = &self.clipboard else {
             self.warn_notification("Clipboard is empty".to_string());
             return;
         };
-
-        let (name, src_key, is_dir) = match item {
-            ObjectItem::Dir { name, key, .. } => (name.clone(), key.clone(), true),
-            ObjectItem::File { name, key, .. } => (name.clone(), key.clone(), false),
+        let (
+            ObjectItem::File {
+                key: old_key,
+                size_byte: old_size_byte,
+                ..
+            },
+            ObjectItem::File {
+                key: new_key,
+                size_byte: new_size_byte,
+                ..
+            },
+        ) = (src_item, &object_item)
+        else {
+            self.warn_notification("Only files can be compared".to_string());
+            return;
         };
+        if src_key == &object_key {
+            self.warn_notification("Cannot compare an object with itself".to_string());
+            return;
+        }
 
-        let src_bucket = src_key_base.bucket_name.clone();
-        let dst_bucket = dest_dir_key.bucket_name.clone();
+        let spec = DiffSpec {
+            old: DiffTarget {
+                object_key: src_key.clone(),
+                name: old_key.clone(),
+                size_byte: *old_size_byte,
+                version_id: None,
+            },
+            new: DiffTarget {
+                object_key,
+                name: new_key.clone(),
+                size_byte: *new_size_byte,
+                version_id: None,
+            },
+        };
+        self.open_diff(spec);
+    }
 
-        let mut dst_key = dest_dir_key.joined_object_path(false);
-        dst_key.push_str(&name);
-        if is_dir && !dst_key.ends_with('/') {
-            dst_key.push('/');
-        }
+    pub fn start_paste_object(&mut self, dest_dir_key: ObjectKey) {
+        let Some((src_key_base, item)) = &self.clipboard else {
+            self.warn_notification("Clipboard is empty".to_string());
+            return;
+        };
+        let spec = build_paste_spec(
+            src_key_base,
+            item,
+            &dest_dir_key,
+            crate::event::PasteMode::Copy,
+        );
+        self.tx.send(AppEventType::OpenPasteConfirmDialog(spec));
+    }
 
-        let spec = crate::event::PasteSpec {
-            src_bucket,
-            src_key,
-            dst_bucket,
-            dst_key,
-            name,
+    pub fn start_paste_to_other_pane(
+        &mut self,
+        object_key: ObjectKey,
+        object_item: ObjectItem,
+        mode: crate::event::PasteMode,
+    ) {
+        let Some(dual_pane) = &self.dual_pane else {
+            self.warn_notification("Dual pane is not open".to_string());
+            return;
+        };
+        let Some(dest_dir_key) = dual_pane.page_stack.current_dir_object_key() else {
+            self.warn_notification("Other pane is not in a bucket".to_string());
+            return;
         };
+        if self.page_stack.current_dir_object_key() == Some(dest_dir_key) {
+            self.warn_notification("Other pane is in the same directory".to_string());
+            return;
+        }
+        let spec = build_paste_spec(&object_key, &object_item, dest_dir_key, mode);
         self.tx.send(AppEventType::OpenPasteConfirmDialog(spec));
     }
 
@@ -884,13 +1963,16 @@ impl<C: Client> App<C> {
 
     pub fn paste_object(&mut self, spec: crate::event::PasteSpec) {
         self.is_loading = true;
+        let request = AppEventType::PasteObject(spec.clone());
         let client = self.client.clone();
         let tx = self.tx.clone();
         let max_concurrent_requests = self.ctx.config.max_concurrent_requests;
         // show loading UI during copy
         // (note: caller should set is_loading; keep logic here simple)
         tokio::spawn(async move {
-            let result = if spec.src_key.ends_with('/') {
+            let result = if spec.mode == crate::event::PasteMode::Move {
+                move_object(&*client, &spec, max_concurrent_requests, &tx).await
+            } else if spec.src_key.ends_with('/') {
                 let progress_tx = tx.clone();
                 client
                     .copy_prefix(
@@ -905,6 +1987,7 @@ impl<C: Client> App<C> {
                         },
                     )
                     .await
+                    .map(Some)
             } else {
                 client
                     .copy_object(
@@ -914,22 +1997,570 @@ impl<C: Client> App<C> {
                         &spec.dst_key,
                     )
                     .await
+                    .map(|_| None)
             };
-            let result = crate::event::CompletePasteObjectResult::new(result, spec.name.clone());
+            let result = crate::event::CompletePasteObjectResult::new(result, spec)
+                .map_err(|e| RequestError::new(e, request));
             tx.send(AppEventType::CompletePasteObject(result));
         });
     }
 
+    pub fn paste_objects(&mut self, spec: crate::event::PasteSpec, objs: Vec<DownloadObjectInfo>) {
+        self.is_loading = true;
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+        let max_concurrent_requests = self.ctx.config.max_concurrent_requests;
+        tokio::spawn(async move {
+            let report = if spec.mode == crate::event::PasteMode::Move {
+                move_objects(&*client, &spec, objs, max_concurrent_requests, &tx).await
+            } else {
+                let progress_tx = tx.clone();
+                client
+                    .copy_objects(
+                        &spec.src_bucket,
+                        &spec.src_key,
+                        &spec.dst_bucket,
+                        &spec.dst_key,
+                        objs,
+                        max_concurrent_requests,
+                        move |cur, total| {
+                            let msg = format!("Copied {}/{} objects...", cur, total);
+                            progress_tx.send(AppEventType::NotifyInfo(msg));
+                        },
+                    )
+                    .await
+            };
+            let result = crate::event::CompletePasteObjectResult {
+                spec,
+                report: Some(report),
+            };
+            tx.send(AppEventType::CompletePasteObject(Ok(result)));
+        });
+    }
+
     pub fn complete_paste_object(
         &mut self,
-        result: Result<crate::event::CompletePasteObjectResult>,
+        result: RequestResult<crate::event::CompletePasteObjectResult>,
     ) {
         match result {
-            Ok(crate::event::CompletePasteObjectResult { name }) => {
-                let msg = format!("Copied '{name}' successfully");
-                self.success_notification(msg);
-                // Refresh current object list; loading state will be managed by the reload flow.
-                self.tx.send(AppEventType::ObjectListRefresh);
+            Ok(crate::event::CompletePasteObjectResult { spec, report }) => {
+                // the destination may be shown in the other pane, so it is loaded again when switched
+                self.app_objects.clear_object_items_under(&spec.dst_dir_key);
+                if spec.mode == crate::event::PasteMode::Move {
+                    self.app_objects.clear_object_items_under(&spec.src_dir_key);
+                }
+
+                match report {
+                    Some(report) if !report.is_all_succeeded() => {
+                        self.open_bulk_report_dialog(BulkOperation::Paste(spec), report);
+                    }
+                    _ => {
+                        let msg =
+                            format!("{} '{}' successfully", spec.mode.past_tense(), spec.name);
+                        self.success_notification(msg);
+                    }
+                }
+                // Refresh current object list; loading state will be managed by the reload flow.
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyRequestError(e));
+                self.is_loading = false;
+            }
+        }
+    }
+
+    pub fn is_showing_reauth_dialog(&self) -> bool {
+        self.reauth_dialog.is_some()
+    }
+
+    pub fn handle_reauth_dialog_events(&mut self, user_events: Vec<UserEvent>) {
+        let Some(dialog) = &mut self.reauth_dialog else {
+            return;
+        };
+        handle_user_events! { user_events =>
+            UserEvent::SelectDialogClose => {
+                self.cancel_reauth();
+            }
+            UserEvent::SelectDialogLeft | UserEvent::SelectDialogRight => {
+                dialog.state.toggle();
+            }
+            UserEvent::SelectDialogSelect => {
+                if dialog.state.is_ok() {
+                    self.reauth_dialog = None;
+                    self.tx.send(AppEventType::ReloadCredentials);
+                } else {
+                    self.cancel_reauth();
+                }
+            }
+        }
+    }
+
+    fn cancel_reauth(&mut self) {
+        if let Some(dialog) = self.reauth_dialog.take() {
+            self.notification = Notification::Error(dialog.error_msg);
+        }
+        self.retry_request = None;
+    }
+
+    pub fn reload_credentials(&mut self) {
+        self.is_loading = true;
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+        spawn(async move {
+            let result = client.reload_credentials().await;
+            let result = CompleteReloadCredentialsResult::new(result);
+            tx.send(AppEventType::CompleteReloadCredentials(result));
+        });
+    }
+
+    pub fn complete_reload_credentials(&mut self, result: Result<CompleteReloadCredentialsResult>) {
+        match result {
+            Ok(_) => {
+                if let Some(request) = self.retry_request.take() {
+                    // retry the failed request with the reloaded credentials
+                    // loading state will be reset when the request is completed
+                    self.tx.send(request);
+                    return;
+                }
+                let msg = "Credentials reloaded successfully".to_string();
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.retry_request = None;
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    fn open_bulk_report_dialog(&mut self, operation: BulkOperation, report: BulkReport) {
+        self.bulk_report_dialog = Some(BulkSummary {
+            operation,
+            report,
+            state: BulkReportDialogState::default(),
+        });
+    }
+
+    pub fn is_showing_bulk_report_dialog(&self) -> bool {
+        self.bulk_report_dialog.is_some()
+    }
+
+    pub fn handle_bulk_report_dialog_events(&mut self, user_events: Vec<UserEvent>) {
+        let Some(dialog) = &mut self.bulk_report_dialog else {
+            return;
+        };
+        self.notification = Notification::None;
+        handle_user_events! { user_events =>
+            UserEvent::SelectDialogClose => {
+                self.bulk_report_dialog = None;
+            }
+            UserEvent::SelectDialogLeft => {
+                dialog.state.select_prev();
+            }
+            UserEvent::SelectDialogRight => {
+                dialog.state.select_next();
+            }
+            UserEvent::SelectDialogSelect => {
+                if dialog.state.is_retry() {
+                    self.retry_bulk_operation();
+                } else if dialog.state.is_save_report() {
+                    self.save_bulk_report();
+                } else {
+                    self.bulk_report_dialog = None;
+                }
+            }
+        }
+    }
+
+    fn retry_bulk_operation(&mut self) {
+        let Some(dialog) = self.bulk_report_dialog.take() else {
+            return;
+        };
+        let request = dialog.operation.retry_event(dialog.report.retry_targets());
+        if dialog.report.credentials_expired {
+            // the failed objects will be retried after the credentials are reloaded
+            self.retry_request = Some(request);
+            self.tx.send(AppEventType::ReloadCredentials);
+        } else {
+            self.tx.send(request);
+        }
+    }
+
+    fn save_bulk_report(&mut self) {
+        let Some(dialog) = &self.bulk_report_dialog else {
+            return;
+        };
+        let name = format!("stu-report-{}.txt", Local::now().format("%Y%m%d-%H%M%S"));
+        let path = self.ctx.config.download_file_path(name);
+        let description = dialog.operation.description();
+        match save_bulk_report(&path, &description, &dialog.report) {
+            Ok(_) => {
+                let msg = format!("Report saved: {}", path.to_string_lossy());
+                self.success_notification(msg);
+            }
+            Err(e) => {
+                self.error_notification(e);
+            }
+        }
+    }
+
+    pub fn open_command_palette(&mut self) {
+        let items = self
+            .page_stack
+            .current_page()
+            .help_items()
+            .into_iter()
+            .map(|item| {
+                let keys = self
+                    .mapper
+                    .find_keys(item.event())
+                    .iter()
+                    .map(|key| format!("<{}>", key_sequence_to_string(key, true)))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                CommandPaletteItem {
+                    event: item.event(),
+                    description: item.description().into(),
+                    keys,
+                }
+            })
+            .collect();
+        self.command_palette = Some(CommandPaletteState::new(items));
+    }
+
+    pub fn is_showing_command_palette(&self) -> bool {
+        self.command_palette.is_some()
+    }
+
+    // Returns the event of the selected action if it should be executed
+    pub fn handle_command_palette_events(
+        &mut self,
+        user_events: Vec<UserEvent>,
+        key_event: KeyEvent,
+    ) -> Option<UserEvent> {
+        let state = self.command_palette.as_mut()?;
+        for user_event in &user_events {
+            match user_event {
+                UserEvent::CommandPaletteClose => {
+                    self.command_palette = None;
+                    return None;
+                }
+                UserEvent::CommandPaletteExecute => {
+                    return self.command_palette.take()?.selected_event();
+                }
+                UserEvent::CommandPaletteDown => {
+                    state.select_next();
+                    return None;
+                }
+                UserEvent::CommandPaletteUp => {
+                    state.select_prev();
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        state.handle_key_event(key_event);
+        None
+    }
+
+    pub fn open_command_line(&mut self) {
+        self.command_line = Some(CommandLine::default());
+    }
+
+    pub fn is_showing_command_line(&self) -> bool {
+        self.command_line.is_some()
+    }
+
+    pub fn handle_command_line_events(&mut self, user_events: Vec<UserEvent>, key_event: KeyEvent) {
+        let Some(command_line) = &mut self.command_line else {
+            return;
+        };
+        handle_user_events_with_default! { user_events =>
+            UserEvent::CommandLineClose => {
+                self.command_line = None;
+            }
+            UserEvent::CommandLineExecute => {
+                let input = command_line.state.input().trim().to_string();
+                self.command_line = None;
+                self.execute_command(input);
+            }
+            UserEvent::CommandLineComplete => {
+                self.complete_command_line();
+            }
+            UserEvent::CommandLinePrevHistory => {
+                self.select_command_history(true);
+            }
+            UserEvent::CommandLineNextHistory => {
+                self.select_command_history(false);
+            }
+            => {
+                command_line.state.handle_key_event(key_event);
+                command_line.completion = None;
+            }
+        }
+    }
+
+    fn complete_command_line(&mut self) {
+        let current_dir = self.page_stack.current_dir_object_key();
+        let Some(command_line) = &mut self.command_line else {
+            return;
+        };
+        if let Some((candidates, i)) = &mut command_line.completion {
+            *i = (*i + 1) % candidates.len();
+            command_line.state = InputDialogState::new(candidates[*i].clone());
+            return;
+        }
+
+        let input = command_line.state.input();
+        let candidates = complete_command(input, current_dir, &self.app_objects);
+        if let Some(first) = candidates.first() {
+            command_line.state = InputDialogState::new(first.clone());
+        }
+        if candidates.len() > 1 {
+            command_line.completion = Some((candidates, 0));
+        }
+    }
+
+    fn select_command_history(&mut self, prev: bool) {
+        let Some(command_line) = &mut self.command_line else {
+            return;
+        };
+        let len = self.command_history.len();
+        let index = match (command_line.history_index, prev) {
+            (None, true) if len > 0 => Some(len - 1),
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i + 1 < len => Some(i + 1),
+            _ => None,
+        };
+        let input = index
+            .map(|i| self.command_history[i].clone())
+            .unwrap_or_default();
+        command_line.state = InputDialogState::new(input);
+        command_line.history_index = index;
+        command_line.completion = None;
+    }
+
+    fn execute_command(&mut self, input: String) {
+        if input.is_empty() {
+            return;
+        }
+        if self.command_history.last() != Some(&input) {
+            self.command_history.push(input.clone());
+        }
+
+        let result = parse_command(&input).and_then(|command| self.run_command(command));
+        if let Err(msg) = result {
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        }
+    }
+
+    fn run_command(&mut self, command: Command) -> std::result::Result<(), String> {
+        let current_dir = self.page_stack.current_dir_object_key();
+        match command {
+            Command::Cd(path) => match resolve_path(&path, current_dir)? {
+                Some(object_key) => self.tx.send(AppEventType::GoToPath(object_key)),
+                None => self.tx.send(AppEventType::BackToBucketList),
+            },
+            Command::Get { pattern, dir } => {
+                let (object_key, objs) =
+                    find_objects_to_get(&pattern, current_dir, &self.app_objects)?;
+                let bucket = object_key.bucket_name.clone();
+                let dir = dir.unwrap_or_default();
+                self.tx
+                    .send(AppEventType::DownloadObjects(bucket, object_key, dir, objs));
+            }
+            Command::Sort { key, desc } => match self.page_stack.current_page_mut() {
+                Page::BucketList(page) => {
+                    let sort_type = match (key, desc) {
+                        (SortKey::Default, _) => BucketListSortType::Default,
+                        (SortKey::Name, false) => BucketListSortType::NameAsc,
+                        (SortKey::Name, true) => BucketListSortType::NameDesc,
+                        _ => return Err("Buckets can only be sorted by name".into()),
+                    };
+                    page.sort_by(sort_type);
+                }
+                Page::ObjectList(page) => {
+                    let sort_type = match (key, desc) {
+                        (SortKey::Default, _) => ObjectListSortType::Default,
+                        (SortKey::Name, false) => ObjectListSortType::NameAsc,
+                        (SortKey::Name, true) => ObjectListSortType::NameDesc,
+                        (SortKey::LastModified, false) => ObjectListSortType::LastModifiedAsc,
+                        (SortKey::LastModified, true) => ObjectListSortType::LastModifiedDesc,
+                        (SortKey::Size, false) => ObjectListSortType::SizeAsc,
+                        (SortKey::Size, true) => ObjectListSortType::SizeDesc,
+                    };
+                    page.sort_by(sort_type);
+                }
+                _ => return Err("sort is only available in the bucket list or object list".into()),
+            },
+            Command::Filter(filter) => match self.page_stack.current_page_mut() {
+                Page::BucketList(page) => page.filter_by(&filter),
+                Page::ObjectList(page) => page.filter_by(&filter),
+                _ => {
+                    return Err("filter is only available in the bucket list or object list".into())
+                }
+            },
+            Command::Profile(Some(profile)) => {
+                self.tx
+                    .send(AppEventType::SwitchProfile(Some(profile), None));
+            }
+            Command::Profile(None) => {
+                let profile = self.client.profile().unwrap_or_else(|| "default".into());
+                self.tx
+                    .send(AppEventType::NotifyInfo(format!("Profile: {profile}")));
+            }
+            Command::Bookmark(BookmarkAction::List) => {
+                self.tx.send(AppEventType::OpenBookmarks);
+            }
+            Command::Bookmark(BookmarkAction::Add(name)) => {
+                let Some(object_key) = current_dir else {
+                    return Err("bookmark add is only available in the object list".into());
+                };
+                self.tx
+                    .send(AppEventType::AddBookmark(name, object_key.clone()));
+            }
+            Command::Bookmark(BookmarkAction::Remove(name)) => {
+                self.tx.send(AppEventType::RemoveBookmark(name));
+            }
+            Command::Bookmark(BookmarkAction::Open(name)) => {
+                let bookmarks = Bookmarks::load().map_err(|e| e.to_string())?;
+                let Some(bookmark) = bookmarks.find(&name) else {
+                    return Err(format!("Bookmark not found: {name}"));
+                };
+                self.tx.send(AppEventType::OpenBookmark(bookmark.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn open_bookmarks(&mut self) {
+        let bookmarks = match Bookmarks::load() {
+            Ok(bookmarks) => bookmarks,
+            Err(e) => {
+                let e = AppError::msg(format!("Failed to load bookmarks: {e}"));
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
+        };
+        if bookmarks.list().is_empty() {
+            let msg = "No bookmarks saved".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let bookmark_list_page = Page::of_bookmark_list(
+            bookmarks.list().to_vec(),
+            Rc::clone(&self.ctx),
+            self.tx.clone(),
+        );
+        self.page_stack.push(bookmark_list_page);
+    }
+
+    pub fn open_bookmark(&mut self, bookmark: Bookmark) {
+        self.open_location(Location {
+            profile: bookmark.profile,
+            object_key: bookmark.object_key,
+        });
+    }
+
+    pub fn add_bookmark(&mut self, name: String, object_key: ObjectKey) {
+        let bookmark = Bookmark {
+            name: name.clone(),
+            profile: self.client.profile(),
+            object_key,
+        };
+        let result = Bookmarks::load().and_then(|mut bookmarks| {
+            bookmarks.add(bookmark);
+            bookmarks.save()
+        });
+        match result {
+            Ok(_) => {
+                let msg = format!("Bookmark added: {name}");
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                let e = AppError::msg(format!("Failed to save bookmarks: {e}"));
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn remove_bookmark(&mut self, name: String) {
+        let result = Bookmarks::load().and_then(|mut bookmarks| {
+            let removed = bookmarks.remove(&name);
+            bookmarks.save().map(|_| removed)
+        });
+        match result {
+            Ok(true) => {
+                let msg = format!("Bookmark removed: {name}");
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Ok(false) => {
+                let msg = format!("Bookmark not found: {name}");
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Err(e) => {
+                let e = AppError::msg(format!("Failed to save bookmarks: {e}"));
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    // open the object list of the key after switching if specified
+    pub fn switch_profile(&mut self, profile: Option<String>, object_key: Option<ObjectKey>) {
+        self.is_loading = true;
+
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+        spawn(async move {
+            let prev_profile = client.profile();
+            let buckets = match client.switch_profile(profile.clone()).await {
+                Ok(_) => client.load_all_buckets().await,
+                Err(e) => Err(e),
+            };
+            if buckets.is_err() {
+                // keep using the previous profile if the new one does not work
+                let _ = client.switch_profile(prev_profile).await;
+            }
+            let result = CompleteSwitchProfileResult::new(buckets, profile, object_key);
+            tx.send(AppEventType::CompleteSwitchProfile(result));
+        });
+    }
+
+    pub fn complete_switch_profile(&mut self, result: Result<CompleteSwitchProfileResult>) {
+        match result {
+            Ok(CompleteSwitchProfileResult {
+                profile,
+                buckets,
+                object_key,
+            }) => {
+                self.app_objects.clear_all();
+                self.page_stack = PageStack::new(Rc::clone(&self.ctx), self.tx.clone());
+                self.dual_pane = None;
+
+                if let Some(object_key) = object_key {
+                    self.app_objects.set_bucket_items(buckets);
+                    if self.app_objects.get_bucket_items().len() > 1 {
+                        let bucket_list_page = Page::of_bucket_list(
+                            self.app_objects.get_bucket_items(),
+                            Rc::clone(&self.ctx),
+                            self.tx.clone(),
+                        );
+                        self.page_stack.push(bucket_list_page);
+                    }
+                    self.go_to_path(object_key);
+                } else {
+                    let result = CompleteInitializeResult {
+                        buckets,
+                        prefix: None,
+                    };
+                    self.complete_initialize(Ok(result));
+                }
+
+                let profile = profile.unwrap_or_else(|| "default".into());
+                let msg = format!("Switched to profile: {profile}");
+                self.tx.send(AppEventType::NotifySuccess(msg));
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
@@ -968,7 +2599,37 @@ impl<C: Client> App<C> {
 
     pub fn error_notification(&mut self, e: AppError) {
         self.handle_error(&e);
-        self.notification = Notification::Error(e.msg);
+        self.show_error(e, None);
+    }
+
+    pub fn request_error_notification(&mut self, e: RequestError) {
+        self.handle_error(&e.error);
+        self.show_error(e.error, Some(*e.request));
+    }
+
+    fn show_error(&mut self, e: AppError, retry_request: Option<AppEventType>) {
+        match e.kind {
+            ErrorKind::ExpiredCredentials if retry_request.is_some() => {
+                self.is_loading = false;
+                self.reauth_dialog = Some(ReauthDialog {
+                    error_msg: e.detailed_msg(),
+                    state: ConfirmDialogState::default(),
+                });
+                self.retry_request = retry_request;
+            }
+            ErrorKind::MfaTokenCodeRequired | ErrorKind::InvalidMfaTokenCode
+                if retry_request.is_some() =>
+            {
+                // ask for the token code again and retry the failed request with the new MFA session
+                self.is_loading = false;
+                self.notification = Notification::Error(e.detailed_msg());
+                self.mfa_token_code_dialog = Some(InputDialogState::default());
+                self.retry_request = retry_request;
+            }
+            _ => {
+                self.notification = Notification::Error(e.detailed_msg());
+            }
+        }
     }
 
     fn handle_error(&self, e: &AppError) {
@@ -997,6 +2658,11 @@ impl<C: Client> App<C> {
         self.render_header(f, chunks[0]);
         self.render_content(f, chunks[1]);
         self.render_footer(f, chunks[2]);
+        self.render_command_palette(f);
+        self.render_command_line(f);
+        self.render_reauth_dialog(f);
+        self.render_mfa_token_code_dialog(f);
+        self.render_bulk_report_dialog(f);
         self.render_loading_dialog(f);
     }
 
@@ -1020,7 +2686,28 @@ impl<C: Client> App<C> {
     }
 
     fn render_content(&mut self, f: &mut Frame, area: Rect) {
-        self.page_stack.current_page_mut().render(f, area);
+        let Some(dual_pane) = &mut self.dual_pane else {
+            self.page_stack.current_page_mut().render(f, area);
+            return;
+        };
+        if let Page::Help(_) = self.page_stack.current_page() {
+            self.page_stack.current_page_mut().render(f, area);
+            return;
+        }
+
+        let [left, right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(area);
+        let (active_area, inactive_area) = if dual_pane.active_left {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        self.page_stack.current_page_mut().render(f, active_area);
+        dual_pane
+            .page_stack
+            .current_page_mut()
+            .render(f, inactive_area);
+        f.buffer_mut()
+            .set_style(inactive_area, Style::default().add_modifier(Modifier::DIM));
     }
 
     fn render_footer(&self, f: &mut Frame, area: Rect) {
@@ -1029,14 +2716,131 @@ impl<C: Client> App<C> {
             Notification::Success(msg) => StatusType::Success(msg.into()),
             Notification::Warn(msg) => StatusType::Warn(msg.into()),
             Notification::Error(msg) => StatusType::Error(msg.into()),
+            Notification::None if self.is_showing_reauth_dialog() => {
+                StatusType::Help(self.select_dialog_short_helps())
+            }
+            Notification::None if self.is_showing_mfa_token_code_dialog() => {
+                StatusType::Help(self.mfa_token_code_dialog_short_helps())
+            }
+            Notification::None if self.is_showing_bulk_report_dialog() => {
+                StatusType::Help(self.select_dialog_short_helps())
+            }
+            Notification::None if self.is_showing_command_palette() => {
+                StatusType::Help(self.command_palette_short_helps())
+            }
+            Notification::None if self.is_showing_command_line() => {
+                StatusType::Help(self.command_line_short_helps())
+            }
             Notification::None => {
                 StatusType::Help(self.page_stack.current_page().short_helps(&self.mapper))
             }
         };
-        let status = Status::new(status_type).theme(&self.ctx.theme);
+        let pending_keys = key_sequence_to_string(self.pending_keys.keys(), true);
+        let status = Status::new(status_type)
+            .pending_keys(pending_keys)
+            .theme(&self.ctx.theme);
         f.render_widget(status, area);
     }
 
+    fn select_dialog_short_helps(&self) -> Vec<crate::help::SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::SelectDialogClose, "Close", 2),
+            BuildShortHelpsItem::group(vec![UserEvent::SelectDialogLeft, UserEvent::SelectDialogRight], "Select", 3),
+            BuildShortHelpsItem::single(UserEvent::SelectDialogSelect, "Confirm", 1),
+        ];
+        build_short_help_spans(helps, &self.mapper)
+    }
+
+    fn command_palette_short_helps(&self) -> Vec<crate::help::SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::CommandPaletteClose, "Close", 2),
+            BuildShortHelpsItem::group(vec![UserEvent::CommandPaletteDown, UserEvent::CommandPaletteUp], "Select", 3),
+            BuildShortHelpsItem::single(UserEvent::CommandPaletteExecute, "Execute", 1),
+        ];
+        build_short_help_spans(helps, &self.mapper)
+    }
+
+    fn render_command_palette(&mut self, f: &mut Frame) {
+        if let Some(state) = &mut self.command_palette {
+            let command_palette = CommandPalette::default().theme(&self.ctx.theme);
+            f.render_stateful_widget(command_palette, f.area(), state);
+
+            let (x, y) = state.cursor();
+            f.set_cursor_position((x, y));
+        }
+    }
+
+    fn command_line_short_helps(&self) -> Vec<crate::help::SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::CommandLineClose, "Close", 2),
+            BuildShortHelpsItem::single(UserEvent::CommandLineComplete, "Complete", 3),
+            BuildShortHelpsItem::group(vec![UserEvent::CommandLinePrevHistory, UserEvent::CommandLineNextHistory], "History", 4),
+            BuildShortHelpsItem::single(UserEvent::CommandLineExecute, "Execute", 1),
+        ];
+        build_short_help_spans(helps, &self.mapper)
+    }
+
+    fn render_command_line(&mut self, f: &mut Frame) {
+        if let Some(command_line) = &mut self.command_line {
+            let dialog = InputDialog::default()
+                .title("Command")
+                .max_width(60)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(dialog, f.area(), &mut command_line.state);
+
+            let (cursor_x, cursor_y) = command_line.state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+    }
+
+    fn render_reauth_dialog(&mut self, f: &mut Frame) {
+        if let Some(dialog) = &mut self.reauth_dialog {
+            let message_lines = vec![
+                Line::from("Credentials have expired or could not be loaded.".bold()),
+                Line::from(""),
+                Line::from("Reload credentials and retry the failed request?"),
+                Line::from("(e.g. after running `aws sso login` in another terminal)"),
+            ];
+            let confirm_dialog = ConfirmDialog::new(message_lines).theme(&self.ctx.theme);
+            f.render_stateful_widget(confirm_dialog, f.area(), &mut dialog.state);
+        }
+    }
+
+    fn mfa_token_code_dialog_short_helps(&self) -> Vec<crate::help::SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+            BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Close", 2),
+            BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Submit", 1),
+        ];
+        build_short_help_spans(helps, &self.mapper)
+    }
+
+    fn render_mfa_token_code_dialog(&mut self, f: &mut Frame) {
+        if let Some(state) = &mut self.mfa_token_code_dialog {
+            let dialog = InputDialog::default()
+                .title("MFA Token Code")
+                .max_width(30)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(dialog, f.area(), state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+    }
+
+    fn render_bulk_report_dialog(&mut self, f: &mut Frame) {
+        if let Some(dialog) = &mut self.bulk_report_dialog {
+            let description = dialog.operation.description();
+            let report_dialog =
+                BulkReportDialog::new(description, &dialog.report).theme(&self.ctx.theme);
+            f.render_stateful_widget(report_dialog, f.area(), &mut dialog.state);
+        }
+    }
+
     fn render_loading_dialog(&self, f: &mut Frame) {
         if self.loading() {
             let dialog = LoadingDialog::default().theme(&self.ctx.theme);
@@ -1045,24 +2849,262 @@ impl<C: Client> App<C> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{
-        event::Sender,
-        keys::UserEventMapper,
-        object::{BucketItem, FileDetail, FileVersion, ObjectItem},
-        pages::page::Page,
+async fn read_parquet_object<C: Client>(
+    client: &C,
+    object_key: &ObjectKey,
+    version_id: Option<String>,
+    size_byte: usize,
+    max_rows: usize,
+) -> Result<TableData> {
+    let bucket = &object_key.bucket_name;
+    let key = object_key.joined_object_path(true);
+
+    let tail_range = ByteRange::tail(size_byte, PARQUET_TAIL_SIZE);
+    let tail = if tail_range.is_empty() {
+        Vec::new()
+    } else {
+        client
+            .download_object_range(bucket, &key, version_id.clone(), tail_range)
+            .await?
+    };
+    let metadata_range = parquet_metadata_range(&tail, tail_range.start)?;
+    let metadata = if metadata_range.start >= tail_range.start {
+        let start = metadata_range.start - tail_range.start;
+        let end = metadata_range.end - tail_range.start;
+        parse_parquet_metadata(&tail[start..end])?
+    } else {
+        let bytes = client
+            .download_object_range(bucket, &key, version_id.clone(), metadata_range)
+            .await?;
+        parse_parquet_metadata(&bytes)?
     };
-    use chrono::{DateTime, Local};
 
-    #[derive(Debug)]
+    let (row_group_count, range) = parquet_row_groups_range(&metadata, max_rows);
+    let bytes = match range {
+        Some(range) if !range.is_empty() => {
+            client
+                .download_object_range(bucket, &key, version_id, range)
+                .await?
+        }
+        _ => Vec::new(),
+    };
+    read_parquet_table(&metadata, row_group_count, range, bytes, max_rows)
+}
+
+async fn read_archive_entry<C: Client>(
+    client: &C,
+    object_key: &ObjectKey,
+    source: &ArchiveSource,
+    entry: &ArchiveEntry,
+    limit: usize,
+) -> Result<Decompressed> {
+    match (entry.location, source) {
+        (
+            EntryLocation::Zip {
+                local_header_offset,
+                compressed_size_byte,
+                method,
+            },
+            ArchiveSource::Object(version_id),
+        ) => {
+            let bucket = &object_key.bucket_name;
+            let key = object_key.joined_object_path(true);
+            let header_range = ByteRange {
+                start: local_header_offset,
+                end: local_header_offset.saturating_add(ZIP_LOCAL_HEADER_SIZE),
+            };
+            let local_header = client
+                .download_object_range(bucket, &key, version_id.clone(), header_range)
+                .await?;
+            let data_range =
+                zip_entry_data_range(&local_header, local_header_offset, compressed_size_byte)?;
+            let data = client
+                .download_object_range(bucket, &key, version_id.clone(), data_range)
+                .await?;
+            decompress_zip_entry(method, &data, limit)
+        }
+        (EntryLocation::Tar { offset }, ArchiveSource::Loaded(object)) => {
+            let size_byte = if limit == 0 {
+                entry.size_byte
+            } else {
+                entry.size_byte.min(limit)
+            };
+            let bytes = object
+                .bytes
+                .get(offset..offset + size_byte)
+                .ok_or_else(|| AppError::msg("Failed to read archive entry"))?;
+            Ok(Decompressed {
+                bytes: bytes.to_vec(),
+                truncated: size_byte < entry.size_byte,
+            })
+        }
+        _ => Err(AppError::msg("Failed to read archive entry")),
+    }
+}
+
+// loads the whole object, or only the first `limit` bytes if it is larger (`0` means no limit)
+async fn load_diff_object<C: Client>(
+    client: &C,
+    target: &DiffTarget,
+    limit: usize,
+) -> Result<RawObject> {
+    let bucket = &target.object_key.bucket_name;
+    let key = target.object_key.joined_object_path(true);
+    let version_id = target.version_id.clone();
+    let size_byte = target.size_byte;
+
+    if limit > 0 && size_byte > limit {
+        let range = ByteRange::head(size_byte, limit);
+        let bytes = client
+            .download_object_range(bucket, &key, version_id, range)
+            .await?;
+        return Ok(RawObject { bytes });
+    }
+
+    let mut bytes = Vec::with_capacity(size_byte);
+    {
+        let mut writer = BufWriter::new(&mut bytes);
+        client
+            .download_object(bucket, &key, version_id, &mut writer, |_| {})
+            .await?;
+    }
+    Ok(RawObject { bytes })
+}
+
+// copies the object or all objects under the prefix, and then deletes the source objects that were copied
+async fn move_object<C: Client>(
+    client: &C,
+    spec: &crate::event::PasteSpec,
+    max_concurrent_requests: usize,
+    tx: &Sender,
+) -> Result<Option<BulkReport>> {
+    if spec.src_key.ends_with('/') {
+        let objs = client
+            .list_all_download_objects(&spec.src_bucket, &spec.src_key)
+            .await?;
+        let report = move_objects(client, spec, objs, max_concurrent_requests, tx).await;
+        Ok(Some(report))
+    } else {
+        client
+            .copy_object(
+                &spec.src_bucket,
+                &spec.src_key,
+                &spec.dst_bucket,
+                &spec.dst_key,
+            )
+            .await?;
+        client
+            .delete_object(&spec.src_bucket, &spec.src_key)
+            .await?;
+        Ok(None)
+    }
+}
+
+async fn move_objects<C: Client>(
+    client: &C,
+    spec: &crate::event::PasteSpec,
+    objs: Vec<DownloadObjectInfo>,
+    max_concurrent_requests: usize,
+    tx: &Sender,
+) -> BulkReport {
+    let progress_tx = tx.clone();
+    let report = client
+        .copy_objects(
+            &spec.src_bucket,
+            &spec.src_key,
+            &spec.dst_bucket,
+            &spec.dst_key,
+            objs.clone(),
+            max_concurrent_requests,
+            move |cur, total| {
+                let msg = format!("Copied {}/{} objects...", cur, total);
+                progress_tx.send(AppEventType::NotifyInfo(msg));
+            },
+        )
+        .await;
+
+    // the objects that failed to be copied are kept in the source
+    let copied = report.succeeded_objects(objs);
+    let progress_tx = tx.clone();
+    let delete_report = client
+        .delete_objects(
+            &spec.src_bucket,
+            copied,
+            max_concurrent_requests,
+            move |cur, total| {
+                let msg = format!("Deleted {}/{} objects...", cur, total);
+                progress_tx.send(AppEventType::NotifyInfo(msg));
+            },
+        )
+        .await;
+    report.followed_by(delete_report)
+}
+
+// copies the item into the directory with the same name
+fn build_paste_spec(
+    src_item_key: &ObjectKey,
+    item: &ObjectItem,
+    dest_dir_key: &ObjectKey,
+    mode: crate::event::PasteMode,
+) -> crate::event::PasteSpec {
+    let (name, src_key, is_dir) = match item {
+        ObjectItem::Dir { name, key, .. } => (name.clone(), key.clone(), true),
+        ObjectItem::File { name, key, .. } => (name.clone(), key.clone(), false),
+    };
+
+    let mut dst_key = dest_dir_key.joined_object_path(false);
+    dst_key.push_str(&name);
+    if is_dir && !dst_key.ends_with('/') {
+        dst_key.push('/');
+    }
+
+    let mut src_dir_key = src_item_key.clone();
+    src_dir_key.object_path.pop();
+
+    crate::event::PasteSpec {
+        src_bucket: src_item_key.bucket_name.clone(),
+        src_key,
+        src_dir_key,
+        dst_bucket: dest_dir_key.bucket_name.clone(),
+        dst_key,
+        dst_dir_key: dest_dir_key.clone(),
+        name,
+        mode,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{PasteMode, Sender},
+        keys::UserEventMapper,
+        object::{BucketItem, ByteRange, FileDetail, FileVersion, ObjectItem},
+        pages::page::Page,
+    };
+    use chrono::{DateTime, Local};
+    use ratatui::crossterm::event::KeyCode;
+
+    #[derive(Debug)]
     struct FakeClient;
 
     #[allow(clippy::manual_async_fn)]
     impl Client for FakeClient {
-        fn region(&self) -> &str {
-            "us-east-1"
+        fn region(&self) -> String {
+            "us-east-1".into()
+        }
+        fn profile(&self) -> Option<String> {
+            None
+        }
+        fn set_mfa_token_code(&self, _code: String) {}
+        fn reload_credentials(&self) -> impl std::future::Future<Output = Result<()>> + Send {
+            async { Ok(()) }
+        }
+        fn switch_profile(
+            &self,
+            _profile: Option<String>,
+        ) -> impl std::future::Future<Output = Result<()>> + Send {
+            async { Ok(()) }
         }
         fn load_all_buckets(
             &self,
@@ -1082,6 +3124,14 @@ mod tests {
         ) -> impl std::future::Future<Output = Result<Vec<ObjectItem>>> + Send {
             async { Ok(vec![]) }
         }
+
+        fn load_dir_names(
+            &self,
+            _bucket: &str,
+            _prefix: &str,
+        ) -> impl std::future::Future<Output = Result<Vec<String>>> + Send {
+            async { Ok(vec!["dir1".into(), "dir2".into()]) }
+        }
         fn load_object_detail(
             &self,
             _bucket: &str,
@@ -1107,6 +3157,15 @@ mod tests {
         ) -> impl std::future::Future<Output = Result<()>> + Send {
             async { Ok(()) }
         }
+        fn download_object_range(
+            &self,
+            _bucket: &str,
+            _key: &str,
+            _version_id: Option<String>,
+            _range: ByteRange,
+        ) -> impl std::future::Future<Output = Result<Vec<u8>>> + Send {
+            async { Ok(Vec::new()) }
+        }
         fn list_all_download_objects(
             &self,
             _bucket: &str,
@@ -1131,9 +3190,37 @@ mod tests {
             _dst_prefix: &str,
             _max_concurrent_requests: usize,
             _f: F,
+        ) -> impl std::future::Future<Output = Result<BulkReport>> + Send {
+            async { Ok(BulkReport::default()) }
+        }
+        fn copy_objects<F: Fn(usize, usize) + Send>(
+            &self,
+            _src_bucket: &str,
+            _src_prefix: &str,
+            _dst_bucket: &str,
+            _dst_prefix: &str,
+            _objs: Vec<DownloadObjectInfo>,
+            _max_concurrent_requests: usize,
+            _f: F,
+        ) -> impl std::future::Future<Output = BulkReport> + Send {
+            async { BulkReport::default() }
+        }
+        fn delete_object(
+            &self,
+            _bucket: &str,
+            _key: &str,
         ) -> impl std::future::Future<Output = Result<()>> + Send {
             async { Ok(()) }
         }
+        fn delete_objects<F: Fn(usize, usize) + Send>(
+            &self,
+            _bucket: &str,
+            _objs: Vec<DownloadObjectInfo>,
+            _max_concurrent_requests: usize,
+            _f: F,
+        ) -> impl std::future::Future<Output = BulkReport> + Send {
+            async { BulkReport::default() }
+        }
         fn open_management_console_buckets(&self) -> Result<()> {
             Ok(())
         }
@@ -1176,6 +3263,532 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_open_bookmark() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        // same profile as the current one
+        let key = ObjectKey::with_prefix("bucket", "a/b/".to_string());
+        app.open_bookmark(Bookmark {
+            name: "ab".into(),
+            profile: None,
+            object_key: key.clone(),
+        });
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // switch the profile before opening the bookmark
+        app.open_bookmark(Bookmark {
+            name: "ab".into(),
+            profile: Some("prod".into()),
+            object_key: key.clone(),
+        });
+        let result = match rx.recv().await.expect("event") {
+            AppEventType::CompleteSwitchProfile(result) => result,
+            other => panic!("unexpected event: {:?}", other),
+        };
+        app.complete_switch_profile(result);
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match rx.recv().await.expect("event") {
+            AppEventType::NotifySuccess(msg) => assert_eq!(msg, "Switched to profile: prod"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_back_and_forward() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let key = |prefix: &str| ObjectKey::with_prefix("bucket", prefix.to_string());
+        app.visit_location(&key("a/"));
+        app.visit_location(&key("a/b/"));
+
+        app.history_forward();
+        assert!(rx.try_recv().is_err());
+
+        app.history_back();
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key("a/")),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        app.history_back();
+        assert!(rx.try_recv().is_err());
+
+        app.history_forward();
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key("a/b/")),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let uris: Vec<String> = app
+            .recent_locations
+            .list()
+            .iter()
+            .map(|l| l.s3_uri())
+            .collect();
+        assert_eq!(uris, vec!["s3://bucket/a/b/", "s3://bucket/a/"]);
+    }
+
+    #[tokio::test]
+    async fn test_open_initial_object() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        let file = |name: &str| ObjectItem::File {
+            name: name.to_string(),
+            size_byte: 1,
+            last_modified: DateTime::<Local>::default(),
+            key: format!("dir/{name}"),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        };
+        let dir_key = ObjectKey::with_prefix("bucket", "dir/".to_string());
+        let object_key = ObjectKey::with_prefix("bucket", "dir/b.txt".to_string());
+        let result = || {
+            Ok(CompleteLoadObjectsResult {
+                items: vec![file("a.txt"), file("b.txt")],
+                object_key: dir_key.clone(),
+            })
+        };
+
+        app.set_initial_object(object_key.clone(), None, true);
+        app.complete_load_objects(result());
+        assert!(matches!(
+            rx.recv().await.expect("event"),
+            AppEventType::LoadObjectDetail
+        ));
+
+        let detail = FileDetail {
+            name: "b.txt".into(),
+            key: "dir/b.txt".into(),
+            ..Default::default()
+        };
+        app.complete_load_object_detail(Ok(CompleteLoadObjectDetailResult {
+            detail: Box::new(detail),
+            map_key: object_key.clone(),
+        }));
+        match rx.recv().await.expect("event") {
+            AppEventType::OpenPreview(key, detail, version_id) => {
+                assert_eq!(key, object_key);
+                assert_eq!(detail.name, "b.txt");
+                assert_eq!(version_id, None);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let missing_key = ObjectKey::with_prefix("bucket", "dir/c.txt".to_string());
+        app.set_initial_object(missing_key, None, false);
+        app.complete_load_objects(result());
+        match rx.recv().await.expect("event") {
+            AppEventType::NotifyWarn(msg) => {
+                assert_eq!(msg, "Object not found: s3://bucket/dir/c.txt");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dual_pane_copy_to_other_pane() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let file_item = ObjectItem::File {
+            name: "file.txt".to_string(),
+            size_byte: 1,
+            last_modified: DateTime::<Local>::default(),
+            key: "a/file.txt".to_string(),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        };
+        let open_dir = |app: &mut App<FakeClient>, prefix: &str, items: Vec<ObjectItem>| {
+            let key = ObjectKey::with_prefix("bucket", prefix.to_string());
+            app.app_objects.set_object_items(key.clone(), items.clone());
+            let dummy_ctx = Rc::new(AppContext::default());
+            let page = Page::of_object_list(items, key, dummy_ctx, Sender::new(tx_raw.clone()));
+            app.page_stack.push(page);
+        };
+        open_dir(&mut app, "a/", vec![file_item.clone()]);
+        let file_key = ObjectKey::with_prefix("bucket", "a/file.txt".to_string());
+
+        app.switch_pane();
+        assert!(matches!(app.current_notification(), Notification::Warn(_)));
+
+        // the new pane is opened in the same directory with the focus
+        app.toggle_dual_pane();
+        assert!(!app.dual_pane.as_ref().unwrap().active_left);
+        app.start_paste_to_other_pane(file_key.clone(), file_item.clone(), PasteMode::Copy);
+        assert!(matches!(app.current_notification(), Notification::Warn(_)));
+
+        open_dir(&mut app, "b/", vec![]);
+        app.switch_pane();
+        assert!(app.dual_pane.as_ref().unwrap().active_left);
+        assert!(matches!(
+            rx.recv().await.expect("event"),
+            AppEventType::ReloadObjects
+        ));
+
+        app.start_paste_to_other_pane(file_key, file_item, PasteMode::Copy);
+        let spec = match rx.recv().await.expect("event") {
+            AppEventType::OpenPasteConfirmDialog(spec) => spec,
+            other => panic!("unexpected event: {:?}", other),
+        };
+        assert_eq!(spec.src_key, "a/file.txt");
+        assert_eq!(spec.dst_key, "b/file.txt");
+
+        app.complete_paste_object(Ok(crate::event::CompletePasteObjectResult {
+            spec,
+            report: None,
+        }));
+        assert!(matches!(
+            rx.recv().await.expect("event"),
+            AppEventType::ObjectListRefresh
+        ));
+
+        // the copied object is shown when the other pane gets active
+        app.switch_pane();
+        assert!(matches!(
+            rx.recv().await.expect("event"),
+            AppEventType::ReloadObjects
+        ));
+
+        app.toggle_dual_pane();
+        assert!(app.dual_pane.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_toggle_dual_pane_opens_same_location() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let dir_item = ObjectItem::Dir {
+            name: "b".to_string(),
+            key: "a/b/".to_string(),
+            s3_uri: "".into(),
+            object_url: "".into(),
+        };
+        let file_item = ObjectItem::File {
+            name: "c.txt".to_string(),
+            size_byte: 1,
+            last_modified: DateTime::<Local>::default(),
+            key: "a/b/c.txt".to_string(),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        };
+        let dir_a_key = ObjectKey::with_prefix("bucket", "a/".to_string());
+        let dir_b_key = ObjectKey::with_prefix("bucket", "a/b/".to_string());
+        let file_key = ObjectKey::with_prefix("bucket", "a/b/c.txt".to_string());
+
+        let dummy_ctx = Rc::new(AppContext::default());
+        let page = Page::of_bucket_list(vec![], Rc::clone(&dummy_ctx), Sender::new(tx_raw.clone()));
+        app.page_stack.push(page);
+        // the objects of a/ are not loaded, e.g. cleared after pasted into it
+        let page = Page::of_object_list(
+            vec![dir_item.clone()],
+            dir_a_key.clone(),
+            Rc::clone(&dummy_ctx),
+            Sender::new(tx_raw.clone()),
+        );
+        app.page_stack.push(page);
+        app.app_objects
+            .set_object_items(dir_b_key.clone(), vec![file_item.clone()]);
+        let page = Page::of_object_list(
+            vec![file_item],
+            dir_b_key,
+            Rc::clone(&dummy_ctx),
+            Sender::new(tx_raw.clone()),
+        );
+        app.page_stack.push(page);
+        app.app_objects
+            .set_object_detail(file_key.clone(), FileDetail::default());
+        app.object_list_move_down();
+        assert_eq!(app.page_stack.len(), 4);
+
+        app.toggle_dual_pane();
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(key) => assert_eq!(key, dir_a_key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        app.complete_load_objects(Ok(CompleteLoadObjectsResult {
+            items: vec![dir_item],
+            object_key: dir_a_key,
+        }));
+
+        // the rest is opened from the loaded objects
+        assert_eq!(app.page_stack.len(), 4);
+        match app.page_stack.current_page() {
+            Page::ObjectDetail(page) => assert_eq!(page.current_object_key(), &file_key),
+            other => panic!("unexpected page: {:?}", other),
+        }
+        assert_eq!(app.dual_pane.as_ref().unwrap().page_stack.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_dual_pane_move_to_other_pane() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let file_item = ObjectItem::File {
+            name: "file.txt".to_string(),
+            size_byte: 1,
+            last_modified: DateTime::<Local>::default(),
+            key: "a/file.txt".to_string(),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        };
+        let open_dir = |app: &mut App<FakeClient>, prefix: &str, items: Vec<ObjectItem>| {
+            let key = ObjectKey::with_prefix("bucket", prefix.to_string());
+            app.app_objects.set_object_items(key.clone(), items.clone());
+            let dummy_ctx = Rc::new(AppContext::default());
+            let page = Page::of_object_list(items, key, dummy_ctx, Sender::new(tx_raw.clone()));
+            app.page_stack.push(page);
+        };
+        open_dir(&mut app, "a/", vec![file_item.clone()]);
+        app.toggle_dual_pane();
+        open_dir(&mut app, "b/", vec![]);
+        app.switch_pane();
+        assert!(matches!(
+            rx.recv().await.expect("event"),
+            AppEventType::ReloadObjects
+        ));
+
+        let file_key = ObjectKey::with_prefix("bucket", "a/file.txt".to_string());
+        app.start_paste_to_other_pane(file_key, file_item, PasteMode::Move);
+        let spec = match rx.recv().await.expect("event") {
+            AppEventType::OpenPasteConfirmDialog(spec) => spec,
+            other => panic!("unexpected event: {:?}", other),
+        };
+        assert_eq!(spec.mode, PasteMode::Move);
+        assert_eq!(spec.src_key, "a/file.txt");
+        assert_eq!(spec.dst_key, "b/file.txt");
+        assert_eq!(
+            spec.src_dir_key,
+            ObjectKey::with_prefix("bucket", "a/".to_string())
+        );
+
+        app.paste_object(spec);
+        let result = match rx.recv().await.expect("event") {
+            AppEventType::CompletePasteObject(result) => result,
+            other => panic!("unexpected event: {:?}", other),
+        };
+        app.complete_paste_object(result);
+        match app.current_notification() {
+            Notification::Success(msg) => assert_eq!(msg, "Moved 'file.txt' successfully"),
+            other => panic!("unexpected notification: {:?}", other),
+        }
+        // both the source and the destination are loaded again
+        let src_dir_key = ObjectKey::with_prefix("bucket", "a/".to_string());
+        assert!(app.app_objects.get_object_items(&src_dir_key).is_none());
+        let dst_dir_key = ObjectKey::with_prefix("bucket", "b/".to_string());
+        assert!(app.app_objects.get_object_items(&dst_dir_key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_command_palette_execute() {
+        let (tx_raw, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let dummy_ctx = Rc::new(AppContext::default());
+        let page = Page::of_bucket_list(vec![], dummy_ctx, Sender::new(tx_raw));
+        app.page_stack.push(page);
+
+        app.open_command_palette();
+        assert!(app.is_showing_command_palette());
+
+        for c in "sort".chars() {
+            let key_event = KeyEvent::from(KeyCode::Char(c));
+            assert_eq!(app.handle_command_palette_events(vec![], key_event), None);
+        }
+        let key_event = KeyEvent::from(KeyCode::Enter);
+        let user_event =
+            app.handle_command_palette_events(vec![UserEvent::CommandPaletteExecute], key_event);
+        assert_eq!(user_event, Some(UserEvent::BucketListSort));
+        assert!(!app.is_showing_command_palette());
+
+        app.open_command_palette();
+        let key_event = KeyEvent::from(KeyCode::Esc);
+        let user_event =
+            app.handle_command_palette_events(vec![UserEvent::CommandPaletteClose], key_event);
+        assert_eq!(user_event, None);
+        assert!(!app.is_showing_command_palette());
+    }
+
+    #[tokio::test]
+    async fn test_command_line_execute() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let file = |name: &str, size_byte: usize| ObjectItem::File {
+            name: name.to_string(),
+            size_byte,
+            last_modified: DateTime::<Local>::default(),
+            key: format!("dir/{name}"),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        };
+        let items = vec![file("a.csv", 30), file("b.txt", 10), file("c.csv", 20)];
+        let key = ObjectKey::with_prefix("bucket", "dir/".to_string());
+        app.app_objects.set_object_items(key.clone(), items.clone());
+        let dummy_ctx = Rc::new(AppContext::default());
+        let page = Page::of_object_list(items, key.clone(), dummy_ctx, Sender::new(tx_raw));
+        app.page_stack.push(page);
+
+        let type_command = |app: &mut App<FakeClient>, input: &str| {
+            app.open_command_line();
+            for c in input.chars() {
+                app.handle_command_line_events(vec![], KeyEvent::from(KeyCode::Char(c)));
+            }
+            let execute = vec![UserEvent::CommandLineExecute];
+            app.handle_command_line_events(execute, KeyEvent::from(KeyCode::Enter));
+        };
+        let names = |app: &App<FakeClient>| -> Vec<String> {
+            let page = app.page_stack.current_page().as_object_list();
+            page.object_list()
+                .iter()
+                .map(|item| item.name().to_string())
+                .collect()
+        };
+
+        type_command(&mut app, "sort size desc");
+        assert!(!app.is_showing_command_line());
+        assert_eq!(names(&app), vec!["a.csv", "c.csv", "b.txt"]);
+
+        type_command(&mut app, "filter re:\\.csv$");
+        assert_eq!(names(&app), vec!["a.csv", "c.csv"]);
+
+        type_command(&mut app, "get c* out");
+        match rx.recv().await.expect("event") {
+            AppEventType::DownloadObjects(bucket, object_key, dir, objs) => {
+                assert_eq!(bucket, "bucket");
+                assert_eq!(object_key, key);
+                assert_eq!(dir, "out");
+                assert_eq!(objs.len(), 1);
+                assert_eq!(objs[0].key, "dir/c.csv");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        type_command(&mut app, "cd ../other");
+        match rx.recv().await.expect("event") {
+            AppEventType::GoToPath(object_key) => {
+                assert_eq!(
+                    object_key,
+                    ObjectKey::with_prefix("bucket", "other/".into())
+                );
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        type_command(&mut app, "rm a.csv");
+        match rx.recv().await.expect("event") {
+            AppEventType::NotifyWarn(msg) => assert_eq!(msg, "Unknown command: rm"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // history and completion
+        app.open_command_line();
+        let prev = vec![UserEvent::CommandLinePrevHistory];
+        app.handle_command_line_events(prev.clone(), KeyEvent::from(KeyCode::Up));
+        app.handle_command_line_events(prev, KeyEvent::from(KeyCode::Up));
+        let command_line = app.command_line.as_ref().unwrap();
+        assert_eq!(command_line.state.input(), "cd ../other");
+
+        let close = vec![UserEvent::CommandLineClose];
+        app.handle_command_line_events(close, KeyEvent::from(KeyCode::Esc));
+        app.open_command_line();
+        for c in "get ".chars() {
+            app.handle_command_line_events(vec![], KeyEvent::from(KeyCode::Char(c)));
+        }
+        let complete = vec![UserEvent::CommandLineComplete];
+        app.handle_command_line_events(complete.clone(), KeyEvent::from(KeyCode::Tab));
+        assert_eq!(
+            app.command_line.as_ref().unwrap().state.input(),
+            "get a.csv"
+        );
+        app.handle_command_line_events(complete, KeyEvent::from(KeyCode::Tab));
+        assert_eq!(
+            app.command_line.as_ref().unwrap().state.input(),
+            "get b.txt"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_report_dialog_retry_failed() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        let obj = |key: &str| DownloadObjectInfo {
+            key: key.to_string(),
+            size_byte: 10,
+        };
+        let operation = BulkOperation::Download {
+            bucket: "bucket".to_string(),
+            key: ObjectKey::with_prefix("bucket", "a/".to_string()),
+            dir: "a".to_string(),
+            download_dir: "/tmp/a".into(),
+        };
+        let report = BulkReport {
+            succeeded_count: 1,
+            succeeded_size_byte: 10,
+            failed: vec![crate::object::FailedObject {
+                obj: obj("a/2.txt"),
+                error: "request timed out".to_string(),
+            }],
+            skipped: vec![obj("a/3.txt")],
+            credentials_expired: false,
+        };
+        app.complete_download_objects(CompleteDownloadObjectsResult::new(operation, report));
+        assert!(app.is_showing_bulk_report_dialog());
+        assert!(!app.loading());
+
+        app.handle_bulk_report_dialog_events(vec![UserEvent::SelectDialogSelect]);
+        assert!(!app.is_showing_bulk_report_dialog());
+        match rx.recv().await.expect("event") {
+            AppEventType::DownloadObjects(bucket, _, dir, objs) => {
+                assert_eq!(bucket, "bucket");
+                assert_eq!(dir, "a");
+                let keys: Vec<_> = objs.into_iter().map(|o| o.key).collect();
+                assert_eq!(keys, vec!["a/2.txt", "a/3.txt"]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_start_paste_object_file_and_dir_specs() {
         let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
@@ -1224,4 +3837,173 @@ mod tests {
             other => panic!("unexpected event: {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn test_expired_credentials_reload_and_retry() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        let key = ObjectKey::with_prefix("bucket", "a/".to_string());
+        let e = AppError::msg("Failed to load objects").with_kind(ErrorKind::ExpiredCredentials);
+        app.show_error(e, Some(AppEventType::LoadObjects(key.clone())));
+        assert!(app.is_showing_reauth_dialog());
+        assert!(!app.is_showing_notification());
+
+        app.handle_reauth_dialog_events(vec![UserEvent::SelectDialogSelect]);
+        assert!(!app.is_showing_reauth_dialog());
+        match rx.recv().await.expect("event") {
+            AppEventType::ReloadCredentials => {}
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        app.complete_reload_credentials(Ok(CompleteReloadCredentialsResult));
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_credentials_without_request_is_not_retried() {
+        let (tx_raw, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        let key = ObjectKey::with_prefix("bucket", "a/".to_string());
+        let e = AppError::msg("Failed to load objects").with_kind(ErrorKind::ExpiredCredentials);
+        app.show_error(e, Some(AppEventType::LoadObjects(key)));
+        app.handle_reauth_dialog_events(vec![UserEvent::SelectDialogClose]);
+
+        // a later error from a request that cannot be retried must not resend the earlier one
+        let e = AppError::msg("Failed to follow object").with_kind(ErrorKind::ExpiredCredentials);
+        app.show_error(e, None);
+        assert!(!app.is_showing_reauth_dialog());
+        assert!(app.is_showing_notification());
+        assert!(app.retry_request.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_mfa_token_code_is_asked_again() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        app.open_mfa_token_code_dialog(Some("bucket".into()), None);
+        input_mfa_token_code(&mut app, "000000");
+        let request = match rx.recv().await.expect("event") {
+            AppEventType::InputMfaTokenCode(code) => {
+                assert_eq!(code, "000000");
+                app.input_mfa_token_code(code);
+                rx.recv().await.expect("event")
+            }
+            other => panic!("unexpected event: {:?}", other),
+        };
+        assert!(matches!(request, AppEventType::Initialize(Some(ref b), None) if b == "bucket"));
+        assert!(!app.is_showing_mfa_token_code_dialog());
+
+        let e = AppError::msg("Failed to load buckets").with_kind(ErrorKind::InvalidMfaTokenCode);
+        app.show_error(e, Some(request));
+        assert!(app.is_showing_mfa_token_code_dialog());
+
+        input_mfa_token_code(&mut app, "123456");
+        match rx.recv().await.expect("event") {
+            AppEventType::InputMfaTokenCode(code) => {
+                assert_eq!(code, "123456");
+                app.input_mfa_token_code(code);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match rx.recv().await.expect("event") {
+            AppEventType::Initialize(bucket, prefix) => {
+                assert_eq!(bucket, Some("bucket".into()));
+                assert_eq!(prefix, None);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_mfa_session_asks_token_code_and_retries() {
+        let (tx_raw, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw));
+
+        let key = ObjectKey::with_prefix("bucket", "a/".to_string());
+        let e = AppError::msg("Failed to load objects").with_kind(ErrorKind::MfaTokenCodeRequired);
+        app.show_error(e, Some(AppEventType::LoadObjects(key.clone())));
+        assert!(app.is_showing_mfa_token_code_dialog());
+
+        input_mfa_token_code(&mut app, "123456");
+        match rx.recv().await.expect("event") {
+            AppEventType::InputMfaTokenCode(code) => app.input_mfa_token_code(code),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match rx.recv().await.expect("event") {
+            AppEventType::LoadObjects(k) => assert_eq!(k, key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complete_load_preview_range_ignores_other_object() {
+        let (tx_raw, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mapper = UserEventMapper::default();
+        let ctx = AppContext::default();
+        let mut app = App::new(mapper, FakeClient, ctx, Sender::new(tx_raw.clone()));
+
+        let object_key = ObjectKey::with_prefix("bucket", "a.txt".to_string());
+        let file_detail = FileDetail {
+            name: "a.txt".to_string(),
+            size_byte: 20,
+            ..Default::default()
+        };
+        let range = ByteRange::head(20, 10);
+        let page = Page::of_object_preview(
+            object_key.clone(),
+            file_detail,
+            None,
+            RawObject {
+                bytes: b"0123456789".to_vec(),
+            },
+            Some(range),
+            Rc::new(AppContext::default()),
+            Sender::new(tx_raw),
+        );
+        app.page_stack.push(page);
+
+        let next = ByteRange { start: 10, end: 20 };
+        let other_key = ObjectKey::with_prefix("bucket", "b.txt".to_string());
+        for (key, version_id) in [(other_key, None), (object_key.clone(), Some("v1".into()))] {
+            let result = CompleteLoadPreviewRangeResult::new(
+                Ok(b"abcdefghij".to_vec()),
+                key,
+                version_id,
+                next,
+            );
+            app.complete_load_preview_range(Ok(result.unwrap()));
+        }
+        let page = app.page_stack.current_page_mut().as_mut_object_preview();
+        assert_eq!(page.truncated_range(), Some(range));
+
+        let result =
+            CompleteLoadPreviewRangeResult::new(Ok(b"abcdefghij".to_vec()), object_key, None, next);
+        app.complete_load_preview_range(Ok(result.unwrap()));
+        let page = app.page_stack.current_page_mut().as_mut_object_preview();
+        assert_eq!(page.truncated_range(), None);
+    }
+
+    fn input_mfa_token_code(app: &mut App<FakeClient>, code: &str) {
+        for c in code.chars() {
+            app.handle_mfa_token_code_dialog_events(vec![], KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.handle_mfa_token_code_dialog_events(
+            vec![UserEvent::InputDialogApply],
+            KeyEvent::from(KeyCode::Enter),
+        );
+    }
 }
@@ -0,0 +1,542 @@
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::{
+    compression::{read_with_limit, Decompressed},
+    error::{AppError, Result},
+    object::{ByteRange, RawObject},
+    util::extension_from_file_name,
+};
+
+const ZIP_EOCD_SIGNATURE: u32 = 0x06054b50;
+const ZIP_EOCD_SIZE: usize = 22;
+const ZIP64_EOCD_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_EOCD_LOCATOR_SIZE: usize = 20;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const ZIP64_EOCD_SIZE: usize = 56;
+const ZIP_CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x02014b50;
+const ZIP_CENTRAL_DIRECTORY_HEADER_SIZE: usize = 46;
+const ZIP_LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const ZIP_MAX_COMMENT_SIZE: usize = 65535;
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+
+const ZIP_METHOD_STORED: u16 = 0;
+const ZIP_METHOD_DEFLATED: u16 = 8;
+
+/// The size of the end of the archive to load to find the central directory of a zip file.
+pub const ZIP_TAIL_SIZE: usize =
+    ZIP_EOCD_SIZE + ZIP_MAX_COMMENT_SIZE + ZIP64_EOCD_LOCATOR_SIZE + ZIP64_EOCD_SIZE;
+
+/// The size of the fixed part of the local file header of a zip file.
+pub const ZIP_LOCAL_HEADER_SIZE: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tar,
+}
+
+impl ArchiveFormat {
+    pub fn from_file_name(file_name: &str) -> Option<ArchiveFormat> {
+        if !file_name.contains('.') {
+            return None;
+        }
+        match extension_from_file_name(file_name).to_lowercase().as_str() {
+            "zip" => Some(ArchiveFormat::Zip),
+            "tar" => Some(ArchiveFormat::Tar),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    pub path: String,
+    pub size_byte: usize,
+    pub last_modified: Option<DateTime<Local>>,
+    pub is_dir: bool,
+    pub location: EntryLocation,
+}
+
+impl ArchiveEntry {
+    pub fn name(&self) -> &str {
+        let path = self.path.trim_end_matches('/');
+        path.rsplit('/').next().unwrap_or(path)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryLocation {
+    Zip {
+        local_header_offset: usize,
+        compressed_size_byte: usize,
+        method: u16,
+    },
+    Tar {
+        // the offset of the data in the loaded archive
+        offset: usize,
+    },
+}
+
+/// Where the data of the archive entries can be read from.
+#[derive(Debug, Clone)]
+pub enum ArchiveSource {
+    // read from the object (with the version id) with ranged requests
+    Object(Option<String>),
+    Loaded(Arc<RawObject>),
+}
+
+/// Returns the range of the central directory from the end of a zip file.
+pub fn zip_central_directory_range(tail: &[u8], tail_start: usize) -> Result<ByteRange> {
+    let eocd_pos = (0..=tail.len().saturating_sub(ZIP_EOCD_SIZE))
+        .rev()
+        .find(|&i| read_u32(tail, i) == Some(ZIP_EOCD_SIGNATURE))
+        .ok_or_else(|| AppError::msg("Not a zip file: end of central directory is not found"))?;
+
+    let invalid = || AppError::msg("Invalid zip file: end of central directory is truncated");
+    let eocd = &tail[eocd_pos..];
+    let mut entries = read_u16(eocd, 10).ok_or_else(invalid)? as usize;
+    let mut size = read_u32(eocd, 12).ok_or_else(invalid)? as usize;
+    let mut offset = read_u32(eocd, 16).ok_or_else(invalid)? as usize;
+
+    let is_zip64 = entries == 0xFFFF || size == 0xFFFFFFFF || offset == 0xFFFFFFFF;
+    if is_zip64 {
+        let locator_pos = eocd_pos
+            .checked_sub(ZIP64_EOCD_LOCATOR_SIZE)
+            .filter(|&i| read_u32(tail, i) == Some(ZIP64_EOCD_LOCATOR_SIGNATURE))
+            .ok_or_else(|| AppError::msg("Invalid zip file: zip64 locator is not found"))?;
+        let zip64_eocd_offset = read_u64(tail, locator_pos + 8).ok_or_else(invalid)? as usize;
+        let zip64_eocd = zip64_eocd_offset
+            .checked_sub(tail_start)
+            .and_then(|i| tail.get(i..))
+            .filter(|b| read_u32(b, 0) == Some(ZIP64_EOCD_SIGNATURE))
+            .ok_or_else(|| {
+                AppError::msg("Invalid zip file: zip64 end of central directory is not found")
+            })?;
+        entries = read_u64(zip64_eocd, 32).unwrap_or_default() as usize;
+        size = read_u64(zip64_eocd, 40).unwrap_or_default() as usize;
+        offset = read_u64(zip64_eocd, 48).unwrap_or_default() as usize;
+    }
+
+    if entries > 0 && size == 0 {
+        return Err(AppError::msg(
+            "Invalid zip file: central directory is empty",
+        ));
+    }
+    let end = offset
+        .checked_add(size)
+        .ok_or_else(|| AppError::msg("Invalid zip file: central directory is out of range"))?;
+    Ok(ByteRange { start: offset, end })
+}
+
+pub fn parse_zip_central_directory(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let invalid = || AppError::msg("Invalid zip file: failed to read central directory");
+
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while pos + ZIP_CENTRAL_DIRECTORY_HEADER_SIZE <= bytes.len() {
+        let header = &bytes[pos..];
+        if read_u32(header, 0) != Some(ZIP_CENTRAL_DIRECTORY_HEADER_SIGNATURE) {
+            break;
+        }
+        let method = read_u16(header, 10).ok_or_else(invalid)?;
+        let time = read_u16(header, 12).ok_or_else(invalid)?;
+        let date = read_u16(header, 14).ok_or_else(invalid)?;
+        let mut compressed_size = read_u32(header, 20).ok_or_else(invalid)? as u64;
+        let mut uncompressed_size = read_u32(header, 24).ok_or_else(invalid)? as u64;
+        let name_len = read_u16(header, 28).ok_or_else(invalid)? as usize;
+        let extra_len = read_u16(header, 30).ok_or_else(invalid)? as usize;
+        let comment_len = read_u16(header, 32).ok_or_else(invalid)? as usize;
+        let mut local_header_offset = read_u32(header, 42).ok_or_else(invalid)? as u64;
+
+        let name_start = ZIP_CENTRAL_DIRECTORY_HEADER_SIZE;
+        let extra_start = name_start + name_len;
+        let name = header.get(name_start..extra_start).ok_or_else(invalid)?;
+        let extra = header
+            .get(extra_start..extra_start + extra_len)
+            .ok_or_else(invalid)?;
+
+        // the values are stored in the zip64 extra field only if they do not fit in 32 bits
+        if let Some(mut zip64) = find_zip_extra_field(extra, ZIP64_EXTRA_FIELD_ID) {
+            for value in [
+                &mut uncompressed_size,
+                &mut compressed_size,
+                &mut local_header_offset,
+            ] {
+                if *value == 0xFFFFFFFF {
+                    *value = read_u64(zip64, 0).ok_or_else(invalid)?;
+                    zip64 = &zip64[8..];
+                }
+            }
+        }
+
+        let path = String::from_utf8_lossy(name).into_owned();
+        entries.push(ArchiveEntry {
+            is_dir: path.ends_with('/'),
+            path,
+            size_byte: uncompressed_size as usize,
+            last_modified: from_dos_datetime(date, time),
+            location: EntryLocation::Zip {
+                local_header_offset: local_header_offset as usize,
+                compressed_size_byte: compressed_size as usize,
+                method,
+            },
+        });
+
+        pos += ZIP_CENTRAL_DIRECTORY_HEADER_SIZE + name_len + extra_len + comment_len;
+    }
+    Ok(entries)
+}
+
+/// Returns the range of the compressed data of a zip entry from its local file header.
+pub fn zip_entry_data_range(
+    local_header: &[u8],
+    local_header_offset: usize,
+    compressed_size_byte: usize,
+) -> Result<ByteRange> {
+    let invalid = || AppError::msg("Invalid zip file: local file header is not found");
+    if read_u32(local_header, 0) != Some(ZIP_LOCAL_HEADER_SIGNATURE) {
+        return Err(invalid());
+    }
+    let name_len = read_u16(local_header, 26).ok_or_else(invalid)? as usize;
+    let extra_len = read_u16(local_header, 28).ok_or_else(invalid)? as usize;
+    let out_of_range = || AppError::msg("Invalid zip file: entry data is out of range");
+    let start = local_header_offset
+        .checked_add(ZIP_LOCAL_HEADER_SIZE + name_len + extra_len)
+        .ok_or_else(out_of_range)?;
+    let end = start
+        .checked_add(compressed_size_byte)
+        .ok_or_else(out_of_range)?;
+    Ok(ByteRange { start, end })
+}
+
+/// Decompresses the data of a zip entry up to `limit` bytes (`0` means no limit).
+pub fn decompress_zip_entry(method: u16, data: &[u8], limit: usize) -> Result<Decompressed> {
+    match method {
+        ZIP_METHOD_STORED => {
+            let truncated = limit != 0 && data.len() > limit;
+            let bytes = if truncated { &data[..limit] } else { data };
+            Ok(Decompressed {
+                bytes: bytes.to_vec(),
+                truncated,
+            })
+        }
+        ZIP_METHOD_DEFLATED => {
+            let mut decoder = flate2::read::DeflateDecoder::new(data);
+            read_with_limit(&mut decoder, limit)
+        }
+        _ => Err(AppError::msg(format!(
+            "Unsupported zip compression method: {method}"
+        ))),
+    }
+}
+
+/// Lists the entries of a tar archive.
+///
+/// If the archive is only a part of the original, the entries whose data are completely loaded are returned.
+pub fn parse_tar(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let mut archive = tar::Archive::new(bytes);
+    let iter = archive
+        .entries()
+        .map_err(|e| AppError::new("Failed to read tar archive", e))?;
+
+    let mut entries = Vec::new();
+    for entry in iter {
+        let Ok(entry) = entry else {
+            break;
+        };
+        let offset = entry.raw_file_position() as usize;
+        let size_byte = entry.size() as usize;
+        if offset + size_byte > bytes.len() {
+            break;
+        }
+        let header = entry.header();
+        let entry_type = header.entry_type();
+        if !entry_type.is_file() && !entry_type.is_dir() {
+            continue;
+        }
+        let Ok(path) = entry.path() else {
+            continue;
+        };
+        let mut path = path.to_string_lossy().into_owned();
+        if entry_type.is_dir() && !path.ends_with('/') {
+            path.push('/');
+        }
+        let last_modified = header
+            .mtime()
+            .ok()
+            .and_then(|t| DateTime::from_timestamp(t as i64, 0))
+            .map(|t| t.with_timezone(&Local));
+        entries.push(ArchiveEntry {
+            is_dir: entry_type.is_dir(),
+            path,
+            size_byte,
+            last_modified,
+            location: EntryLocation::Tar { offset },
+        });
+    }
+    if entries.is_empty() && !infer::archive::is_tar(bytes) {
+        return Err(AppError::msg("Not a tar file"));
+    }
+    Ok(entries)
+}
+
+/// Returns the relative path to extract an entry to, or `None` if the path points outside the destination.
+pub fn entry_extract_path(path: &str) -> Option<PathBuf> {
+    let mut extract_path = PathBuf::new();
+    for component in Path::new(path).components() {
+        match component {
+            Component::Normal(c) => extract_path.push(c),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    (!extract_path.as_os_str().is_empty()).then_some(extract_path)
+}
+
+fn find_zip_extra_field(mut extra: &[u8], id: u16) -> Option<&[u8]> {
+    while extra.len() >= 4 {
+        let field_id = read_u16(extra, 0)?;
+        let size = read_u16(extra, 2)? as usize;
+        let data = extra.get(4..4 + size)?;
+        if field_id == id {
+            return Some(data);
+        }
+        extra = &extra[4 + size..];
+    }
+    None
+}
+
+fn from_dos_datetime(date: u16, time: u16) -> Option<DateTime<Local>> {
+    let year = (date >> 9) as i32 + 1980;
+    let month = ((date >> 5) & 0x0F) as u32;
+    let day = (date & 0x1F) as u32;
+    let hour = (time >> 11) as u32;
+    let min = ((time >> 5) & 0x3F) as u32;
+    let sec = ((time & 0x1F) * 2) as u32;
+    NaiveDate::from_ymd_opt(year, month, day)?
+        .and_hms_opt(hour, min, sec)?
+        .and_local_timezone(Local)
+        .single()
+}
+
+fn read_u16(bytes: &[u8], pos: usize) -> Option<u16> {
+    let b = bytes.get(pos..pos + 2)?;
+    Some(u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> Option<u32> {
+    let b = bytes.get(pos..pos + 4)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_u64(bytes: &[u8], pos: usize) -> Option<u64> {
+    let b = bytes.get(pos..pos + 8)?;
+    Some(u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_read_zip() {
+        let zip = build_zip(&[
+            ("dir/", b"", ZIP_METHOD_STORED),
+            ("dir/a.txt", b"Hello, world!", ZIP_METHOD_STORED),
+            ("dir/b.txt", &b"abc".repeat(100), ZIP_METHOD_DEFLATED),
+        ]);
+
+        let tail_start = zip.len().saturating_sub(ZIP_TAIL_SIZE);
+        let range = zip_central_directory_range(&zip[tail_start..], tail_start).unwrap();
+        let entries = parse_zip_central_directory(&zip[range.start..range.end]).unwrap();
+
+        let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["dir/", "dir/a.txt", "dir/b.txt"]);
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[1].name(), "a.txt");
+        assert_eq!(entries[2].size_byte, 300);
+        assert_eq!(
+            entries[2]
+                .last_modified
+                .unwrap()
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            "2024-01-02 13:01:02"
+        );
+
+        for (entry, expected) in [
+            (&entries[1], b"Hello, world!".to_vec()),
+            (&entries[2], b"abc".repeat(100)),
+        ] {
+            let EntryLocation::Zip {
+                local_header_offset,
+                compressed_size_byte,
+                method,
+            } = entry.location
+            else {
+                panic!("unexpected location");
+            };
+            let local_header = &zip[local_header_offset..];
+            let range =
+                zip_entry_data_range(local_header, local_header_offset, compressed_size_byte)
+                    .unwrap();
+            let actual = decompress_zip_entry(method, &zip[range.start..range.end], 0).unwrap();
+            assert_eq!(actual.bytes, expected);
+            assert!(!actual.truncated);
+        }
+    }
+
+    #[test]
+    fn test_zip_central_directory_range_not_zip() {
+        let actual = zip_central_directory_range(b"not a zip file", 0);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_zip_central_directory_range_truncated_eocd() {
+        let mut tail = ZIP_EOCD_SIGNATURE.to_le_bytes().to_vec();
+        tail.extend([0; 8]);
+        let actual = zip_central_directory_range(&tail, 0);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_zip_central_directory_range_overflowing_zip64() {
+        let mut zip64_eocd = ZIP64_EOCD_SIGNATURE.to_le_bytes().to_vec();
+        zip64_eocd.extend([0; 28]);
+        zip64_eocd.extend(1u64.to_le_bytes()); // entries
+        zip64_eocd.extend(u64::MAX.to_le_bytes()); // size
+        zip64_eocd.extend(u64::MAX.to_le_bytes()); // offset
+
+        let mut tail = zip64_eocd;
+        tail.extend(ZIP64_EOCD_LOCATOR_SIGNATURE.to_le_bytes());
+        tail.extend([0; 4]);
+        tail.extend(0u64.to_le_bytes()); // zip64 end of central directory offset
+        tail.extend([0; 4]);
+        tail.extend(ZIP_EOCD_SIGNATURE.to_le_bytes());
+        tail.extend([0; 6]);
+        tail.extend([0xFF; 10]); // entries, size and offset are stored in zip64
+        tail.extend([0; 2]);
+
+        let actual = zip_central_directory_range(&tail, 0);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_zip_entry_data_range_overflow() {
+        let mut local_header = ZIP_LOCAL_HEADER_SIGNATURE.to_le_bytes().to_vec();
+        local_header.extend([0; ZIP_LOCAL_HEADER_SIZE - 4]);
+        let actual = zip_entry_data_range(&local_header, 0, usize::MAX);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_parse_tar() {
+        let mut builder = tar::Builder::new(Vec::new());
+        append_tar_entry(&mut builder, "dir/a.txt", b"Hello, world!");
+        append_tar_entry(&mut builder, "dir/b.txt", &b"abc".repeat(200));
+        let tar = builder.into_inner().unwrap();
+
+        let entries = parse_tar(&tar).unwrap();
+        let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["dir/a.txt", "dir/b.txt"]);
+
+        let EntryLocation::Tar { offset } = entries[0].location else {
+            panic!("unexpected location");
+        };
+        assert_eq!(
+            &tar[offset..offset + entries[0].size_byte],
+            b"Hello, world!"
+        );
+
+        // only the entries that are completely loaded are listed
+        let entries = parse_tar(&tar[..1024]).unwrap();
+        let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["dir/a.txt"]);
+    }
+
+    #[rstest]
+    #[case("a/b.txt", Some("a/b.txt"))]
+    #[case("./a/b.txt", Some("a/b.txt"))]
+    #[case("/a/b.txt", None)]
+    #[case("a/../../b.txt", None)]
+    #[case("", None)]
+    fn test_entry_extract_path(#[case] path: &str, #[case] expected: Option<&str>) {
+        assert_eq!(entry_extract_path(path), expected.map(PathBuf::from));
+    }
+
+    fn build_zip(files: &[(&str, &[u8], u16)]) -> Vec<u8> {
+        // 2024-01-02 13:01:02
+        let date: u16 = ((2024 - 1980) << 9) | (1 << 5) | 2;
+        let time: u16 = (13 << 11) | (1 << 5) | 1;
+
+        let mut zip = Vec::new();
+        let mut central_directory = Vec::new();
+        for (name, data, method) in files {
+            let compressed = if *method == ZIP_METHOD_DEFLATED {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            } else {
+                data.to_vec()
+            };
+            let offset = zip.len() as u32;
+
+            zip.extend(ZIP_LOCAL_HEADER_SIGNATURE.to_le_bytes());
+            zip.extend([20, 0, 0, 0]); // version, flags
+            zip.extend(method.to_le_bytes());
+            zip.extend(time.to_le_bytes());
+            zip.extend(date.to_le_bytes());
+            zip.extend([0; 4]); // crc32
+            zip.extend((compressed.len() as u32).to_le_bytes());
+            zip.extend((data.len() as u32).to_le_bytes());
+            zip.extend((name.len() as u16).to_le_bytes());
+            zip.extend([0, 0]); // extra length
+            zip.extend(name.as_bytes());
+            zip.extend(&compressed);
+
+            central_directory.extend(ZIP_CENTRAL_DIRECTORY_HEADER_SIGNATURE.to_le_bytes());
+            central_directory.extend([20, 0, 20, 0, 0, 0]); // versions, flags
+            central_directory.extend(method.to_le_bytes());
+            central_directory.extend(time.to_le_bytes());
+            central_directory.extend(date.to_le_bytes());
+            central_directory.extend([0; 4]); // crc32
+            central_directory.extend((compressed.len() as u32).to_le_bytes());
+            central_directory.extend((data.len() as u32).to_le_bytes());
+            central_directory.extend((name.len() as u16).to_le_bytes());
+            central_directory.extend([0; 12]); // extra, comment, disk, attributes
+            central_directory.extend(offset.to_le_bytes());
+            central_directory.extend(name.as_bytes());
+        }
+        let central_directory_offset = zip.len() as u32;
+        zip.extend(&central_directory);
+
+        zip.extend(ZIP_EOCD_SIGNATURE.to_le_bytes());
+        zip.extend([0; 4]); // disks
+        zip.extend((files.len() as u16).to_le_bytes());
+        zip.extend((files.len() as u16).to_le_bytes());
+        zip.extend((central_directory.len() as u32).to_le_bytes());
+        zip.extend(central_directory_offset.to_le_bytes());
+        zip.extend([0, 0]); // comment length
+        zip
+    }
+
+    fn append_tar_entry(builder: &mut tar::Builder<Vec<u8>>, path: &str, data: &[u8]) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(1704200462);
+        header.set_cksum();
+        builder.append_data(&mut header, path, data).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, object::ObjectKey};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub name: String,
+    // `None` means the default credentials
+    pub profile: Option<String>,
+    pub object_key: ObjectKey,
+}
+
+impl Bookmark {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(false)
+        )
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BookmarksFile {
+    #[serde(default)]
+    bookmarks: Vec<BookmarkEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BookmarkEntry {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+    bucket: String,
+    #[serde(default)]
+    prefix: String,
+}
+
+impl From<BookmarkEntry> for Bookmark {
+    fn from(entry: BookmarkEntry) -> Self {
+        Bookmark {
+            name: entry.name,
+            profile: entry.profile,
+            object_key: ObjectKey::with_prefix(entry.bucket, entry.prefix),
+        }
+    }
+}
+
+impl From<&Bookmark> for BookmarkEntry {
+    fn from(bookmark: &Bookmark) -> Self {
+        BookmarkEntry {
+            name: bookmark.name.clone(),
+            profile: bookmark.profile.clone(),
+            bucket: bookmark.object_key.bucket_name.clone(),
+            prefix: bookmark.object_key.joined_object_path(false),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    bookmarks: Vec<Bookmark>,
+}
+
+impl Bookmarks {
+    pub fn load() -> anyhow::Result<Bookmarks> {
+        let path = Config::bookmarks_file_path()?;
+        if path.exists() {
+            let content = std::fs::read_to_string(path)?;
+            Bookmarks::from_toml(&content)
+        } else {
+            Ok(Bookmarks::default())
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Config::bookmarks_file_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_toml()?)?;
+        Ok(())
+    }
+
+    fn from_toml(content: &str) -> anyhow::Result<Bookmarks> {
+        let file: BookmarksFile = toml::from_str(content)?;
+        let bookmarks = file.bookmarks.into_iter().map(Bookmark::from).collect();
+        Ok(Bookmarks { bookmarks })
+    }
+
+    fn to_toml(&self) -> anyhow::Result<String> {
+        let file = BookmarksFile {
+            bookmarks: self.bookmarks.iter().map(BookmarkEntry::from).collect(),
+        };
+        Ok(toml::to_string(&file)?)
+    }
+
+    pub fn list(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|b| b.name == name)
+    }
+
+    /// Adds the bookmark, or replaces the one with the same name.
+    pub fn add(&mut self, bookmark: Bookmark) {
+        match self.bookmarks.iter_mut().find(|b| b.name == bookmark.name) {
+            Some(b) => *b = bookmark,
+            None => self.bookmarks.push(bookmark),
+        }
+    }
+
+    /// Removes the bookmark, returns false if it does not exist.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.bookmarks.len();
+        self.bookmarks.retain(|b| b.name != name);
+        self.bookmarks.len() != len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmarks_toml() {
+        let content = r#"
+[[bookmarks]]
+name = "logs"
+profile = "prod"
+bucket = "bucket-1"
+prefix = "logs/2024/"
+
+[[bookmarks]]
+name = "root"
+bucket = "bucket-2"
+"#;
+        let bookmarks = Bookmarks::from_toml(content).unwrap();
+        assert_eq!(
+            bookmarks.list(),
+            &[
+                Bookmark {
+                    name: "logs".into(),
+                    profile: Some("prod".into()),
+                    object_key: ObjectKey {
+                        bucket_name: "bucket-1".into(),
+                        object_path: vec!["logs".into(), "2024".into()],
+                    },
+                },
+                Bookmark {
+                    name: "root".into(),
+                    profile: None,
+                    object_key: ObjectKey::bucket("bucket-2"),
+                },
+            ]
+        );
+        assert_eq!(bookmarks.list()[0].s3_uri(), "s3://bucket-1/logs/2024/");
+        assert_eq!(bookmarks.list()[1].s3_uri(), "s3://bucket-2/");
+
+        let actual = Bookmarks::from_toml(&bookmarks.to_toml().unwrap()).unwrap();
+        assert_eq!(actual.list(), bookmarks.list());
+
+        assert!(Bookmarks::from_toml("").unwrap().list().is_empty());
+    }
+
+    #[test]
+    fn test_bookmarks_add_remove() {
+        let bookmark = |name: &str, bucket: &str| Bookmark {
+            name: name.into(),
+            profile: None,
+            object_key: ObjectKey::bucket(bucket),
+        };
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add(bookmark("a", "bucket-1"));
+        bookmarks.add(bookmark("b", "bucket-2"));
+        bookmarks.add(bookmark("a", "bucket-3"));
+
+        assert_eq!(
+            bookmarks.list(),
+            &[bookmark("a", "bucket-3"), bookmark("b", "bucket-2")]
+        );
+        assert_eq!(bookmarks.find("b"), Some(&bookmark("b", "bucket-2")));
+
+        assert!(bookmarks.remove("a"));
+        assert!(!bookmarks.remove("a"));
+        assert_eq!(bookmarks.list(), &[bookmark("b", "bucket-2")]);
+        assert_eq!(bookmarks.find("a"), None);
+    }
+}
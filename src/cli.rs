@@ -0,0 +1,709 @@
+use std::{
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Local};
+use clap::Subcommand;
+use serde::{Serialize, Serializer};
+
+use crate::{
+    archive::entry_extract_path,
+    client::{retry_item, run_bulk_operation, Client},
+    command::{parse_location_target, LocationTarget},
+    config::{Config, UiObjectListConfig},
+    error::{AppError, ErrorKind},
+    file::create_binary_file,
+    format::{format_datetime, format_size_byte},
+    object::{BucketItem, BulkReport, DownloadObjectInfo, ObjectItem},
+};
+
+/// Subcommands that run without starting the TUI
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List the buckets, or the objects under the location
+    Ls {
+        /// Location (S3 URI, ARN or URL) of the bucket or the directory
+        #[arg(value_name = "TARGET")]
+        target: Option<String>,
+
+        /// List all objects under the location recursively
+        #[arg(short, long)]
+        recursive: bool,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Download the object, or all objects under the directory
+    Get {
+        /// Location (S3 URI, ARN or URL) of the object or the directory
+        #[arg(value_name = "SOURCE")]
+        source: String,
+
+        /// Local path to save to (defaults to the name of the source in the current directory)
+        #[arg(value_name = "DEST")]
+        dest: Option<PathBuf>,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Copy the object, or all objects under the directory, within S3
+    Cp {
+        /// Location (S3 URI, ARN or URL) of the object or the directory
+        #[arg(value_name = "SOURCE")]
+        source: String,
+
+        /// Location (S3 URI, ARN or URL) to copy to
+        #[arg(value_name = "DEST")]
+        dest: String,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Write the content of the object to the standard output
+    Cat {
+        /// Location (S3 URI, ARN or URL) of the object
+        #[arg(value_name = "TARGET")]
+        target: String,
+    },
+    /// Show the total size and count of the objects under the location
+    Du {
+        /// Location (S3 URI, ARN or URL) of the bucket or the directory
+        #[arg(value_name = "TARGET")]
+        target: String,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub async fn run<C: Client>(client: &C, command: Command, config: &Config) -> anyhow::Result<()> {
+    match command {
+        Command::Ls {
+            target,
+            recursive,
+            json,
+        } => ls(client, target, recursive, json, config).await,
+        Command::Get { source, dest, json } => get(client, source, dest, json, config).await,
+        Command::Cp { source, dest, json } => cp(client, source, dest, json, config).await,
+        Command::Cat { target } => cat(client, target).await,
+        Command::Du { target, json } => du(client, target, json).await,
+    }
+}
+
+pub fn read_mfa_token_code() -> anyhow::Result<String> {
+    eprint!("MFA token code: ");
+    std::io::stderr().flush()?;
+    let mut code = String::new();
+    std::io::stdin().read_line(&mut code)?;
+    Ok(code.trim().to_string())
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ListEntry {
+    Bucket {
+        name: String,
+        s3_uri: String,
+    },
+    Dir {
+        name: String,
+        key: String,
+        s3_uri: String,
+    },
+    File {
+        name: String,
+        key: String,
+        size_byte: usize,
+        // not available when listing recursively
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_datetime"
+        )]
+        last_modified: Option<DateTime<Local>>,
+        s3_uri: String,
+    },
+}
+
+impl From<BucketItem> for ListEntry {
+    fn from(item: BucketItem) -> Self {
+        ListEntry::Bucket {
+            name: item.name,
+            s3_uri: item.s3_uri,
+        }
+    }
+}
+
+impl From<ObjectItem> for ListEntry {
+    fn from(item: ObjectItem) -> Self {
+        match item {
+            ObjectItem::Dir {
+                name, key, s3_uri, ..
+            } => ListEntry::Dir { name, key, s3_uri },
+            ObjectItem::File {
+                name,
+                size_byte,
+                last_modified,
+                key,
+                s3_uri,
+                ..
+            } => ListEntry::File {
+                name,
+                key,
+                size_byte,
+                last_modified: Some(last_modified),
+                s3_uri,
+            },
+        }
+    }
+}
+
+impl ListEntry {
+    fn line(&self, config: &UiObjectListConfig) -> String {
+        let date_w = config.date_width;
+        match self {
+            ListEntry::Bucket { name, .. } => name.clone(),
+            ListEntry::Dir { name, .. } => format!("{:date_w$}  {:>10}  {name}/", "", "DIR"),
+            ListEntry::File {
+                name,
+                size_byte,
+                last_modified,
+                ..
+            } => {
+                let date = last_modified
+                    .map(|d| format_datetime(&d, &config.date_format))
+                    .unwrap_or_default();
+                let size = format_size_byte(*size_byte);
+                format!("{date:date_w$}  {size:>10}  {name}")
+            }
+        }
+    }
+}
+
+fn serialize_datetime<S: Serializer>(
+    datetime: &Option<DateTime<Local>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match datetime {
+        Some(d) => serializer.serialize_str(&d.to_rfc3339()),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DiskUsage {
+    s3_uri: String,
+    count: usize,
+    size_byte: usize,
+}
+
+impl DiskUsage {
+    fn line(&self) -> String {
+        format!(
+            "{}  {} objects  {}",
+            format_size_byte(self.size_byte),
+            self.count,
+            self.s3_uri
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TransferReport {
+    source: String,
+    dest: String,
+    count: usize,
+    size_byte: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failed: Vec<FailedEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct FailedEntry {
+    key: String,
+    error: String,
+}
+
+impl TransferReport {
+    fn from_bulk_report(source: String, dest: String, report: BulkReport) -> Self {
+        let failed = report
+            .failed
+            .into_iter()
+            .map(|f| FailedEntry {
+                key: f.obj.key,
+                error: f.error,
+            })
+            .collect();
+        TransferReport {
+            source,
+            dest,
+            count: report.succeeded_count,
+            size_byte: report.succeeded_size_byte,
+            failed,
+        }
+    }
+
+    fn lines(&self, verb: &str) -> Vec<String> {
+        let mut lines = vec![format!(
+            "{verb} {} objects ({}): {} -> {}",
+            self.count,
+            format_size_byte(self.size_byte),
+            self.source,
+            self.dest
+        )];
+        for f in &self.failed {
+            lines.push(format!("Failed: {}: {}", f.key, f.error));
+        }
+        lines
+    }
+
+    fn into_result(self) -> anyhow::Result<()> {
+        if self.failed.is_empty() {
+            Ok(())
+        } else {
+            anyhow::bail!("{} objects failed", self.failed.len())
+        }
+    }
+}
+
+async fn ls<C: Client>(
+    client: &C,
+    target: Option<String>,
+    recursive: bool,
+    json: bool,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let entries: Vec<ListEntry> = match target {
+        None => {
+            let buckets = client.load_all_buckets().await.map_err(to_anyhow)?;
+            buckets.into_iter().map(ListEntry::from).collect()
+        }
+        Some(target) => {
+            let target = parse_target(&target)?;
+            let prefix = target.key.clone();
+            if !target.is_dir() {
+                vec![load_file_entry(client, &target).await?]
+            } else if recursive {
+                let objs = client
+                    .list_all_download_objects(&target.bucket, &prefix)
+                    .await
+                    .map_err(to_anyhow)?;
+                objs.into_iter()
+                    .map(|obj| recursive_file_entry(&target.bucket, &prefix, obj))
+                    .collect()
+            } else {
+                let items = client
+                    .load_objects(&target.bucket, &prefix)
+                    .await
+                    .map_err(to_anyhow)?;
+                items.into_iter().map(ListEntry::from).collect()
+            }
+        }
+    };
+
+    if json {
+        print_json(&entries)
+    } else {
+        let config = &config.ui.object_list;
+        print_lines(entries.iter().map(|e| e.line(config)))
+    }
+}
+
+async fn load_file_entry<C: Client>(
+    client: &C,
+    target: &LocationTarget,
+) -> anyhow::Result<ListEntry> {
+    let name = target.key.rsplit('/').next().unwrap_or_default();
+    let detail = client
+        .load_object_detail(&target.bucket, &target.key, name)
+        .await
+        .map_err(|e| match e.kind {
+            ErrorKind::NoSuchKey => anyhow::anyhow!(
+                "Object not found: {} (add a trailing slash to list a directory)",
+                s3_uri(&target.bucket, &target.key)
+            ),
+            _ => to_anyhow(e),
+        })?;
+    Ok(ListEntry::File {
+        name: detail.name,
+        key: detail.key,
+        size_byte: detail.size_byte,
+        last_modified: Some(detail.last_modified),
+        s3_uri: detail.s3_uri,
+    })
+}
+
+fn recursive_file_entry(bucket: &str, prefix: &str, obj: DownloadObjectInfo) -> ListEntry {
+    let name = obj.key.strip_prefix(prefix).unwrap_or(&obj.key).to_string();
+    ListEntry::File {
+        name,
+        s3_uri: s3_uri(bucket, &obj.key),
+        key: obj.key,
+        size_byte: obj.size_byte,
+        last_modified: None,
+    }
+}
+
+async fn get<C: Client>(
+    client: &C,
+    source: String,
+    dest: Option<PathBuf>,
+    json: bool,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let target = parse_target(&source)?;
+    let source = s3_uri(&target.bucket, &target.key);
+
+    let report = if target.is_dir() {
+        let dir = dest.unwrap_or_else(|| PathBuf::from(dir_name(&target)));
+        let objs = client
+            .list_all_download_objects(&target.bucket, &target.key)
+            .await
+            .map_err(to_anyhow)?;
+        let item_max_attempts = config.request.item_max_attempts;
+        let download = |obj: DownloadObjectInfo| {
+            let path = local_object_path(&dir, &target.key, &obj.key);
+            let bucket = &target.bucket;
+            async move {
+                let Some(path) = path else {
+                    let msg = format!("Unsafe key to save as a local path: {}", obj.key);
+                    return Err(AppError::msg(msg));
+                };
+                let result = retry_item(item_max_attempts, || async {
+                    // recreate the file so that a retry does not append to partial content
+                    let mut writer = create_binary_file(&path)?;
+                    client
+                        .download_object(bucket, &obj.key, None, &mut writer, |_| {})
+                        .await
+                })
+                .await;
+                if result.is_err() {
+                    // do not leave a partially written file
+                    let _ = std::fs::remove_file(&path);
+                }
+                result
+            }
+        };
+        let report =
+            run_bulk_operation(objs, config.max_concurrent_requests, download, |_| {}).await;
+        let dest = dir.to_string_lossy().into();
+        TransferReport::from_bulk_report(source, dest, report)
+    } else {
+        let name = target.key.rsplit('/').next().unwrap_or_default();
+        let path = download_path(dest, name);
+        let mut writer = create_binary_file(&path).map_err(to_anyhow)?;
+        let result = client
+            .download_object(
+                &target.bucket,
+                &target.key,
+                target.version_id,
+                &mut writer,
+                |_| {},
+            )
+            .await;
+        if let Err(e) = result {
+            // do not leave the empty or partial file
+            let _ = std::fs::remove_file(&path);
+            return Err(to_anyhow(e));
+        }
+        let size_byte = std::fs::metadata(&path)?.len() as usize;
+        TransferReport {
+            source,
+            dest: path.to_string_lossy().into(),
+            count: 1,
+            size_byte,
+            failed: vec![],
+        }
+    };
+
+    if json {
+        print_json(&report)?;
+    } else {
+        print_lines(report.lines("Downloaded").into_iter())?;
+    }
+    report.into_result()
+}
+
+async fn cp<C: Client>(
+    client: &C,
+    source: String,
+    dest: String,
+    json: bool,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let src = parse_target(&source)?;
+    let dst = parse_target(&dest)?;
+    if src.version_id.is_some() {
+        anyhow::bail!("Copying a specific version is not supported: {source}");
+    }
+
+    let report = if src.is_dir() {
+        if src.key.is_empty() {
+            anyhow::bail!("Copying a whole bucket is not supported: {source}");
+        }
+        if !dst.is_dir() {
+            anyhow::bail!("Destination must be a directory: {dest}");
+        }
+        let report = client
+            .copy_prefix(
+                &src.bucket,
+                &src.key,
+                &dst.bucket,
+                &dst.key,
+                config.max_concurrent_requests,
+                |_, _| {},
+            )
+            .await
+            .map_err(to_anyhow)?;
+        TransferReport::from_bulk_report(
+            s3_uri(&src.bucket, &src.key),
+            s3_uri(&dst.bucket, &dst.key),
+            report,
+        )
+    } else {
+        let name = src.key.rsplit('/').next().unwrap_or_default();
+        let dst_key = copy_dest_key(&dst.key, name);
+        // also checks that the source exists before copying
+        let detail = client
+            .load_object_detail(&src.bucket, &src.key, name)
+            .await
+            .map_err(to_anyhow)?;
+        client
+            .copy_object(&src.bucket, &src.key, &dst.bucket, &dst_key)
+            .await
+            .map_err(to_anyhow)?;
+        TransferReport {
+            source: s3_uri(&src.bucket, &src.key),
+            dest: s3_uri(&dst.bucket, &dst_key),
+            count: 1,
+            size_byte: detail.size_byte,
+            failed: vec![],
+        }
+    };
+
+    if json {
+        print_json(&report)?;
+    } else {
+        print_lines(report.lines("Copied").into_iter())?;
+    }
+    report.into_result()
+}
+
+async fn cat<C: Client>(client: &C, target: String) -> anyhow::Result<()> {
+    let target = parse_target(&target)?;
+    if target.is_dir() {
+        anyhow::bail!(
+            "Target is not an object: {}",
+            s3_uri(&target.bucket, &target.key)
+        );
+    }
+    let mut writer = BufWriter::new(std::io::stdout());
+    client
+        .download_object(
+            &target.bucket,
+            &target.key,
+            target.version_id,
+            &mut writer,
+            |_| {},
+        )
+        .await
+        .map_err(to_anyhow)?;
+    // the buffered output would be discarded with its error when dropped
+    writer.flush()?;
+    Ok(())
+}
+
+async fn du<C: Client>(client: &C, target: String, json: bool) -> anyhow::Result<()> {
+    let target = parse_target(&target)?;
+    let prefix = dir_prefix(&target.key);
+    let objs = client
+        .list_all_download_objects(&target.bucket, &prefix)
+        .await
+        .map_err(to_anyhow)?;
+    let usage = DiskUsage {
+        s3_uri: s3_uri(&target.bucket, &prefix),
+        count: objs.len(),
+        size_byte: objs.iter().map(|obj| obj.size_byte).sum(),
+    };
+
+    if json {
+        print_json(&usage)
+    } else {
+        print_lines(std::iter::once(usage.line()))
+    }
+}
+
+fn parse_target(input: &str) -> anyhow::Result<LocationTarget> {
+    parse_location_target(input).ok_or_else(|| anyhow::anyhow!("Invalid location: {input}"))
+}
+
+fn to_anyhow(e: AppError) -> anyhow::Error {
+    anyhow::anyhow!(e.detailed_msg())
+}
+
+fn s3_uri(bucket: &str, key: &str) -> String {
+    format!("s3://{bucket}/{key}")
+}
+
+// `du` always treats the target as a directory
+fn dir_prefix(key: &str) -> String {
+    if key.is_empty() || key.ends_with('/') {
+        key.to_string()
+    } else {
+        format!("{key}/")
+    }
+}
+
+fn dir_name(target: &LocationTarget) -> &str {
+    target
+        .key
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or(&target.bucket)
+}
+
+// keys can contain `..` or start with `/`, which must not be written outside the directory
+fn local_object_path(dir: &Path, prefix: &str, key: &str) -> Option<PathBuf> {
+    let relative_path = key.strip_prefix(prefix).unwrap_or(key);
+    entry_extract_path(relative_path).map(|path| dir.join(path))
+}
+
+fn download_path(dest: Option<PathBuf>, name: &str) -> PathBuf {
+    match dest {
+        None => PathBuf::from(name),
+        Some(dest) if is_dir_path(&dest) => dest.join(name),
+        Some(dest) => dest,
+    }
+}
+
+fn is_dir_path(path: &Path) -> bool {
+    path.is_dir() || path.to_string_lossy().ends_with(std::path::MAIN_SEPARATOR)
+}
+
+fn copy_dest_key(dst_key: &str, name: &str) -> String {
+    if dst_key.is_empty() || dst_key.ends_with('/') {
+        format!("{dst_key}{name}")
+    } else {
+        dst_key.to_string()
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> anyhow::Result<()> {
+    let mut out = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut out, value)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn print_lines(lines: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let mut out = std::io::stdout().lock();
+    for line in lines {
+        writeln!(out, "{line}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::*;
+
+    fn file_entry(last_modified: Option<DateTime<Local>>) -> ListEntry {
+        ListEntry::File {
+            name: "a.txt".into(),
+            key: "dir/a.txt".into(),
+            size_byte: 1536,
+            last_modified,
+            s3_uri: "s3://bucket/dir/a.txt".into(),
+        }
+    }
+
+    #[test]
+    fn test_list_entry_line() {
+        let config = UiObjectListConfig::default();
+        let last_modified = Local.with_ymd_and_hms(2024, 1, 2, 13, 4, 5).unwrap();
+
+        let bucket = ListEntry::Bucket {
+            name: "bucket".into(),
+            s3_uri: "s3://bucket/".into(),
+        };
+        let dir = ListEntry::Dir {
+            name: "logs".into(),
+            key: "dir/logs/".into(),
+            s3_uri: "s3://bucket/dir/logs/".into(),
+        };
+        assert_eq!(bucket.line(&config), "bucket");
+        assert_eq!(dir.line(&config), "                            DIR  logs/");
+        assert_eq!(
+            file_entry(Some(last_modified)).line(&config),
+            "2024-01-02 13:04:05    1.50 KiB  a.txt"
+        );
+        assert_eq!(
+            file_entry(None).line(&config),
+            "                       1.50 KiB  a.txt"
+        );
+    }
+
+    #[test]
+    fn test_list_entry_json() {
+        let actual = serde_json::to_string(&[file_entry(None)]).unwrap();
+        let expected = r#"[{"type":"file","name":"a.txt","key":"dir/a.txt","size_byte":1536,"s3_uri":"s3://bucket/dir/a.txt"}]"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("dir", "dir/")]
+    #[case("dir/", "dir/")]
+    fn test_dir_prefix(#[case] key: &str, #[case] expected: &str) {
+        assert_eq!(dir_prefix(key), expected);
+    }
+
+    #[rstest]
+    #[case("", "bucket")]
+    #[case("a/", "a")]
+    #[case("a/b/", "b")]
+    fn test_dir_name(#[case] key: &str, #[case] expected: &str) {
+        let target = LocationTarget {
+            bucket: "bucket".into(),
+            key: key.into(),
+            version_id: None,
+        };
+        assert_eq!(dir_name(&target), expected);
+    }
+
+    #[rstest]
+    #[case(None, "a.txt")]
+    #[case(Some("b.txt"), "b.txt")]
+    #[case(Some("out/"), "out/a.txt")]
+    fn test_download_path(#[case] dest: Option<&str>, #[case] expected: &str) {
+        let actual = download_path(dest.map(PathBuf::from), "a.txt");
+        assert_eq!(actual, PathBuf::from(expected));
+    }
+
+    #[rstest]
+    #[case("dir/a.txt", Some("out/a.txt"))]
+    #[case("dir/sub/a.txt", Some("out/sub/a.txt"))]
+    #[case("dir/../../etc/x", None)]
+    #[case("dir//etc/x", None)]
+    #[case("dir/", None)]
+    fn test_local_object_path(#[case] key: &str, #[case] expected: Option<&str>) {
+        let actual = local_object_path(Path::new("out"), "dir/", key);
+        assert_eq!(actual, expected.map(PathBuf::from));
+    }
+
+    #[rstest]
+    #[case("", "a.txt")]
+    #[case("dir/", "dir/a.txt")]
+    #[case("dir/b.txt", "dir/b.txt")]
+    fn test_copy_dest_key(#[case] dst_key: &str, #[case] expected: &str) {
+        assert_eq!(copy_dest_key(dst_key, "a.txt"), expected);
+    }
+}
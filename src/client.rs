@@ -1,25 +1,54 @@
 use std::{
+    error::Error,
     fmt::Debug,
     future::Future,
     io::{BufWriter, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::{Duration, Instant},
 };
 
-use aws_config::{default_provider::region, meta::region::RegionProviderChain, BehaviorVersion};
+use aws_config::{
+    default_provider::region, meta::region::RegionProviderChain, retry::RetryConfig,
+    timeout::TimeoutConfig, BehaviorVersion,
+};
+use aws_credential_types::provider::error::CredentialsError;
 use aws_sdk_s3::{
-    config::Region,
-    error::SdkError,
+    config::{http::HttpResponse, Region},
+    error::{ProvideErrorMetadata, SdkError},
     operation::list_objects_v2::{ListObjectsV2Error, ListObjectsV2Output},
 };
+use aws_sdk_sso::operation::get_role_credentials::GetRoleCredentialsError;
+use aws_sdk_sts::operation::{
+    assume_role::AssumeRoleError, get_session_token::GetSessionTokenError,
+};
 use chrono::TimeZone;
 use futures::StreamExt;
 
 use crate::{
-    error::{AppError, Result},
-    object::{BucketItem, DownloadObjectInfo, FileDetail, FileVersion, ObjectItem},
+    config::{RequestConfig, RetryMode},
+    credentials::{AssumeRoleCredentialsProvider, AssumeRoleOptions, MfaTokenCodeError},
+    error::{AppError, ErrorKind, Result},
+    object::{
+        BucketItem, BulkReport, ByteRange, DownloadObjectInfo, FailedObject, FileDetail,
+        FileVersion, ObjectItem,
+    },
 };
 
 const DELIMITER: &str = "/";
 
+const SSO_TOKEN_EXPIRED_MSG: &str = "SSO token has expired";
+
+// initial wait before retrying a failed item of a bulk operation, doubled for each attempt
+const ITEM_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+const ITEM_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+const COPY_OBJECT_ACTIONS: &str = "s3:GetObject (source) and s3:PutObject (destination)";
+const DELETE_OBJECT_ACTIONS: &str = "s3:DeleteObject";
+
+#[derive(Clone)]
 pub enum AddressingStyle {
     Auto,
     Path,          // https://s3.region.amazonaws.com/bucket/key
@@ -38,13 +67,19 @@ impl AddressingStyle {
 
 #[rustfmt::skip]
 pub trait Client: Send + Sync + 'static + Debug {
-    fn region(&self) -> &str;
+    fn region(&self) -> String;
+    fn profile(&self) -> Option<String>;
+    fn set_mfa_token_code(&self, code: String);
+    fn reload_credentials(&self) -> impl Future<Output = Result<()>> + Send;
+    fn switch_profile(&self, profile: Option<String>) -> impl Future<Output = Result<()>> + Send;
     fn load_all_buckets(&self) -> impl Future<Output = Result<Vec<BucketItem>>> + Send;
     fn load_bucket(&self, name: &str) -> impl Future<Output = Result<Vec<BucketItem>>> + Send;
     fn load_objects(&self, bucket: &str, prefix: &str) -> impl Future<Output = Result<Vec<ObjectItem>>> + Send;
+    fn load_dir_names(&self, bucket: &str, prefix: &str) -> impl Future<Output = Result<Vec<String>>> + Send;
     fn load_object_detail(&self, bucket: &str, key: &str, name: &str) -> impl Future<Output = Result<FileDetail>> + Send;
     fn load_object_versions(&self, bucket: &str, key: &str) -> impl Future<Output = Result<Vec<FileVersion>>> + Send;
     fn download_object<W: std::io::Write + Send, F: Fn(usize) + Send>(&self, bucket: &str, key: &str, version_id: Option<String>, writer: &mut BufWriter<W>, f: F) -> impl Future<Output = Result<()>> + Send;
+    fn download_object_range(&self, bucket: &str, key: &str, version_id: Option<String>, range: ByteRange) -> impl Future<Output = Result<Vec<u8>>> + Send;
     fn list_all_download_objects(&self, bucket: &str, prefix: &str) -> impl Future<Output = Result<Vec<DownloadObjectInfo>>> + Send;
     fn copy_object(&self, src_bucket: &str, src_key: &str, dst_bucket: &str, dst_key: &str) -> impl Future<Output = Result<()>> + Send;
     fn copy_prefix<F: Fn(usize, usize) + Send>(
@@ -55,7 +90,20 @@ pub trait Client: Send + Sync + 'static + Debug {
         dst_prefix: &str,
         max_concurrent_requests: usize,
         f: F,
-    ) -> impl Future<Output = Result<()>> + Send;
+    ) -> impl Future<Output = Result<BulkReport>> + Send;
+    #[allow(clippy::too_many_arguments)]
+    fn copy_objects<F: Fn(usize, usize) + Send>(
+        &self,
+        src_bucket: &str,
+        src_prefix: &str,
+        dst_bucket: &str,
+        dst_prefix: &str,
+        objs: Vec<DownloadObjectInfo>,
+        max_concurrent_requests: usize,
+        f: F,
+    ) -> impl Future<Output = BulkReport> + Send;
+    fn delete_object(&self, bucket: &str, key: &str) -> impl Future<Output = Result<()>> + Send;
+    fn delete_objects<F: Fn(usize, usize) + Send>(&self, bucket: &str, objs: Vec<DownloadObjectInfo>, max_concurrent_requests: usize, f: F) -> impl Future<Output = BulkReport> + Send;
     fn open_management_console_buckets(&self) -> Result<()>;
     fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()>;
     fn open_management_console_object(&self, bucket: &str, prefix: &str) -> Result<()>;
@@ -67,6 +115,8 @@ pub async fn new(
     profile: Option<String>,
     default_region_fallback: String,
     addressing_style: AddressingStyle,
+    assume_role: AssumeRoleOptions,
+    request: RequestConfig,
 ) -> impl Client {
     AwsSdkClient::new(
         region,
@@ -74,18 +124,34 @@ pub async fn new(
         profile,
         default_region_fallback,
         addressing_style,
+        assume_role,
+        request,
     )
     .await
 }
 
 struct AwsSdkClient {
-    client: aws_sdk_s3::Client,
-    region: String,
+    client: RwLock<aws_sdk_s3::Client>,
+    region: RwLock<String>,
+    options: RwLock<AwsSdkClientOptions>,
+    mfa_token_code: Arc<Mutex<Option<String>>>,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+#[derive(Clone)]
+struct AwsSdkClientOptions {
+    region: Option<String>,
+    endpoint_url: Option<String>,
+    profile: Option<String>,
+    default_region_fallback: String,
+    addressing_style: AddressingStyle,
+    assume_role: AssumeRoleOptions,
+    request: RequestConfig,
 }
 
 impl Debug for AwsSdkClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "AwsSdkClient {{ region: {} }}", self.region)
+        write!(f, "AwsSdkClient {{ region: {} }}", self.region())
     }
 }
 
@@ -96,50 +162,286 @@ impl AwsSdkClient {
         profile: Option<String>,
         default_region_fallback: String,
         addressing_style: AddressingStyle,
+        assume_role: AssumeRoleOptions,
+        request: RequestConfig,
     ) -> AwsSdkClient {
-        let mut region_builder = region::Builder::default();
-        if let Some(profile) = &profile {
-            region_builder = region_builder.profile_name(profile);
-        }
-        let region_provider = RegionProviderChain::first_try(region.map(Region::new))
-            .or_else(region_builder.build())
-            .or_else(Region::new(default_region_fallback));
-
-        let mut config_loader =
-            aws_config::defaults(BehaviorVersion::latest()).region(region_provider);
-        if let Some(url) = &endpoint_url {
-            config_loader = config_loader.endpoint_url(url);
+        let rate_limiter = Arc::new(RateLimiter::new(request.max_requests_per_second));
+        let options = AwsSdkClientOptions {
+            region,
+            endpoint_url,
+            profile,
+            default_region_fallback,
+            addressing_style,
+            assume_role,
+            request,
+        };
+        let mfa_token_code = Arc::new(Mutex::new(None));
+
+        let (client, region) = build_s3_client(&options, &mfa_token_code).await;
+
+        AwsSdkClient {
+            client: RwLock::new(client),
+            region: RwLock::new(region),
+            options: RwLock::new(options),
+            mfa_token_code,
+            rate_limiter,
         }
-        if let Some(profile) = &profile {
-            config_loader = config_loader.profile_name(profile);
+    }
+
+    // waits for the rate limiter, so call this once per request
+    async fn s3(&self) -> aws_sdk_s3::Client {
+        self.rate_limiter.acquire().await;
+        self.s3_without_limit()
+    }
+
+    fn s3_without_limit(&self) -> aws_sdk_s3::Client {
+        // aws_sdk_s3::Client is cheap to clone
+        self.client.read().unwrap().clone()
+    }
+}
+
+async fn build_s3_client(
+    options: &AwsSdkClientOptions,
+    mfa_token_code: &Arc<Mutex<Option<String>>>,
+) -> (aws_sdk_s3::Client, String) {
+    let mut region_builder = region::Builder::default();
+    if let Some(profile) = &options.profile {
+        region_builder = region_builder.profile_name(profile);
+    }
+    let region_provider = RegionProviderChain::first_try(options.region.clone().map(Region::new))
+        .or_else(region_builder.build())
+        .or_else(Region::new(options.default_region_fallback.clone()));
+
+    let mut config_loader = aws_config::defaults(BehaviorVersion::latest()).region(region_provider);
+    if let Some(url) = &options.endpoint_url {
+        config_loader = config_loader.endpoint_url(url);
+    }
+    if let Some(profile) = &options.profile {
+        config_loader = config_loader.profile_name(profile);
+    }
+    config_loader = config_loader.retry_config(build_retry_config(&options.request));
+    if let Some(timeout_config) = build_timeout_config(&options.request) {
+        config_loader = config_loader.timeout_config(timeout_config);
+    }
+    let sdk_config = config_loader.load().await;
+
+    let force_path_style = options
+        .addressing_style
+        .to_force_path_style(sdk_config.endpoint_url());
+    let mut config_builder =
+        aws_sdk_s3::config::Builder::from(&sdk_config).force_path_style(force_path_style);
+    if options.assume_role.is_enabled() {
+        // the default credentials are used as the source credentials to call STS
+        let provider = AssumeRoleCredentialsProvider::new(
+            sdk_config.clone(),
+            options.assume_role.clone(),
+            Arc::clone(mfa_token_code),
+        );
+        config_builder = config_builder.credentials_provider(provider);
+    }
+    let config = config_builder.build();
+
+    let client = aws_sdk_s3::Client::from_conf(config);
+    let region = sdk_config.region().unwrap().to_string();
+
+    (client, region)
+}
+
+fn build_retry_config(request: &RequestConfig) -> RetryConfig {
+    let retry_config = match request.retry_mode {
+        RetryMode::Standard => RetryConfig::standard(),
+        RetryMode::Adaptive => RetryConfig::adaptive(),
+    };
+    retry_config.with_max_attempts(request.max_attempts.max(1))
+}
+
+fn build_timeout_config(request: &RequestConfig) -> Option<TimeoutConfig> {
+    if request.connect_timeout_secs.is_none() && request.read_timeout_secs.is_none() {
+        // keep the SDK defaults
+        return None;
+    }
+    let mut builder = TimeoutConfig::builder();
+    if let Some(secs) = request.connect_timeout_secs {
+        builder = builder.connect_timeout(Duration::from_secs(secs));
+    }
+    if let Some(secs) = request.read_timeout_secs {
+        builder = builder.read_timeout(Duration::from_secs(secs));
+    }
+    Some(builder.build())
+}
+
+/// Limits the number of requests sent per second.
+///
+/// Each request reserves the next available slot and waits until that time,
+/// so concurrent requests are spread evenly instead of being sent in bursts.
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Option<Duration>,
+    next: tokio::sync::Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(max_requests_per_second: Option<u32>) -> RateLimiter {
+        let interval = max_requests_per_second
+            .filter(|n| *n > 0)
+            .map(|n| Duration::from_secs(1) / n);
+        RateLimiter {
+            interval,
+            next: tokio::sync::Mutex::new(Instant::now()),
         }
-        let sdk_config = config_loader.load().await;
+    }
+
+    async fn acquire(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        let wait_until = {
+            let mut next = self.next.lock().await;
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + interval;
+            slot
+        };
+        tokio::time::sleep_until(wait_until.into()).await;
+    }
+}
 
-        let config_builder = aws_sdk_s3::config::Builder::from(&sdk_config)
-            .force_path_style(addressing_style.to_force_path_style(sdk_config.endpoint_url()));
-        let config = config_builder.build();
+/// Processes the objects concurrently and collects the results instead of stopping at the first failure.
+///
+/// If the credentials have expired, the objects that have not been started yet are skipped because they would all fail,
+/// while the objects already in progress are processed to the end.
+/// `progress` is called each time an object is processed.
+pub async fn run_bulk_operation<F, Fut, P>(
+    objs: Vec<DownloadObjectInfo>,
+    max_concurrent_requests: usize,
+    f: F,
+    mut progress: P,
+) -> BulkReport
+where
+    F: Fn(DownloadObjectInfo) -> Fut,
+    Fut: Future<Output = Result<()>>,
+    P: FnMut(&BulkReport),
+{
+    // Concurrency comes from config; clamp to at least 1.
+    let concurrency = max_concurrent_requests.max(1);
+    let mut report = BulkReport::default();
+    let stopped = AtomicBool::new(false);
+
+    let mut iter = futures::stream::iter(objs)
+        .map(|obj| {
+            let fut = f(obj.clone());
+            let stopped = &stopped;
+            async move {
+                if stopped.load(Ordering::Relaxed) {
+                    return (obj, None);
+                }
+                let result = fut.await;
+                if matches!(&result, Err(e) if e.kind == ErrorKind::ExpiredCredentials) {
+                    stopped.store(true, Ordering::Relaxed);
+                }
+                (obj, Some(result))
+            }
+        })
+        .buffered(concurrency);
 
-        let client = aws_sdk_s3::Client::from_conf(config);
-        let region = sdk_config.region().unwrap().to_string();
+    while let Some((obj, result)) = iter.next().await {
+        match result {
+            Some(Ok(())) => {
+                report.succeeded_count += 1;
+                report.succeeded_size_byte += obj.size_byte;
+            }
+            Some(Err(e)) => {
+                if e.kind == ErrorKind::ExpiredCredentials {
+                    report.credentials_expired = true;
+                }
+                report.failed.push(FailedObject {
+                    obj,
+                    error: e.detailed_msg(),
+                });
+            }
+            None => {
+                report.skipped.push(obj);
+                continue;
+            }
+        }
+        progress(&report);
+    }
+    report
+}
 
-        AwsSdkClient { client, region }
+/// Runs `f` up to `max_attempts` times while it fails with a retryable error.
+///
+/// This is used for the individual items of bulk operations,
+/// so that a transient failure of one item does not abort the whole operation.
+pub async fn retry_item<T, F, Fut>(max_attempts: u32, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Ok(v) => return Ok(v),
+            Err(e) if e.kind.is_retryable() && attempt < max_attempts => {
+                tracing::debug!("retrying item (attempt {attempt}): {}", e.msg);
+                tokio::time::sleep(item_retry_delay(attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
     }
 }
 
+fn item_retry_delay(attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+    ITEM_RETRY_BASE_DELAY
+        .saturating_mul(factor)
+        .min(ITEM_RETRY_MAX_DELAY)
+}
+
 impl Client for AwsSdkClient {
-    fn region(&self) -> &str {
-        &self.region
+    fn region(&self) -> String {
+        self.region.read().unwrap().clone()
+    }
+
+    fn profile(&self) -> Option<String> {
+        self.options.read().unwrap().profile.clone()
+    }
+
+    fn set_mfa_token_code(&self, code: String) {
+        *self.mfa_token_code.lock().unwrap() = Some(code);
+    }
+
+    async fn reload_credentials(&self) -> Result<()> {
+        // rebuild the client to discard the cached credentials and load them again from the provider chain
+        let options = self.options.read().unwrap().clone();
+        let (client, _) = build_s3_client(&options, &self.mfa_token_code).await;
+        *self.client.write().unwrap() = client;
+        Ok(())
+    }
+
+    async fn switch_profile(&self, profile: Option<String>) -> Result<()> {
+        let mut options = self.options.read().unwrap().clone();
+        options.profile = profile;
+        let (client, region) = build_s3_client(&options, &self.mfa_token_code).await;
+        *self.client.write().unwrap() = client;
+        *self.region.write().unwrap() = region;
+        *self.options.write().unwrap() = options;
+        Ok(())
     }
 
     async fn load_all_buckets(&self) -> Result<Vec<BucketItem>> {
+        let region = self.region();
         let list_buckets_result = self
-            .client
+            .s3()
+            .await
             .list_buckets()
-            .bucket_region(&self.region)
+            .bucket_region(&region)
             .send()
             .await;
-        let list_buckets_output =
-            list_buckets_result.map_err(|e| AppError::new("Failed to load buckets", e))?;
+        let list_buckets_output = list_buckets_result.map_err(|e| {
+            sdk_error("Failed to load buckets", e).with_context("s3:ListAllMyBuckets", "s3://")
+        })?;
 
         let buckets: Vec<BucketItem> = list_buckets_output
             .buckets()
@@ -148,7 +450,7 @@ impl Client for AwsSdkClient {
                 let bucket_name = bucket.name().unwrap().to_string();
                 let s3_uri = build_bucket_s3_uri(&bucket_name);
                 let arn = build_bucket_arn(&bucket_name);
-                let object_url = build_bucket_url(&self.region, &bucket_name);
+                let object_url = build_bucket_url(&region, &bucket_name);
                 BucketItem {
                     name: bucket_name,
                     s3_uri,
@@ -168,7 +470,7 @@ impl Client for AwsSdkClient {
     async fn load_bucket(&self, name: &str) -> Result<Vec<BucketItem>> {
         let s3_uri = build_bucket_s3_uri(name);
         let arn = build_bucket_arn(name);
-        let object_url = build_bucket_url(&self.region, name);
+        let object_url = build_bucket_url(&self.region(), name);
 
         let bucket = BucketItem {
             name: name.to_string(),
@@ -183,10 +485,12 @@ impl Client for AwsSdkClient {
         let mut dirs_vec: Vec<Vec<ObjectItem>> = Vec::new();
         let mut files_vec: Vec<Vec<ObjectItem>> = Vec::new();
 
+        let region = self.region();
         let mut token: Option<String> = None;
         loop {
             let result = self
-                .client
+                .s3()
+                .await
                 .list_objects_v2()
                 .bucket(bucket)
                 .prefix(prefix)
@@ -197,16 +501,20 @@ impl Client for AwsSdkClient {
 
             if let Err(SdkError::ServiceError(ref e)) = result {
                 if let ListObjectsV2Error::NoSuchBucket(_) = e.err() {
-                    return Err(AppError::msg(format!("Bucket '{bucket}' not found")));
+                    let e = AppError::msg(format!("Bucket '{bucket}' not found"));
+                    return Err(e.with_kind(ErrorKind::NoSuchBucket));
                 }
             }
 
-            let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
+            let output = result.map_err(|e| {
+                sdk_error("Failed to load objects", e)
+                    .with_context("s3:ListBucket", build_object_s3_uri(bucket, prefix))
+            })?;
 
-            let dirs = objects_output_to_dirs(&self.region, bucket, &output);
+            let dirs = objects_output_to_dirs(&region, bucket, &output);
             dirs_vec.push(dirs);
 
-            let files = objects_output_to_files(&self.region, bucket, &output);
+            let files = objects_output_to_files(&region, bucket, &output);
             files_vec.push(files);
 
             token = output.next_continuation_token().map(String::from);
@@ -220,15 +528,44 @@ impl Client for AwsSdkClient {
         Ok(di.chain(fi).collect())
     }
 
+    async fn load_dir_names(&self, bucket: &str, prefix: &str) -> Result<Vec<String>> {
+        // only the first page, as it is used for the suggestions
+        let output = self
+            .s3()
+            .await
+            .list_objects_v2()
+            .bucket(bucket)
+            .prefix(prefix)
+            .delimiter(DELIMITER)
+            .send()
+            .await
+            .map_err(|e| {
+                sdk_error("Failed to load directories", e)
+                    .with_context("s3:ListBucket", build_object_s3_uri(bucket, prefix))
+            })?;
+
+        let names = output
+            .common_prefixes()
+            .iter()
+            .filter_map(|dir| parse_path(dir.prefix()?, true).pop())
+            .filter(|name| !name.is_empty())
+            .collect();
+        Ok(names)
+    }
+
     async fn load_object_detail(&self, bucket: &str, key: &str, name: &str) -> Result<FileDetail> {
         let result = self
-            .client
+            .s3()
+            .await
             .head_object()
             .bucket(bucket)
             .key(key)
             .send()
             .await;
-        let output = result.map_err(|e| AppError::new("Failed to load object detail", e))?;
+        let output = result.map_err(|e| {
+            sdk_error("Failed to load object detail", e)
+                .with_context("s3:GetObject", build_object_s3_uri(bucket, key))
+        })?;
 
         let name = name.to_owned();
         let size_byte = output.content_length().unwrap() as usize;
@@ -243,7 +580,7 @@ impl Client for AwsSdkClient {
         let key = key.to_owned();
         let s3_uri = build_object_s3_uri(bucket, &key);
         let arn = build_object_arn(bucket, &key);
-        let object_url = build_object_url(&self.region, bucket, &key);
+        let object_url = build_object_url(&self.region(), bucket, &key);
         Ok(FileDetail {
             name,
             size_byte,
@@ -261,13 +598,17 @@ impl Client for AwsSdkClient {
 
     async fn load_object_versions(&self, bucket: &str, key: &str) -> Result<Vec<FileVersion>> {
         let result = self
-            .client
+            .s3()
+            .await
             .list_object_versions()
             .bucket(bucket)
             .prefix(key)
             .send()
             .await;
-        let output = result.map_err(|e| AppError::new("Failed to load object versions", e))?;
+        let output = result.map_err(|e| {
+            sdk_error("Failed to load object versions", e)
+                .with_context("s3:ListBucketVersions", build_object_s3_uri(bucket, key))
+        })?;
 
         let versions = output
             .versions()
@@ -298,13 +639,21 @@ impl Client for AwsSdkClient {
         writer: &mut BufWriter<W>,
         f: F,
     ) -> Result<()> {
-        let mut request = self.client.get_object().bucket(bucket).key(key);
+        let action = if version_id.is_some() {
+            "s3:GetObjectVersion"
+        } else {
+            "s3:GetObject"
+        };
+        let mut request = self.s3().await.get_object().bucket(bucket).key(key);
         if let Some(version_id) = version_id {
             request = request.version_id(version_id);
         }
 
         let result = request.send().await;
-        let output = result.map_err(|e| AppError::new("Failed to download object", e))?;
+        let output = result.map_err(|e| {
+            sdk_error("Failed to download object", e)
+                .with_context(action, build_object_s3_uri(bucket, key))
+        })?;
 
         let mut stream = output.body;
         let mut i = 0;
@@ -312,7 +661,7 @@ impl Client for AwsSdkClient {
         while let Some(buf) = stream // buf: 32 KiB
             .try_next()
             .await
-            .map_err(|e| AppError::new("Failed to collect body", e))?
+            .map_err(|e| AppError::new("Failed to collect body", e).with_kind(ErrorKind::Network))?
         {
             writer.write_all(&buf).map_err(AppError::error)?;
             total_bytes += buf.len();
@@ -330,6 +679,45 @@ impl Client for AwsSdkClient {
         Ok(())
     }
 
+    async fn download_object_range(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        range: ByteRange,
+    ) -> Result<Vec<u8>> {
+        let action = if version_id.is_some() {
+            "s3:GetObjectVersion"
+        } else {
+            "s3:GetObject"
+        };
+        if range.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut request = self
+            .s3()
+            .await
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            // the end of the Range header is inclusive
+            .range(format!("bytes={}-{}", range.start, range.end - 1));
+        if let Some(version_id) = version_id {
+            request = request.version_id(version_id);
+        }
+
+        let result = request.send().await;
+        let output = result.map_err(|e| {
+            sdk_error("Failed to download object", e)
+                .with_context(action, build_object_s3_uri(bucket, key))
+        })?;
+
+        let bytes = output.body.collect().await.map_err(|e| {
+            AppError::new("Failed to collect body", e).with_kind(ErrorKind::Network)
+        })?;
+        Ok(bytes.to_vec())
+    }
+
     async fn list_all_download_objects(
         &self,
         bucket: &str,
@@ -340,14 +728,18 @@ impl Client for AwsSdkClient {
         let mut token: Option<String> = None;
         loop {
             let result = self
-                .client
+                .s3()
+                .await
                 .list_objects_v2()
                 .bucket(bucket)
                 .prefix(prefix)
                 .set_continuation_token(token)
                 .send()
                 .await;
-            let output = result.map_err(|e| AppError::new("Failed to list download objects", e))?;
+            let output = result.map_err(|e| {
+                sdk_error("Failed to list download objects", e)
+                    .with_context("s3:ListBucket", build_object_s3_uri(bucket, prefix))
+            })?;
 
             let os = output
                 .contents()
@@ -380,7 +772,8 @@ impl Client for AwsSdkClient {
         // The SDK accepts a String and handles necessary header wiring.
         let copy_source = format!("{}/{}", src_bucket, src_key);
         let result = self
-            .client
+            .s3()
+            .await
             .copy_object()
             .bucket(dst_bucket)
             .key(dst_key)
@@ -388,9 +781,10 @@ impl Client for AwsSdkClient {
             .send()
             .await;
 
-        result
-            .map(|_| ())
-            .map_err(|e| AppError::new("Failed to copy object", e))
+        result.map(|_| ()).map_err(|e| {
+            let resource = build_copy_resource(src_bucket, src_key, dst_bucket, dst_key);
+            sdk_error("Failed to copy object", e).with_context(COPY_OBJECT_ACTIONS, resource)
+        })
     }
 
     async fn copy_prefix<F: Fn(usize, usize) + Send>(
@@ -401,26 +795,54 @@ impl Client for AwsSdkClient {
         dst_prefix: &str,
         max_concurrent_requests: usize,
         f: F,
-    ) -> Result<()> {
+    ) -> Result<BulkReport> {
         // Normalize prefixes to end with '/'
         let src_prefix = normalize_prefix(src_prefix);
-        let dst_prefix = normalize_prefix(dst_prefix);
 
         let objs = self
             .list_all_download_objects(src_bucket, &src_prefix)
             .await?;
+
+        let report = self
+            .copy_objects(
+                src_bucket,
+                &src_prefix,
+                dst_bucket,
+                dst_prefix,
+                objs,
+                max_concurrent_requests,
+                f,
+            )
+            .await;
+        Ok(report)
+    }
+
+    async fn copy_objects<F: Fn(usize, usize) + Send>(
+        &self,
+        src_bucket: &str,
+        src_prefix: &str,
+        dst_bucket: &str,
+        dst_prefix: &str,
+        objs: Vec<DownloadObjectInfo>,
+        max_concurrent_requests: usize,
+        f: F,
+    ) -> BulkReport {
+        // Normalize prefixes to end with '/'
+        let src_prefix = normalize_prefix(src_prefix);
+        let dst_prefix = normalize_prefix(dst_prefix);
+
         let total_count = objs.len();
 
-        // Concurrency comes from config; clamp to at least 1.
-        let concurrency: usize = max_concurrent_requests.max(1);
-        let s3 = self.client.clone();
+        let s3 = self.s3_without_limit();
+        let rate_limiter = &self.rate_limiter;
+        let max_attempts = self.options.read().unwrap().request.item_max_attempts;
         // Wrap repeatedly reused strings in Arc to avoid per-item cloning allocations
         let src_bucket = std::sync::Arc::new(src_bucket.to_string());
         let dst_bucket = std::sync::Arc::new(dst_bucket.to_string());
         let src_prefix = std::sync::Arc::new(src_prefix);
         let dst_prefix = std::sync::Arc::new(dst_prefix);
 
-        let mut iter = futures::stream::iter(objs.into_iter().map(|obj| {
+        let copy = |obj: DownloadObjectInfo| {
             let s3 = s3.clone();
             let src_bucket = std::sync::Arc::clone(&src_bucket);
             let dst_bucket = std::sync::Arc::clone(&dst_bucket);
@@ -430,43 +852,99 @@ impl Client for AwsSdkClient {
                 // Compute destination key preserving the suffix relative to src_prefix
                 let (_, dst_key) = compute_dst_key(&src_prefix, &dst_prefix, &obj.key);
                 let copy_source = format!("{}/{}", &**src_bucket, obj.key);
-                let result = s3
-                    .copy_object()
-                    .bucket(&**dst_bucket)
-                    .key(&dst_key)
-                    .copy_source(copy_source)
-                    .send()
-                    .await;
-                result
-                    .map(|_| ())
-                    .map_err(|e| AppError::new("Failed to copy object", e))
+                retry_item(max_attempts, || async {
+                    rate_limiter.acquire().await;
+                    let result = s3
+                        .copy_object()
+                        .bucket(&**dst_bucket)
+                        .key(&dst_key)
+                        .copy_source(&copy_source)
+                        .send()
+                        .await;
+                    result.map(|_| ()).map_err(|e| {
+                        let resource =
+                            build_copy_resource(&src_bucket, &obj.key, &dst_bucket, &dst_key);
+                        sdk_error("Failed to copy object", e)
+                            .with_context(COPY_OBJECT_ACTIONS, resource)
+                    })
+                })
+                .await
             }
-        }))
-        .buffered(concurrency);
+        };
 
-        let mut cur_count = 0usize;
         // Throttle progress callbacks to avoid overwhelming the UI.
         // Aim for at most ~50 updates; always notify on the last item.
         let notify_every: usize = (total_count / 50).max(1);
-        while let Some(res) = iter.next().await {
-            match res {
-                Ok(()) => {
-                    cur_count += 1;
-                    if cur_count % notify_every == 0 || cur_count == total_count {
-                        f(cur_count, total_count);
-                    }
-                }
-                Err(e) => return Err(e),
+        let progress = move |report: &BulkReport| {
+            let cur_count = report.processed_count();
+            if cur_count.is_multiple_of(notify_every) || cur_count == total_count {
+                f(cur_count, total_count);
             }
-        }
+        };
 
-        Ok(())
+        run_bulk_operation(objs, max_concurrent_requests, copy, progress).await
+    }
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        let result = self
+            .s3()
+            .await
+            .delete_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await;
+
+        result.map(|_| ()).map_err(|e| {
+            let resource = build_object_s3_uri(bucket, key);
+            sdk_error("Failed to delete object", e).with_context(DELETE_OBJECT_ACTIONS, resource)
+        })
+    }
+
+    async fn delete_objects<F: Fn(usize, usize) + Send>(
+        &self,
+        bucket: &str,
+        objs: Vec<DownloadObjectInfo>,
+        max_concurrent_requests: usize,
+        f: F,
+    ) -> BulkReport {
+        let total_count = objs.len();
+
+        let s3 = self.s3_without_limit();
+        let rate_limiter = &self.rate_limiter;
+        let max_attempts = self.options.read().unwrap().request.item_max_attempts;
+
+        let delete = |obj: DownloadObjectInfo| {
+            let s3 = s3.clone();
+            async move {
+                retry_item(max_attempts, || async {
+                    rate_limiter.acquire().await;
+                    let result = s3.delete_object().bucket(bucket).key(&obj.key).send().await;
+                    result.map(|_| ()).map_err(|e| {
+                        let resource = build_object_s3_uri(bucket, &obj.key);
+                        sdk_error("Failed to delete object", e)
+                            .with_context(DELETE_OBJECT_ACTIONS, resource)
+                    })
+                })
+                .await
+            }
+        };
+
+        let notify_every: usize = (total_count / 50).max(1);
+        let progress = move |report: &BulkReport| {
+            let cur_count = report.processed_count();
+            if cur_count.is_multiple_of(notify_every) || cur_count == total_count {
+                f(cur_count, total_count);
+            }
+        };
+
+        run_bulk_operation(objs, max_concurrent_requests, delete, progress).await
     }
 
     fn open_management_console_buckets(&self) -> Result<()> {
         let path = format!(
             "https://s3.console.aws.amazon.com/s3/buckets?region={}",
-            self.region
+            self.region()
         );
         open::that(path).map_err(AppError::error)
     }
@@ -474,7 +952,9 @@ impl Client for AwsSdkClient {
     fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()> {
         let path = format!(
             "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
-            bucket, self.region, prefix
+            bucket,
+            self.region(),
+            prefix
         );
         open::that(path).map_err(AppError::error)
     }
@@ -482,12 +962,133 @@ impl Client for AwsSdkClient {
     fn open_management_console_object(&self, bucket: &str, prefix: &str) -> Result<()> {
         let path = format!(
             "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}",
-            bucket, self.region, prefix
+            bucket,
+            self.region(),
+            prefix
         );
         open::that(path).map_err(AppError::error)
     }
 }
 
+fn sdk_error<E>(msg: &str, e: SdkError<E, HttpResponse>) -> AppError
+where
+    E: ProvideErrorMetadata + Error + Send + Sync + 'static,
+{
+    let kind = classify_sdk_error(&e);
+    AppError::new(msg, e).with_kind(kind)
+}
+
+fn classify_sdk_error<E>(e: &SdkError<E, HttpResponse>) -> ErrorKind
+where
+    E: ProvideErrorMetadata + Error + 'static,
+{
+    match e {
+        SdkError::ServiceError(service_error) => {
+            let code = service_error.err().code();
+            let status = service_error.raw().status().as_u16();
+            classify_service_error(code, status)
+        }
+        SdkError::TimeoutError(_) => ErrorKind::Timeout,
+        SdkError::DispatchFailure(failure) => {
+            // failures of the credentials provider (e.g. expired SSO session) are returned as dispatch failures
+            if let Some(credentials_error) = find_credentials_error(e) {
+                classify_credentials_error(credentials_error)
+            } else if failure.is_timeout() {
+                ErrorKind::Timeout
+            } else {
+                ErrorKind::Network
+            }
+        }
+        _ => ErrorKind::Unknown,
+    }
+}
+
+fn find_credentials_error<'a>(e: &'a (dyn Error + 'static)) -> Option<&'a CredentialsError> {
+    let mut source = e.source();
+    while let Some(err) = source {
+        if let Some(credentials_error) = err.downcast_ref::<CredentialsError>() {
+            return Some(credentials_error);
+        }
+        source = err.source();
+    }
+    None
+}
+
+fn classify_credentials_error(e: &CredentialsError) -> ErrorKind {
+    match e
+        .source()
+        .and_then(|s| s.downcast_ref::<MfaTokenCodeError>())
+    {
+        Some(MfaTokenCodeError::Required) => return ErrorKind::MfaTokenCodeRequired,
+        Some(MfaTokenCodeError::Invalid(_)) => return ErrorKind::InvalidMfaTokenCode,
+        None => {}
+    }
+    match e {
+        CredentialsError::ProviderError(_) if is_expired_token_error(e) => {
+            ErrorKind::ExpiredCredentials
+        }
+        // e.g. AssumeRole is denied, the role ARN is wrong or the source profile is missing
+        CredentialsError::ProviderError(_) => ErrorKind::InvalidCredentials,
+        CredentialsError::ProviderTimedOut(_) => ErrorKind::Timeout,
+        // no credentials are configured, or the configuration is invalid, so reloading does not help
+        _ => ErrorKind::Unknown,
+    }
+}
+
+fn is_expired_token_error(e: &CredentialsError) -> bool {
+    let mut source = e.source();
+    while let Some(err) = source {
+        let code = if let Some(e) = err.downcast_ref::<AssumeRoleError>() {
+            e.code()
+        } else if let Some(e) = err.downcast_ref::<GetSessionTokenError>() {
+            e.code()
+        } else if let Some(e) = err.downcast_ref::<GetRoleCredentialsError>() {
+            e.code()
+        } else if err.to_string().contains(SSO_TOKEN_EXPIRED_MSG) {
+            // the error type of the SSO token provider is private, so only the message can be checked
+            return true;
+        } else {
+            None
+        };
+        match code {
+            // SSO rejects an expired access token as unauthorized
+            Some("ExpiredToken" | "ExpiredTokenException" | "UnauthorizedException") => {
+                return true
+            }
+            Some(_) => return false,
+            None => {}
+        }
+        source = err.source();
+    }
+    false
+}
+
+fn classify_service_error(code: Option<&str>, status: u16) -> ErrorKind {
+    match code {
+        Some("ExpiredToken" | "ExpiredTokenException" | "TokenRefreshRequired") => {
+            ErrorKind::ExpiredCredentials
+        }
+        Some("AccessDenied" | "AllAccessDisabled") => ErrorKind::AccessDenied,
+        Some("NoSuchBucket") => ErrorKind::NoSuchBucket,
+        // the SDK sets "NotFound" for 404 responses without a body (e.g. HeadObject)
+        Some("NoSuchKey" | "NoSuchVersion" | "NotFound") => ErrorKind::NoSuchKey,
+        Some("SlowDown" | "Throttling" | "ThrottlingException" | "RequestLimitExceeded") => {
+            ErrorKind::Throttling
+        }
+        Some("RequestTimeout") => ErrorKind::Timeout,
+        Some("InternalError" | "ServiceUnavailable") => ErrorKind::ServiceUnavailable,
+        Some(_) => ErrorKind::Unknown,
+        // other responses without a body (e.g. 403 from HeadObject) have no error code
+        None => match status {
+            403 => ErrorKind::AccessDenied,
+            404 => ErrorKind::NoSuchKey,
+            503 => ErrorKind::Throttling,
+            500 => ErrorKind::ServiceUnavailable,
+            _ => ErrorKind::Unknown,
+        },
+    }
+}
+
 fn objects_output_to_dirs(
     region: &str,
     bucket: &str,
@@ -610,13 +1211,22 @@ fn build_object_arn(bucket: &str, key: &str) -> String {
     format!("arn:aws:s3:::{bucket}/{key}")
 }
 
+fn build_copy_resource(src_bucket: &str, src_key: &str, dst_bucket: &str, dst_key: &str) -> String {
+    let src = build_object_s3_uri(src_bucket, src_key);
+    let dst = build_object_s3_uri(dst_bucket, dst_key);
+    format!("{src} -> {dst}")
+}
+
 fn build_object_url(region: &str, bucket: &str, key: &str) -> String {
     format!("https://{bucket}.s3.{region}.amazonaws.com/{key}")
 }
 
 #[cfg(test)]
 mod tests {
-    use super::compute_dst_key;
+    use super::*;
+    use aws_sdk_s3::error::ErrorMetadata;
+    use rstest::*;
+    use std::sync::atomic::AtomicU32;
 
     #[test]
     fn test_compute_dst_key_preserves_suffix() {
@@ -630,6 +1240,195 @@ mod tests {
     fn test_compute_dst_key_when_no_prefix_match() {
         let _ = compute_dst_key("src/", "dst/", "other/x");
     }
+
+    #[rstest]
+    #[case(Some("ExpiredToken"), 400, ErrorKind::ExpiredCredentials)]
+    #[case(Some("TokenRefreshRequired"), 400, ErrorKind::ExpiredCredentials)]
+    #[case(Some("AccessDenied"), 403, ErrorKind::AccessDenied)]
+    #[case(Some("NoSuchBucket"), 404, ErrorKind::NoSuchBucket)]
+    #[case(Some("NoSuchKey"), 404, ErrorKind::NoSuchKey)]
+    #[case(Some("NotFound"), 404, ErrorKind::NoSuchKey)] // HeadObject
+    #[case(Some("SlowDown"), 503, ErrorKind::Throttling)]
+    #[case(Some("InternalError"), 500, ErrorKind::ServiceUnavailable)]
+    #[case(Some("InvalidArgument"), 400, ErrorKind::Unknown)]
+    #[case(None, 403, ErrorKind::AccessDenied)]
+    #[case(None, 404, ErrorKind::NoSuchKey)]
+    #[case(None, 503, ErrorKind::Throttling)]
+    #[case(None, 400, ErrorKind::Unknown)]
+    fn test_classify_service_error(
+        #[case] code: Option<&str>,
+        #[case] status: u16,
+        #[case] expected: ErrorKind,
+    ) {
+        assert_eq!(classify_service_error(code, status), expected);
+    }
+
+    #[rstest]
+    #[case(
+        CredentialsError::provider_error("the SSO token has expired and cannot be refreshed"),
+        ErrorKind::ExpiredCredentials
+    )]
+    #[case(
+        CredentialsError::provider_error(AssumeRoleError::generic(ErrorMetadata::builder().code("ExpiredToken").build())),
+        ErrorKind::ExpiredCredentials
+    )]
+    #[case(
+        CredentialsError::provider_error(GetRoleCredentialsError::generic(ErrorMetadata::builder().code("UnauthorizedException").build())),
+        ErrorKind::ExpiredCredentials
+    )]
+    #[case(
+        CredentialsError::provider_error(AssumeRoleError::generic(ErrorMetadata::builder().code("AccessDenied").build())),
+        ErrorKind::InvalidCredentials
+    )]
+    #[case(
+        CredentialsError::provider_error("profile `base` was not defined"),
+        ErrorKind::InvalidCredentials
+    )]
+    #[case(
+        CredentialsError::provider_timed_out(Duration::from_secs(5)),
+        ErrorKind::Timeout
+    )]
+    #[case(CredentialsError::not_loaded("no credentials"), ErrorKind::Unknown)]
+    #[case(
+        CredentialsError::not_loaded(MfaTokenCodeError::Required),
+        ErrorKind::MfaTokenCodeRequired
+    )]
+    #[case(CredentialsError::provider_error(MfaTokenCodeError::Invalid("AccessDenied".into())), ErrorKind::InvalidMfaTokenCode)]
+    #[case(
+        CredentialsError::invalid_configuration("invalid profile"),
+        ErrorKind::Unknown
+    )]
+    fn test_classify_credentials_error(#[case] e: CredentialsError, #[case] expected: ErrorKind) {
+        assert_eq!(classify_credentials_error(&e), expected);
+    }
+
+    async fn run_retry_item(max_attempts: u32, fail_count: u32, kind: ErrorKind) -> (bool, u32) {
+        let calls = AtomicU32::new(0);
+        let result = retry_item(max_attempts, || async {
+            let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if n <= fail_count {
+                Err(AppError::msg("failed").with_kind(kind))
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+        (result.is_ok(), calls.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_retry_item_retries_retryable_error() {
+        assert_eq!(run_retry_item(3, 1, ErrorKind::Throttling).await, (true, 2));
+    }
+
+    #[tokio::test]
+    async fn test_retry_item_gives_up_after_max_attempts() {
+        assert_eq!(run_retry_item(1, 1, ErrorKind::Network).await, (false, 1));
+    }
+
+    #[rstest]
+    #[case(1, Duration::from_millis(500))]
+    #[case(3, Duration::from_secs(2))]
+    #[case(7, ITEM_RETRY_MAX_DELAY)]
+    #[case(33, ITEM_RETRY_MAX_DELAY)]
+    #[case(u32::MAX, ITEM_RETRY_MAX_DELAY)]
+    fn test_item_retry_delay(#[case] attempt: u32, #[case] expected: Duration) {
+        assert_eq!(item_retry_delay(attempt), expected);
+    }
+
+    #[tokio::test]
+    async fn test_retry_item_does_not_retry_non_retryable_error() {
+        assert_eq!(
+            run_retry_item(3, 1, ErrorKind::AccessDenied).await,
+            (false, 1)
+        );
+    }
+
+    fn objs(keys: &[&str]) -> Vec<DownloadObjectInfo> {
+        keys.iter()
+            .map(|key| DownloadObjectInfo {
+                key: key.to_string(),
+                size_byte: 10,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_run_bulk_operation_continues_past_failures() {
+        let f = |obj: DownloadObjectInfo| async move {
+            if obj.key == "b" {
+                Err(AppError::msg("failed").with_kind(ErrorKind::AccessDenied))
+            } else {
+                Ok(())
+            }
+        };
+        let report = run_bulk_operation(objs(&["a", "b", "c"]), 2, f, |_| {}).await;
+
+        assert_eq!(report.succeeded_count, 2);
+        assert_eq!(report.succeeded_size_byte, 20);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].obj.key, "b");
+        assert!(report.skipped.is_empty());
+        assert!(!report.credentials_expired);
+    }
+
+    #[tokio::test]
+    async fn test_run_bulk_operation_skips_rest_when_credentials_expired() {
+        let f = |obj: DownloadObjectInfo| async move {
+            if obj.key == "b" {
+                Err(AppError::msg("failed").with_kind(ErrorKind::ExpiredCredentials))
+            } else {
+                Ok(())
+            }
+        };
+        let report = run_bulk_operation(objs(&["a", "b", "c", "d"]), 1, f, |_| {}).await;
+
+        assert_eq!(report.succeeded_count, 1);
+        assert_eq!(report.failed.len(), 1);
+        let skipped: Vec<_> = report.skipped.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(skipped, vec!["c", "d"]);
+        assert!(report.credentials_expired);
+        let retry: Vec<_> = report.retry_targets().into_iter().map(|o| o.key).collect();
+        assert_eq!(retry, vec!["b", "c", "d"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_bulk_operation_finishes_in_flight_items_when_credentials_expired() {
+        let f = |obj: DownloadObjectInfo| async move {
+            // a, b and c are started together, and c is still in progress when b fails
+            let delay_ms = match obj.key.as_str() {
+                "a" => 20,
+                "b" => 10,
+                "c" => 50,
+                _ => 0,
+            };
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            if obj.key == "b" {
+                Err(AppError::msg("failed").with_kind(ErrorKind::ExpiredCredentials))
+            } else {
+                Ok(())
+            }
+        };
+        let report = run_bulk_operation(objs(&["a", "b", "c", "d", "e"]), 3, f, |_| {}).await;
+
+        assert_eq!(report.succeeded_count, 2);
+        let failed: Vec<_> = report.failed.iter().map(|f| f.obj.key.as_str()).collect();
+        assert_eq!(failed, vec!["b"]);
+        let skipped: Vec<_> = report.skipped.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(skipped, vec!["d", "e"]);
+        assert!(report.credentials_expired);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spreads_requests() {
+        let limiter = RateLimiter::new(Some(20));
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        // the first request is sent immediately, the rest wait 50ms each
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
 }
 
 #[cfg(test)]
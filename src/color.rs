@@ -1,5 +1,12 @@
+use std::str::FromStr;
+
+use indexmap::IndexMap;
 use ratatui::style::Color;
 
+use crate::config::Config;
+
+const PRESET_NAMES: [&str; 2] = ["dark", "light"];
+
 #[derive(Debug, Clone)]
 pub struct ColorTheme {
     pub bg: Color,
@@ -19,6 +26,14 @@ pub struct ColorTheme {
     pub dialog_selected: Color,
 
     pub preview_line_number: Color,
+    pub preview_search_match_bg: Color,
+    pub preview_search_match_fg: Color,
+    pub preview_search_current_match_bg: Color,
+    pub preview_search_current_match_fg: Color,
+
+    pub diff_insert: Color,
+    pub diff_delete: Color,
+    pub diff_hunk: Color,
 
     pub help_key_fg: Color,
 
@@ -31,6 +46,12 @@ pub struct ColorTheme {
 
 impl Default for ColorTheme {
     fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl ColorTheme {
+    pub fn dark() -> Self {
         Self {
             bg: Color::Reset,
             fg: Color::Reset,
@@ -49,6 +70,14 @@ impl Default for ColorTheme {
             dialog_selected: Color::Cyan,
 
             preview_line_number: Color::DarkGray,
+            preview_search_match_bg: Color::Yellow,
+            preview_search_match_fg: Color::Black,
+            preview_search_current_match_bg: Color::Red,
+            preview_search_current_match_fg: Color::Black,
+
+            diff_insert: Color::Green,
+            diff_delete: Color::Red,
+            diff_hunk: Color::Cyan,
 
             help_key_fg: Color::Yellow,
 
@@ -59,4 +88,181 @@ impl Default for ColorTheme {
             status_error: Color::Red,
         }
     }
+
+    pub fn light() -> Self {
+        Self {
+            bg: Color::Reset,
+            fg: Color::Reset,
+
+            divider: Color::Gray,
+            link: Color::Blue,
+
+            list_selected_bg: Color::Blue,
+            list_selected_fg: Color::White,
+            list_selected_inactive_bg: Color::Gray,
+            list_selected_inactive_fg: Color::Black,
+            list_filter_match: Color::Red,
+
+            detail_selected: Color::Blue,
+
+            dialog_selected: Color::Blue,
+
+            preview_line_number: Color::Gray,
+            preview_search_match_bg: Color::Yellow,
+            preview_search_match_fg: Color::Black,
+            preview_search_current_match_bg: Color::Red,
+            preview_search_current_match_fg: Color::White,
+
+            diff_insert: Color::Green,
+            diff_delete: Color::Red,
+            diff_hunk: Color::Blue,
+
+            help_key_fg: Color::Magenta,
+
+            status_help: Color::Gray,
+            status_info: Color::Blue,
+            status_success: Color::Green,
+            status_warn: Color::Magenta,
+            status_error: Color::Red,
+        }
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    /// Loads the preset and applies the colors set in the theme file on top of it.
+    pub fn load(preset: &str) -> anyhow::Result<ColorTheme> {
+        let path = Config::theme_file_path()?;
+        let custom_theme_str = std::fs::read_to_string(path).unwrap_or_default();
+        build_color_theme(preset, &custom_theme_str).map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn color_mut(&mut self, key: &str) -> Option<&mut Color> {
+        let color = match key {
+            "bg" => &mut self.bg,
+            "fg" => &mut self.fg,
+            "divider" => &mut self.divider,
+            "link" => &mut self.link,
+            "list_selected_bg" => &mut self.list_selected_bg,
+            "list_selected_fg" => &mut self.list_selected_fg,
+            "list_selected_inactive_bg" => &mut self.list_selected_inactive_bg,
+            "list_selected_inactive_fg" => &mut self.list_selected_inactive_fg,
+            "list_filter_match" => &mut self.list_filter_match,
+            "detail_selected" => &mut self.detail_selected,
+            "dialog_selected" => &mut self.dialog_selected,
+            "preview_line_number" => &mut self.preview_line_number,
+            "preview_search_match_bg" => &mut self.preview_search_match_bg,
+            "preview_search_match_fg" => &mut self.preview_search_match_fg,
+            "preview_search_current_match_bg" => &mut self.preview_search_current_match_bg,
+            "preview_search_current_match_fg" => &mut self.preview_search_current_match_fg,
+            "diff_insert" => &mut self.diff_insert,
+            "diff_delete" => &mut self.diff_delete,
+            "diff_hunk" => &mut self.diff_hunk,
+            "help_key_fg" => &mut self.help_key_fg,
+            "status_help" => &mut self.status_help,
+            "status_info" => &mut self.status_info,
+            "status_success" => &mut self.status_success,
+            "status_warn" => &mut self.status_warn,
+            "status_error" => &mut self.status_error,
+            _ => return None,
+        };
+        Some(color)
+    }
+}
+
+fn build_color_theme(preset: &str, custom_theme_str: &str) -> Result<ColorTheme, String> {
+    let mut theme = ColorTheme::preset(preset).ok_or_else(|| {
+        format!(
+            "unknown theme preset: {preset} (available: {})",
+            PRESET_NAMES.join(", ")
+        )
+    })?;
+
+    let custom_theme: IndexMap<String, toml::Value> = toml::from_str(custom_theme_str)
+        .map_err(|e| format!("failed to parse custom theme: {e}"))?;
+    for (key, value) in custom_theme {
+        let color = parse_color(&value).map_err(|e| format!("invalid color for {key}: {e}"))?;
+        let target = theme
+            .color_mut(&key)
+            .ok_or_else(|| format!("unknown theme key: {key}"))?;
+        *target = color;
+    }
+    Ok(theme)
+}
+
+// named colors (e.g. `red`, `light-blue`), hex RGB (`#rrggbb`) and 256-color indices (`0`-`255`)
+fn parse_color(value: &toml::Value) -> Result<Color, String> {
+    match value {
+        toml::Value::String(s) => {
+            Color::from_str(s).map_err(|_| format!("unable to parse color: {s}"))
+        }
+        toml::Value::Integer(n) => u8::try_from(*n)
+            .map(Color::Indexed)
+            .map_err(|_| format!("color index must be between 0 and 255: {n}")),
+        v => Err(format!("color must be a string or an integer: {v}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("\"red\"", Color::Red)]
+    #[case("\"light-blue\"", Color::LightBlue)]
+    #[case("\"DarkGray\"", Color::DarkGray)]
+    #[case("\"#005f87\"", Color::Rgb(0, 95, 135))]
+    #[case("244", Color::Indexed(244))]
+    #[case("\"reset\"", Color::Reset)]
+    fn test_parse_color(#[case] raw: &str, #[case] expected: Color) {
+        let value: toml::Value = toml::from_str(&format!("v = {raw}")).unwrap();
+        assert_eq!(parse_color(&value["v"]), Ok(expected));
+    }
+
+    #[test]
+    fn test_build_color_theme() {
+        let custom = r##"
+list_selected_bg = "#005f87"
+list_selected_fg = "white"
+status_help = 244
+"##;
+        let theme = build_color_theme("light", custom).unwrap();
+        assert_eq!(theme.list_selected_bg, Color::Rgb(0, 95, 135));
+        assert_eq!(theme.list_selected_fg, Color::White);
+        assert_eq!(theme.status_help, Color::Indexed(244));
+        // not overridden
+        assert_eq!(theme.help_key_fg, ColorTheme::light().help_key_fg);
+    }
+
+    #[rstest]
+    #[case(
+        "solarized",
+        "",
+        "unknown theme preset: solarized (available: dark, light)"
+    )]
+    #[case("dark", "selected_bg = \"red\"", "unknown theme key: selected_bg")]
+    #[case(
+        "dark",
+        "divider = \"foo\"",
+        "invalid color for divider: unable to parse color: foo"
+    )]
+    #[case(
+        "dark",
+        "divider = 256",
+        "invalid color for divider: color index must be between 0 and 255: 256"
+    )]
+    fn test_build_color_theme_error(
+        #[case] preset: &str,
+        #[case] custom: &str,
+        #[case] expected: &str,
+    ) {
+        let err = build_color_theme(preset, custom).unwrap_err();
+        assert_eq!(err, expected);
+    }
 }
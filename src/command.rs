@@ -0,0 +1,647 @@
+use crate::{
+    object::{AppObjects, DownloadObjectInfo, ObjectItem, ObjectKey},
+    util::glob_match,
+};
+
+const S3_URI_PREFIX: &str = "s3://";
+const ARN_PREFIX: &str = "arn:";
+const ARN_S3_RESOURCE: &str = ":s3:::";
+const URL_PREFIXES: [&str; 2] = ["https://", "http://"];
+// the host of the virtual-hosted-style URL is `bucket.s3.region.amazonaws.com` or `bucket.s3-region.amazonaws.com`
+const VIRTUAL_HOSTED_S3_MARKERS: [&str; 2] = [".s3.", ".s3-"];
+
+const COMMAND_NAMES: [&str; 6] = ["cd", "get", "sort", "filter", "profile", "bookmark"];
+const SORT_KEYS: [&str; 4] = ["default", "name", "modified", "size"];
+const SORT_ORDERS: [&str; 2] = ["asc", "desc"];
+const BOOKMARK_ACTIONS: [&str; 4] = ["list", "add", "remove", "open"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    // move to the path, `s3://bucket/prefix`, `/bucket/prefix` or relative to the current directory
+    Cd(String),
+    // download the objects matching the glob pattern to the directory (relative to the download directory)
+    Get {
+        pattern: String,
+        dir: Option<String>,
+    },
+    Sort {
+        key: SortKey,
+        desc: bool,
+    },
+    // `re:` prefix means regular expression, empty means clear
+    Filter(String),
+    // show the current profile if none
+    Profile(Option<String>),
+    Bookmark(BookmarkAction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookmarkAction {
+    List,
+    // bookmark the current directory with the name
+    Add(String),
+    Remove(String),
+    Open(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Default,
+    Name,
+    LastModified,
+    Size,
+}
+
+pub fn parse_command(input: &str) -> Result<Command, String> {
+    let input = input.trim();
+    let (name, args) = match input.split_once(char::is_whitespace) {
+        Some((name, args)) => (name, args.trim()),
+        None => (input, ""),
+    };
+    let arg_list: Vec<&str> = args.split_whitespace().collect();
+
+    match name {
+        "cd" => match arg_list.as_slice() {
+            [path] => Ok(Command::Cd(path.to_string())),
+            _ => Err("Usage: cd <path>".into()),
+        },
+        "get" => match arg_list.as_slice() {
+            [pattern] => Ok(Command::Get {
+                pattern: pattern.to_string(),
+                dir: None,
+            }),
+            [pattern, dir] => Ok(Command::Get {
+                pattern: pattern.to_string(),
+                dir: Some(dir.to_string()),
+            }),
+            _ => Err("Usage: get <pattern> [dir]".into()),
+        },
+        "sort" => {
+            let usage = || "Usage: sort <default|name|modified|size> [asc|desc]".to_string();
+            let (key, order) = match arg_list.as_slice() {
+                [key] => (*key, "asc"),
+                [key, order] => (*key, *order),
+                _ => return Err(usage()),
+            };
+            let key = match key {
+                "default" => SortKey::Default,
+                "name" => SortKey::Name,
+                "modified" => SortKey::LastModified,
+                "size" => SortKey::Size,
+                _ => return Err(usage()),
+            };
+            let desc = match order {
+                "asc" => false,
+                "desc" => true,
+                _ => return Err(usage()),
+            };
+            Ok(Command::Sort { key, desc })
+        }
+        "filter" => Ok(Command::Filter(args.to_string())),
+        "profile" => match arg_list.as_slice() {
+            [] => Ok(Command::Profile(None)),
+            [profile] => Ok(Command::Profile(Some(profile.to_string()))),
+            _ => Err("Usage: profile [name]".into()),
+        },
+        "bookmark" => match arg_list.as_slice() {
+            [] | ["list"] => Ok(Command::Bookmark(BookmarkAction::List)),
+            ["add", name] => Ok(Command::Bookmark(BookmarkAction::Add(name.to_string()))),
+            ["remove", name] => Ok(Command::Bookmark(BookmarkAction::Remove(name.to_string()))),
+            ["open", name] => Ok(Command::Bookmark(BookmarkAction::Open(name.to_string()))),
+            _ => Err("Usage: bookmark [list|add <name>|remove <name>|open <name>]".into()),
+        },
+        _ => Err(format!("Unknown command: {name}")),
+    }
+}
+
+/// Resolves the path to the directory key, or `None` for the bucket list.
+///
+/// A relative path is resolved from the current directory, and `.` and `..` are supported.
+/// An absolute path is `/bucket/prefix` or a location accepted by [`parse_location`].
+pub fn resolve_path(
+    path: &str,
+    current_dir: Option<&ObjectKey>,
+) -> Result<Option<ObjectKey>, String> {
+    let (mut paths, relative) = if let Some(path) = parse_location(path) {
+        (Vec::new(), path)
+    } else if let Some(path) = path.strip_prefix('/') {
+        (Vec::new(), path.to_string())
+    } else {
+        match current_dir {
+            Some(key) => (key.paths(), path.to_string()),
+            None => return Err(format!("Relative path is not available here: {path}")),
+        }
+    };
+
+    for name in relative.split('/') {
+        match name {
+            "" | "." => {}
+            ".." => {
+                paths.pop();
+            }
+            _ => paths.push(name.to_string()),
+        }
+    }
+
+    if paths.is_empty() {
+        return Ok(None);
+    }
+    let bucket_name = paths.remove(0);
+    Ok(Some(ObjectKey {
+        bucket_name,
+        object_path: paths,
+    }))
+}
+
+/// Parses the location into the path of `bucket/key`, or returns `None` if it is not a location.
+///
+/// The location is an S3 URI (`s3://bucket/key`), an ARN (`arn:aws:s3:::bucket/key`),
+/// or a virtual-hosted-style or path-style URL (`https://bucket.s3.region.amazonaws.com/key`, `https://s3.region.amazonaws.com/bucket/key`).
+pub fn parse_location(input: &str) -> Option<String> {
+    if let Some(path) = input.strip_prefix(S3_URI_PREFIX) {
+        return Some(path.to_string());
+    }
+    if let Some(arn) = input.strip_prefix(ARN_PREFIX) {
+        let (_, path) = arn.split_once(ARN_S3_RESOURCE)?;
+        return Some(path.to_string());
+    }
+
+    let url = URL_PREFIXES.iter().find_map(|p| input.strip_prefix(p))?;
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = url.split_once('/').unwrap_or((url, ""));
+    let host = host.split(':').next().unwrap_or_default();
+    let path = percent_decode(path);
+    let bucket_end = VIRTUAL_HOSTED_S3_MARKERS
+        .iter()
+        .filter_map(|marker| host.rfind(marker))
+        .max();
+    match bucket_end {
+        Some(i) => Some(format!("{}/{}", &host[..i], path)),
+        None => Some(path),
+    }
+}
+
+/// Location specified on the command line, a directory or an object.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LocationTarget {
+    pub bucket: String,
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+impl LocationTarget {
+    pub fn is_dir(&self) -> bool {
+        self.key.is_empty() || self.key.ends_with('/')
+    }
+
+    /// Returns the prefix of the directory containing the target.
+    pub fn dir_prefix(&self) -> &str {
+        match self.key.rfind('/') {
+            Some(i) => &self.key[..=i],
+            None => "",
+        }
+    }
+}
+
+/// Parses the location with the version (`?versionId=`) as in [`FileVersion::s3_uri`](crate::object::FileVersion::s3_uri).
+pub fn parse_location_target(input: &str) -> Option<LocationTarget> {
+    let (location, query) = input.split_once('?').unwrap_or((input, ""));
+    let path = parse_location(location)?;
+    let (bucket, key) = path.split_once('/').unwrap_or((&path, ""));
+    if bucket.is_empty() {
+        return None;
+    }
+    let version_id = query
+        .split('&')
+        .find_map(|param| param.strip_prefix("versionId="))
+        .map(percent_decode);
+    Some(LocationTarget {
+        bucket: bucket.to_string(),
+        key: key.to_string(),
+        version_id,
+    })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                decoded.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Parses the input of the go-to-path dialog into the directory key.
+///
+/// A relative path is resolved from the root of the current bucket.
+pub fn parse_go_to_path(input: &str, current_bucket: &str) -> Result<ObjectKey, String> {
+    let root = ObjectKey::bucket(current_bucket);
+    match resolve_path(input, Some(&root))? {
+        Some(key) => Ok(key),
+        None => Err(format!("Bucket is not specified: {input}")),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GoToPathCompletion {
+    // candidates of the whole input
+    Candidates(Vec<String>),
+    // the objects of the directory are not loaded, so the directories starting with the prefix should be listed
+    List {
+        dir: String,
+        bucket: String,
+        prefix: String,
+    },
+}
+
+/// Completes the last name of the go-to-path input with the buckets or the directories.
+pub fn complete_go_to_path(
+    input: &str,
+    current_bucket: &str,
+    app_objects: &AppObjects,
+) -> GoToPathCompletion {
+    let (dir, name) = split_last_path(input);
+    let root = ObjectKey::bucket(current_bucket);
+    let Ok(dir_key) = resolve_path(dir, Some(&root)) else {
+        return GoToPathCompletion::Candidates(Vec::new());
+    };
+    match dir_key {
+        Some(key) if app_objects.get_object_items(&key).is_none() => GoToPathCompletion::List {
+            dir: dir.to_string(),
+            prefix: format!("{}{name}", key.joined_object_path(false)),
+            bucket: key.bucket_name,
+        },
+        _ => GoToPathCompletion::Candidates(complete_path(input, Some(&root), app_objects, true)),
+    }
+}
+
+/// Finds the loaded objects to download by `get` command, returns the key of their directory and the objects.
+pub fn find_objects_to_get(
+    pattern: &str,
+    current_dir: Option<&ObjectKey>,
+    app_objects: &AppObjects,
+) -> Result<(ObjectKey, Vec<DownloadObjectInfo>), String> {
+    let (dir, name_pattern) = split_last_path(pattern);
+    let Some(dir_key) = resolve_path(dir, current_dir)? else {
+        return Err(format!("Bucket is not specified: {pattern}"));
+    };
+    let Some(items) = app_objects.get_object_items(&dir_key) else {
+        return Err(format!("Objects are not loaded: {dir}"));
+    };
+
+    let prefix = dir_key.joined_object_path(false);
+    let objs: Vec<DownloadObjectInfo> = items
+        .iter()
+        .filter_map(|item| match item {
+            ObjectItem::File {
+                name, size_byte, ..
+            } if glob_match(name_pattern, name) => Some(DownloadObjectInfo {
+                key: format!("{prefix}{name}"),
+                size_byte: *size_byte,
+            }),
+            _ => None,
+        })
+        .collect();
+    if objs.is_empty() {
+        return Err(format!("No objects match: {pattern}"));
+    }
+    Ok((dir_key, objs))
+}
+
+/// Returns the candidates of the whole input completing its last word.
+///
+/// Paths are completed with the buckets and the objects which have already been loaded.
+pub fn complete_command(
+    input: &str,
+    current_dir: Option<&ObjectKey>,
+    app_objects: &AppObjects,
+) -> Vec<String> {
+    let (head, word) = match input.rfind(char::is_whitespace) {
+        Some(i) => input.split_at(i + 1),
+        None => ("", input),
+    };
+
+    let starts_with_word = |candidates: &[&str]| -> Vec<String> {
+        candidates
+            .iter()
+            .filter(|c| c.starts_with(word))
+            .map(|c| c.to_string())
+            .collect()
+    };
+
+    let args: Vec<&str> = head.split_whitespace().collect();
+    let candidates = match args.as_slice() {
+        [] => starts_with_word(&COMMAND_NAMES)
+            .into_iter()
+            .map(|name| format!("{name} "))
+            .collect(),
+        ["cd"] => complete_path(word, current_dir, app_objects, true),
+        ["get"] => complete_path(word, current_dir, app_objects, false),
+        ["sort"] => starts_with_word(&SORT_KEYS),
+        ["sort", _] => starts_with_word(&SORT_ORDERS),
+        ["bookmark"] => starts_with_word(&BOOKMARK_ACTIONS),
+        _ => Vec::new(),
+    };
+    candidates
+        .into_iter()
+        .map(|candidate| format!("{head}{candidate}"))
+        .collect()
+}
+
+fn complete_path(
+    path: &str,
+    current_dir: Option<&ObjectKey>,
+    app_objects: &AppObjects,
+    dir_only: bool,
+) -> Vec<String> {
+    let (dir, name) = split_last_path(path);
+    let Ok(dir_key) = resolve_path(dir, current_dir) else {
+        return Vec::new();
+    };
+
+    match dir_key {
+        None => app_objects
+            .get_bucket_items()
+            .iter()
+            .filter(|item| item.name.starts_with(name))
+            .map(|item| format!("{dir}{}/", item.name))
+            .collect(),
+        Some(key) => app_objects
+            .get_object_items(&key)
+            .unwrap_or_default()
+            .iter()
+            .filter(|item| item.name().starts_with(name))
+            .filter_map(|item| match item {
+                ObjectItem::Dir { name, .. } => Some(format!("{dir}{name}/")),
+                ObjectItem::File { name, .. } if !dir_only => Some(format!("{dir}{name}")),
+                ObjectItem::File { .. } => None,
+            })
+            .collect(),
+    }
+}
+
+// splits the path into the directory part (with the trailing slash) and the last name
+fn split_last_path(path: &str) -> (&str, &str) {
+    match path.rfind('/') {
+        Some(i) => path.split_at(i + 1),
+        None => ("", path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local};
+    use rstest::rstest;
+
+    use crate::object::BucketItem;
+
+    use super::*;
+
+    #[rstest]
+    #[case("cd s3://bucket/a/", Ok(Command::Cd("s3://bucket/a/".into())))]
+    #[case("  cd   ..  ", Ok(Command::Cd("..".into())))]
+    #[case("cd", Err("Usage: cd <path>".into()))]
+    #[case("get *.csv", Ok(Command::Get { pattern: "*.csv".into(), dir: None }))]
+    #[case("get *.csv ./out", Ok(Command::Get { pattern: "*.csv".into(), dir: Some("./out".into()) }))]
+    #[case("get a b c", Err("Usage: get <pattern> [dir]".into()))]
+    #[case("sort size desc", Ok(Command::Sort { key: SortKey::Size, desc: true }))]
+    #[case("sort name", Ok(Command::Sort { key: SortKey::Name, desc: false }))]
+    #[case("sort size down", Err("Usage: sort <default|name|modified|size> [asc|desc]".into()))]
+    #[case("filter re:^2024- x", Ok(Command::Filter("re:^2024- x".into())))]
+    #[case("filter", Ok(Command::Filter("".into())))]
+    #[case("profile prod", Ok(Command::Profile(Some("prod".into()))))]
+    #[case("profile", Ok(Command::Profile(None)))]
+    #[case("bookmark", Ok(Command::Bookmark(BookmarkAction::List)))]
+    #[case("bookmark add logs", Ok(Command::Bookmark(BookmarkAction::Add("logs".into()))))]
+    #[case("bookmark open logs", Ok(Command::Bookmark(BookmarkAction::Open("logs".into()))))]
+    #[case("bookmark remove", Err("Usage: bookmark [list|add <name>|remove <name>|open <name>]".into()))]
+    #[case("rm foo", Err("Unknown command: rm".into()))]
+    fn test_parse_command(#[case] input: &str, #[case] expected: Result<Command, String>) {
+        assert_eq!(parse_command(input), expected);
+    }
+
+    #[rstest]
+    #[case("s3://bucket/a/b/", Some(("bucket", vec!["a", "b"])))]
+    #[case("s3://bucket", Some(("bucket", vec![])))]
+    #[case("s3://", None)]
+    #[case("/other/c", Some(("other", vec!["c"])))]
+    #[case("/", None)]
+    #[case("c/d", Some(("bucket", vec!["a", "b", "c", "d"])))]
+    #[case("..", Some(("bucket", vec!["a"])))]
+    #[case("../../..", None)]
+    #[case("./../c/", Some(("bucket", vec!["a", "c"])))]
+    #[case("", Some(("bucket", vec!["a", "b"])))]
+    fn test_resolve_path(#[case] path: &str, #[case] expected: Option<(&str, Vec<&str>)>) {
+        let current_dir = ObjectKey::with_prefix("bucket", "a/b/".into());
+        let expected = expected.map(|(bucket, paths)| ObjectKey {
+            bucket_name: bucket.into(),
+            object_path: paths.into_iter().map(String::from).collect(),
+        });
+        assert_eq!(resolve_path(path, Some(&current_dir)), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("s3://bucket/a/b/", Some("bucket/a/b/"))]
+    #[case("arn:aws:s3:::bucket/a/b", Some("bucket/a/b"))]
+    #[case("arn:aws-cn:s3:::bucket", Some("bucket"))]
+    #[case("arn:aws:iam::123456789012:user/foo", None)]
+    #[case(
+        "https://bucket.s3.ap-northeast-1.amazonaws.com/a/b%20c/",
+        Some("bucket/a/b c/")
+    )]
+    #[case(
+        "https://my.bucket.s3-us-west-2.amazonaws.com/a?versionId=1",
+        Some("my.bucket/a")
+    )]
+    #[case("https://s3.ap-northeast-1.amazonaws.com/bucket/a/", Some("bucket/a/"))]
+    #[case("http://localhost:4566/bucket/a/", Some("bucket/a/"))]
+    #[case("bucket/a/", None)]
+    fn test_parse_location(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(parse_location(input), expected.map(String::from));
+    }
+
+    #[rstest]
+    #[case("s3://bucket/a/b/", Some(("bucket", "a/b/", None)))]
+    #[case("s3://bucket", Some(("bucket", "", None)))]
+    #[case("s3://bucket/a/b.txt?versionId=abc", Some(("bucket", "a/b.txt", Some("abc"))))]
+    #[case("https://bucket.s3.us-east-1.amazonaws.com/b.txt?versionId=a%2Bb", Some(("bucket", "b.txt", Some("a+b"))))]
+    #[case("arn:aws:s3:::bucket/a/b.txt", Some(("bucket", "a/b.txt", None)))]
+    #[case("s3://", None)]
+    #[case("bucket/a/", None)]
+    fn test_parse_location_target(
+        #[case] input: &str,
+        #[case] expected: Option<(&str, &str, Option<&str>)>,
+    ) {
+        let expected = expected.map(|(bucket, key, version_id)| LocationTarget {
+            bucket: bucket.into(),
+            key: key.into(),
+            version_id: version_id.map(String::from),
+        });
+        assert_eq!(parse_location_target(input), expected);
+    }
+
+    #[rstest]
+    #[case("", true, "")]
+    #[case("a/b/", true, "a/b/")]
+    #[case("a/b.txt", false, "a/")]
+    #[case("b.txt", false, "")]
+    fn test_location_target_dir(#[case] key: &str, #[case] is_dir: bool, #[case] dir_prefix: &str) {
+        let target = LocationTarget {
+            bucket: "bucket".into(),
+            key: key.into(),
+            version_id: None,
+        };
+        assert_eq!(target.is_dir(), is_dir);
+        assert_eq!(target.dir_prefix(), dir_prefix);
+    }
+
+    #[rstest]
+    #[case("a/b/", Ok(("bucket", vec!["a", "b"])))]
+    #[case("a/../c", Ok(("bucket", vec!["c"])))]
+    #[case("/other/c/", Ok(("other", vec!["c"])))]
+    #[case("arn:aws:s3:::other/c", Ok(("other", vec!["c"])))]
+    #[case("s3://", Err("Bucket is not specified: s3://".into()))]
+    fn test_parse_go_to_path(
+        #[case] input: &str,
+        #[case] expected: Result<(&str, Vec<&str>), String>,
+    ) {
+        let expected = expected.map(|(bucket, paths)| ObjectKey {
+            bucket_name: bucket.into(),
+            object_path: paths.into_iter().map(String::from).collect(),
+        });
+        assert_eq!(parse_go_to_path(input, "bucket"), expected);
+    }
+
+    #[rstest]
+    #[case("", GoToPathCompletion::Candidates(vec!["logs/".into()]))]
+    #[case("lo", GoToPathCompletion::Candidates(vec!["logs/".into()]))]
+    #[case("s3://b", GoToPathCompletion::Candidates(vec!["s3://bucket1/".into(), "s3://bucket2/".into()]))]
+    #[case("logs/", GoToPathCompletion::Candidates(vec![]))]
+    #[case("data/20", GoToPathCompletion::List { dir: "data/".into(), bucket: "bucket1".into(), prefix: "data/20".into() })]
+    #[case("s3://bucket2/x", GoToPathCompletion::List { dir: "s3://bucket2/".into(), bucket: "bucket2".into(), prefix: "x".into() })]
+    fn test_complete_go_to_path(#[case] input: &str, #[case] expected: GoToPathCompletion) {
+        let app_objects = app_objects();
+        assert_eq!(
+            complete_go_to_path(input, "bucket1", &app_objects),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_path_without_current_dir() {
+        assert!(resolve_path("a/", None).is_err());
+        assert!(resolve_path("/bucket/a/", None).is_ok());
+    }
+
+    #[test]
+    fn test_find_objects_to_get() {
+        let app_objects = app_objects();
+        let current_dir = ObjectKey::bucket("bucket1");
+
+        let (key, objs) = find_objects_to_get("*.csv", Some(&current_dir), &app_objects).unwrap();
+        assert_eq!(key, current_dir);
+        let keys: Vec<&str> = objs.iter().map(|obj| obj.key.as_str()).collect();
+        assert_eq!(keys, vec!["2024-01.csv", "2024-02.csv"]);
+
+        let (key, objs) = find_objects_to_get("logs/*", Some(&current_dir), &app_objects).unwrap();
+        assert_eq!(key, ObjectKey::with_prefix("bucket1", "logs/".into()));
+        let keys: Vec<&str> = objs.iter().map(|obj| obj.key.as_str()).collect();
+        assert_eq!(keys, vec!["logs/app.log"]);
+
+        let result = find_objects_to_get("*.txt", Some(&current_dir), &app_objects);
+        assert_eq!(result.unwrap_err(), "No objects match: *.txt");
+
+        let result = find_objects_to_get("data/*", Some(&current_dir), &app_objects);
+        assert_eq!(result.unwrap_err(), "Objects are not loaded: data/");
+    }
+
+    #[rstest]
+    #[case("", vec!["cd ", "get ", "sort ", "filter ", "profile ", "bookmark "])]
+    #[case("f", vec!["filter "])]
+    #[case("cd ", vec!["cd logs/"])]
+    #[case("get ", vec!["get logs/", "get 2024-01.csv", "get 2024-02.csv"])]
+    #[case("get 2024-0", vec!["get 2024-01.csv", "get 2024-02.csv"])]
+    #[case("cd logs/", vec![])]
+    #[case("get logs/", vec!["get logs/app.log"])]
+    #[case("cd s3://", vec!["cd s3://bucket1/", "cd s3://bucket2/"])]
+    #[case("cd s3://bucket2", vec!["cd s3://bucket2/"])]
+    #[case("cd /b", vec!["cd /bucket1/", "cd /bucket2/"])]
+    #[case("cd ../", vec!["cd ../bucket1/", "cd ../bucket2/"])]
+    #[case("sort s", vec!["sort size"])]
+    #[case("sort size ", vec!["sort size asc", "sort size desc"])]
+    #[case("bookmark ", vec!["bookmark list", "bookmark add", "bookmark remove", "bookmark open"])]
+    #[case("filter ", vec![])]
+    fn test_complete_command(#[case] input: &str, #[case] expected: Vec<&str>) {
+        let app_objects = app_objects();
+        let current_dir = ObjectKey::bucket("bucket1");
+        assert_eq!(
+            complete_command(input, Some(&current_dir), &app_objects),
+            expected
+        );
+    }
+
+    fn app_objects() -> AppObjects {
+        let mut app_objects = AppObjects::default();
+        app_objects.set_bucket_items(vec![bucket("bucket1"), bucket("bucket2")]);
+        app_objects.set_object_items(
+            ObjectKey::bucket("bucket1"),
+            vec![dir("logs"), file("2024-01.csv"), file("2024-02.csv")],
+        );
+        app_objects.set_object_items(
+            ObjectKey::with_prefix("bucket1", "logs/".into()),
+            vec![file("app.log")],
+        );
+        app_objects
+    }
+
+    fn bucket(name: &str) -> BucketItem {
+        BucketItem {
+            name: name.into(),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+        }
+    }
+
+    fn dir(name: &str) -> ObjectItem {
+        ObjectItem::Dir {
+            name: name.into(),
+            key: "".into(),
+            s3_uri: "".into(),
+            object_url: "".into(),
+        }
+    }
+
+    fn file(name: &str) -> ObjectItem {
+        ObjectItem::File {
+            name: name.into(),
+            size_byte: 10,
+            last_modified: parse_datetime("2024-01-02T13:01:02+09:00"),
+            key: "".into(),
+            s3_uri: "".into(),
+            arn: "".into(),
+            object_url: "".into(),
+            e_tag: "".into(),
+        }
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        DateTime::parse_from_rfc3339(s)
+            .unwrap()
+            .with_timezone(&Local)
+    }
+}
@@ -0,0 +1,247 @@
+use std::{borrow::Cow, io::Read};
+
+use crate::{
+    error::{AppError, Result},
+    util::extension_from_file_name,
+};
+
+const DECOMPRESS_BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+}
+
+impl Compression {
+    /// Detects the compression from the file extension, the Content-Encoding and the magic bytes, in that order.
+    pub fn detect(
+        file_name: &str,
+        content_encoding: Option<&str>,
+        bytes: &[u8],
+    ) -> Option<Compression> {
+        Compression::from_extension(file_name)
+            .or_else(|| content_encoding.and_then(Compression::from_content_encoding))
+            .or_else(|| Compression::from_magic_bytes(bytes))
+    }
+
+    pub fn from_extension(file_name: &str) -> Option<Compression> {
+        if !file_name.contains('.') {
+            return None;
+        }
+        match extension_from_file_name(file_name).to_lowercase().as_str() {
+            "gz" | "gzip" | "tgz" => Some(Compression::Gzip),
+            "zst" | "zstd" => Some(Compression::Zstd),
+            "bz2" => Some(Compression::Bzip2),
+            "xz" => Some(Compression::Xz),
+            _ => None,
+        }
+    }
+
+    pub fn from_content_encoding(content_encoding: &str) -> Option<Compression> {
+        // the last one is the outermost encoding
+        let encoding = content_encoding.split(',').next_back()?;
+        match encoding.trim().to_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(Compression::Gzip),
+            "zstd" => Some(Compression::Zstd),
+            "bzip2" | "x-bzip2" => Some(Compression::Bzip2),
+            "xz" | "x-xz" => Some(Compression::Xz),
+            _ => None,
+        }
+    }
+
+    fn from_magic_bytes(bytes: &[u8]) -> Option<Compression> {
+        if infer::archive::is_gz(bytes) {
+            Some(Compression::Gzip)
+        } else if infer::archive::is_zst(bytes) {
+            Some(Compression::Zstd)
+        } else if infer::archive::is_bz2(bytes) {
+            Some(Compression::Bzip2)
+        } else if infer::archive::is_xz(bytes) {
+            Some(Compression::Xz)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Zstd => "zstd",
+            Compression::Bzip2 => "bzip2",
+            Compression::Xz => "xz",
+        }
+    }
+
+    /// Decompresses the bytes up to `limit` bytes (`0` means no limit).
+    ///
+    /// If the bytes are only a part of the compressed data, the bytes that could be decompressed are returned as truncated.
+    pub fn decompress(&self, bytes: &[u8], limit: usize) -> Result<Decompressed> {
+        let mut decoder: Box<dyn Read + '_> = match self {
+            Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+            Compression::Zstd => Box::new(
+                zstd::stream::read::Decoder::new(bytes)
+                    .map_err(|e| AppError::new("Failed to decompress object", e))?,
+            ),
+            Compression::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(bytes)),
+            Compression::Xz => Box::new(xz2::read::XzDecoder::new_multi_decoder(bytes)),
+        };
+        read_with_limit(&mut decoder, limit)
+    }
+}
+
+/// Reads the decompressed bytes from the decoder up to `limit` bytes (`0` means no limit).
+///
+/// If the decoder fails after some bytes are read, e.g. because the input is only a part of the compressed data,
+/// the bytes that could be read are returned as truncated.
+pub fn read_with_limit<R: Read>(decoder: &mut R, limit: usize) -> Result<Decompressed> {
+    let limit = if limit == 0 { usize::MAX } else { limit };
+
+    let mut decompressed = Vec::new();
+    let mut buf = vec![0; DECOMPRESS_BUFFER_SIZE];
+    loop {
+        let n = match decoder.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if decompressed.is_empty() => {
+                return Err(AppError::new("Failed to decompress object", e));
+            }
+            Err(_) => {
+                return Ok(Decompressed {
+                    bytes: decompressed,
+                    truncated: true,
+                });
+            }
+        };
+        if decompressed.len() + n > limit {
+            let rest = limit - decompressed.len();
+            decompressed.extend_from_slice(&buf[..rest]);
+            return Ok(Decompressed {
+                bytes: decompressed,
+                truncated: true,
+            });
+        }
+        decompressed.extend_from_slice(&buf[..n]);
+    }
+    Ok(Decompressed {
+        bytes: decompressed,
+        truncated: false,
+    })
+}
+
+#[derive(Debug)]
+pub struct Decompressed {
+    pub bytes: Vec<u8>,
+    // true if the decompressed bytes are only a part of the original data
+    pub truncated: bool,
+}
+
+/// Returns the file name without the compression extension, e.g. `foo.json.gz` -> `foo.json`, `foo.tgz` -> `foo.tar`.
+pub fn strip_compression_extension(file_name: &str) -> Cow<'_, str> {
+    if Compression::from_extension(file_name).is_none() {
+        return Cow::Borrowed(file_name);
+    }
+    match file_name.rsplit_once('.') {
+        Some((name, ext)) if ext.eq_ignore_ascii_case("tgz") => Cow::Owned(format!("{name}.tar")),
+        Some((name, _)) => Cow::Borrowed(name),
+        None => Cow::Borrowed(file_name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("foo.json.gz", None, b"{}", Some(Compression::Gzip))]
+    #[case("foo.log.ZST", None, b"", Some(Compression::Zstd))]
+    #[case("foo.bz2", None, b"", Some(Compression::Bzip2))]
+    #[case("foo.xz", None, b"", Some(Compression::Xz))]
+    #[case("foo.json", Some("gzip"), b"", Some(Compression::Gzip))]
+    #[case("foo.json", Some("identity, zstd"), b"", Some(Compression::Zstd))]
+    #[case("foo", None, b"\x1f\x8b\x08\x00", Some(Compression::Gzip))]
+    #[case("foo.txt", Some("identity"), b"plain text", None)]
+    fn test_detect(
+        #[case] file_name: &str,
+        #[case] content_encoding: Option<&str>,
+        #[case] bytes: &[u8],
+        #[case] expected: Option<Compression>,
+    ) {
+        let actual = Compression::detect(file_name, content_encoding, bytes);
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Compression::Gzip)]
+    #[case(Compression::Zstd)]
+    #[case(Compression::Bzip2)]
+    #[case(Compression::Xz)]
+    fn test_decompress(#[case] compression: Compression) {
+        let original = "Hello, world!\n".repeat(100);
+        let compressed = compress(compression, original.as_bytes());
+
+        let actual = compression.decompress(&compressed, 0).unwrap();
+        assert_eq!(actual.bytes, original.as_bytes());
+        assert!(!actual.truncated);
+
+        let actual = compression.decompress(&compressed, 10).unwrap();
+        assert_eq!(actual.bytes, b"Hello, wor");
+        assert!(actual.truncated);
+    }
+
+    #[test]
+    fn test_decompress_partial_bytes() {
+        let original = "Hello, world!\n".repeat(10000);
+        let compressed = compress(Compression::Gzip, original.as_bytes());
+        let partial = &compressed[..compressed.len() / 2];
+
+        let actual = Compression::Gzip.decompress(partial, 0).unwrap();
+        assert!(actual.truncated);
+        assert!(original.as_bytes().starts_with(&actual.bytes));
+    }
+
+    #[test]
+    fn test_decompress_invalid_bytes() {
+        let actual = Compression::Gzip.decompress(b"not compressed", 0);
+        assert!(actual.is_err());
+    }
+
+    #[rstest]
+    #[case("foo.json.gz", "foo.json")]
+    #[case("foo.log.zst", "foo.log")]
+    #[case("foo.tgz", "foo.tar")]
+    #[case("foo.txt", "foo.txt")]
+    #[case("foo", "foo")]
+    fn test_strip_compression_extension(#[case] file_name: &str, #[case] expected: &str) {
+        assert_eq!(strip_compression_extension(file_name), expected);
+    }
+
+    fn compress(compression: Compression, bytes: &[u8]) -> Vec<u8> {
+        match compression {
+            Compression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(bytes).unwrap();
+                encoder.finish().unwrap()
+            }
+            Compression::Zstd => zstd::encode_all(bytes, 0).unwrap(),
+            Compression::Bzip2 => {
+                let mut encoder =
+                    bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+                encoder.write_all(bytes).unwrap();
+                encoder.finish().unwrap()
+            }
+            Compression::Xz => {
+                let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+                encoder.write_all(bytes).unwrap();
+                encoder.finish().unwrap()
+            }
+        }
+    }
+}
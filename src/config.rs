@@ -13,12 +13,17 @@ const STU_ROOT_DIR_ENV_VAR: &str = "STU_ROOT_DIR";
 const APP_BASE_DIR: &str = ".stu";
 const CONFIG_FILE_NAME: &str = "config.toml";
 const KEYBINDINGS_FILE_NAME: &str = "keybindings.toml";
+const THEME_FILE_NAME: &str = "theme.toml";
+const BOOKMARKS_FILE_NAME: &str = "bookmarks.toml";
+const RECENT_LOCATIONS_FILE_NAME: &str = "recent_locations.toml";
 const ERROR_LOG_FILE_NAME: &str = "error.log";
 const DEBUG_LOG_FILE_NAME: &str = "debug.log";
 const DOWNLOAD_DIR: &str = "download";
 const PREVIEW_THEME_DIR: &str = "preview_theme";
 const PREVIEW_SYNTAX_DIR: &str = "preview_syntax";
 
+const MAX_ITEM_MAX_ATTEMPTS: u32 = 10;
+
 #[optional(derives = [Deserialize])]
 #[derive(Debug, Clone, SmartDefault)]
 pub struct Config {
@@ -32,11 +37,19 @@ pub struct Config {
     pub ui: UiConfig,
     #[nested]
     pub preview: PreviewConfig,
+    #[nested]
+    pub assume_role: AssumeRoleConfig,
+    #[nested]
+    pub request: RequestConfig,
 }
 
 #[optional(derives = [Deserialize])]
 #[derive(Debug, Clone, SmartDefault)]
 pub struct UiConfig {
+    #[default = "dark"]
+    pub theme: String,
+    #[default = 1000]
+    pub key_sequence_timeout_millis: u64,
     #[nested]
     pub object_list: UiObjectListConfig,
     #[nested]
@@ -85,6 +98,47 @@ pub struct PreviewConfig {
     pub encodings: Vec<String>,
     #[default = false]
     pub auto_detect_encoding: bool,
+    #[default = 10485760]
+    pub max_size_byte: usize,
+    #[default(vec!["log".into()])]
+    pub tail_extensions: Vec<String>,
+    #[default = 5]
+    pub follow_interval_secs: u64,
+    #[default = 52428800]
+    pub max_decompressed_size_byte: usize,
+    #[default = 1000]
+    pub max_table_rows: usize,
+}
+
+#[optional(derives = [Deserialize])]
+#[derive(Debug, Clone, SmartDefault)]
+pub struct AssumeRoleConfig {
+    pub role_arn: Option<String>,
+    pub external_id: Option<String>,
+    #[default = "stu"]
+    pub role_session_name: String,
+    pub mfa_serial: Option<String>,
+}
+
+#[optional(derives = [Deserialize])]
+#[derive(Debug, Clone, SmartDefault)]
+pub struct RequestConfig {
+    #[default(RetryMode::Standard)]
+    pub retry_mode: RetryMode,
+    #[default = 3]
+    pub max_attempts: u32,
+    pub connect_timeout_secs: Option<u64>,
+    pub read_timeout_secs: Option<u64>,
+    pub max_requests_per_second: Option<u32>,
+    #[default = 3]
+    pub item_max_attempts: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RetryMode {
+    Standard,
+    Adaptive,
 }
 
 fn default_download_dir() -> String {
@@ -110,12 +164,24 @@ impl Config {
             if let Some(ref mut download_dir) = config.download_dir {
                 *download_dir = Self::expand_env_vars(download_dir)?;
             }
-            Ok(config.into())
+            let config: Config = config.into();
+            config.validate()?;
+            Ok(config)
         } else {
             Ok(Config::default())
         }
     }
 
+    fn validate(&self) -> anyhow::Result<()> {
+        let item_max_attempts = self.request.item_max_attempts;
+        if !(1..=MAX_ITEM_MAX_ATTEMPTS).contains(&item_max_attempts) {
+            anyhow::bail!(
+                "request.item_max_attempts must be between 1 and {MAX_ITEM_MAX_ATTEMPTS}: {item_max_attempts}"
+            );
+        }
+        Ok(())
+    }
+
     fn expand_env_vars(path: &str) -> anyhow::Result<String> {
         let mut result = path.to_string();
         while let Some(start) = result.find('$') {
@@ -145,6 +211,21 @@ impl Config {
         Ok(dir.join(KEYBINDINGS_FILE_NAME))
     }
 
+    pub fn theme_file_path() -> anyhow::Result<PathBuf> {
+        let dir = Config::get_app_base_dir()?;
+        Ok(dir.join(THEME_FILE_NAME))
+    }
+
+    pub fn bookmarks_file_path() -> anyhow::Result<PathBuf> {
+        let dir = Config::get_app_base_dir()?;
+        Ok(dir.join(BOOKMARKS_FILE_NAME))
+    }
+
+    pub fn recent_locations_file_path() -> anyhow::Result<PathBuf> {
+        let dir = Config::get_app_base_dir()?;
+        Ok(dir.join(RECENT_LOCATIONS_FILE_NAME))
+    }
+
     pub fn error_log_path() -> anyhow::Result<PathBuf> {
         let dir = Config::get_app_base_dir()?;
         Ok(dir.join(ERROR_LOG_FILE_NAME))
@@ -0,0 +1,204 @@
+use std::{
+    error::Error,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use aws_config::SdkConfig;
+use aws_credential_types::{
+    provider::{self, error::CredentialsError, future, ProvideCredentials},
+    Credentials,
+};
+use aws_sdk_sts::error::ProvideErrorMetadata;
+
+// refresh a little before the actual expiration to avoid using credentials that are about to expire
+const EXPIRY_BUFFER: Duration = Duration::from_secs(60);
+
+/// The MFA session could not be obtained, so a (new) MFA token code has to be entered.
+#[derive(Debug)]
+pub enum MfaTokenCodeError {
+    // no token code is set, e.g. the MFA session has expired
+    Required,
+    Invalid(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for MfaTokenCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MfaTokenCodeError::Required => write!(f, "MFA token code is required"),
+            MfaTokenCodeError::Invalid(_) => write!(f, "MFA token code is invalid"),
+        }
+    }
+}
+
+impl Error for MfaTokenCodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MfaTokenCodeError::Required => None,
+            MfaTokenCodeError::Invalid(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AssumeRoleOptions {
+    pub role_arn: Option<String>,
+    pub external_id: Option<String>,
+    pub role_session_name: String,
+    pub mfa_serial: Option<String>,
+}
+
+impl AssumeRoleOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.role_arn.is_some() || self.mfa_serial.is_some()
+    }
+
+    pub fn requires_mfa(&self) -> bool {
+        self.mfa_serial.is_some()
+    }
+}
+
+/// Credentials provider that assumes the specified role (and/or gets an MFA session) via STS.
+///
+/// The returned credentials have an expiration time,
+/// so the SDK identity cache calls this provider again when they are about to expire.
+/// When MFA is used, the MFA session credentials obtained by GetSessionToken are kept
+/// and reused to assume the role again, so the token code is only required once per MFA session.
+#[derive(Debug)]
+pub struct AssumeRoleCredentialsProvider {
+    base_config: SdkConfig,
+    options: AssumeRoleOptions,
+    mfa_token_code: Arc<Mutex<Option<String>>>,
+    mfa_session: tokio::sync::Mutex<Option<Credentials>>,
+}
+
+impl AssumeRoleCredentialsProvider {
+    pub fn new(
+        base_config: SdkConfig,
+        options: AssumeRoleOptions,
+        mfa_token_code: Arc<Mutex<Option<String>>>,
+    ) -> AssumeRoleCredentialsProvider {
+        AssumeRoleCredentialsProvider {
+            base_config,
+            options,
+            mfa_token_code,
+            mfa_session: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    async fn load_credentials(&self) -> provider::Result {
+        let mfa_session = match &self.options.mfa_serial {
+            Some(serial) => Some(self.load_mfa_session_credentials(serial).await?),
+            None => None,
+        };
+
+        let Some(role_arn) = &self.options.role_arn else {
+            return mfa_session
+                .ok_or_else(|| CredentialsError::not_loaded("Role ARN or MFA serial is not set"));
+        };
+
+        let mut sts_config_builder = aws_sdk_sts::config::Builder::from(&self.base_config);
+        if let Some(creds) = mfa_session {
+            sts_config_builder = sts_config_builder.credentials_provider(creds);
+        }
+        let sts = aws_sdk_sts::Client::from_conf(sts_config_builder.build());
+
+        let output = sts
+            .assume_role()
+            .role_arn(role_arn)
+            .role_session_name(&self.options.role_session_name)
+            .set_external_id(self.options.external_id.clone())
+            .send()
+            .await
+            .map_err(CredentialsError::provider_error)?;
+
+        convert_credentials(output.credentials(), "StuAssumeRole")
+    }
+
+    async fn load_mfa_session_credentials(&self, serial: &str) -> provider::Result {
+        let mut mfa_session = self.mfa_session.lock().await;
+        if let Some(creds) = mfa_session.as_ref() {
+            if !is_expired(creds, SystemTime::now()) {
+                return Ok(creds.clone());
+            }
+        }
+
+        let token_code = self
+            .mfa_token_code
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| CredentialsError::not_loaded(MfaTokenCodeError::Required))?;
+
+        let sts = aws_sdk_sts::Client::new(&self.base_config);
+        let output = sts
+            .get_session_token()
+            .serial_number(serial)
+            .token_code(token_code)
+            .send()
+            .await
+            .map_err(|e| {
+                // a wrong token code is rejected as AccessDenied
+                if e.code() == Some("AccessDenied") {
+                    CredentialsError::provider_error(MfaTokenCodeError::Invalid(Box::new(e)))
+                } else {
+                    CredentialsError::provider_error(e)
+                }
+            })?;
+
+        let creds = convert_credentials(output.credentials(), "StuMfaSession")?;
+        *mfa_session = Some(creds.clone());
+        Ok(creds)
+    }
+}
+
+impl ProvideCredentials for AssumeRoleCredentialsProvider {
+    fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::new(self.load_credentials())
+    }
+}
+
+fn convert_credentials(
+    creds: Option<&aws_sdk_sts::types::Credentials>,
+    provider_name: &'static str,
+) -> provider::Result {
+    let creds =
+        creds.ok_or_else(|| CredentialsError::unhandled("No credentials in the STS response"))?;
+    let expiry = SystemTime::try_from(*creds.expiration()).map_err(CredentialsError::unhandled)?;
+    Ok(Credentials::new(
+        creds.access_key_id(),
+        creds.secret_access_key(),
+        Some(creds.session_token().to_string()),
+        Some(expiry),
+        provider_name,
+    ))
+}
+
+fn is_expired(creds: &Credentials, now: SystemTime) -> bool {
+    creds
+        .expiry()
+        .is_some_and(|expiry| expiry <= now + EXPIRY_BUFFER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(None, false)]
+    #[case(Some(3600), false)]
+    #[case(Some(61), false)]
+    #[case(Some(60), true)]
+    #[case(Some(0), true)]
+    fn test_is_expired(#[case] expires_in_secs: Option<u64>, #[case] expected: bool) {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let expiry = expires_in_secs.map(|secs| now + Duration::from_secs(secs));
+        let creds = Credentials::new("akid", "secret", None, expiry, "test");
+        assert_eq!(is_expired(&creds, now), expected);
+    }
+}
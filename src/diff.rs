@@ -0,0 +1,223 @@
+use similar::{ChangeTag, TextDiff};
+
+/// The number of unchanged lines shown around the changes.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    // 1-based line numbers, set only for the side that contains the line
+    pub old_number: Option<usize>,
+    pub new_number: Option<usize>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    // e.g. `@@ -1,4 +1,5 @@`
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineDiff {
+    pub hunks: Vec<DiffHunk>,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// A row of the diff view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffRow {
+    Hunk(String),
+    Line(DiffLine),
+    // the old and the new line shown side by side
+    Pair(Option<DiffLine>, Option<DiffLine>),
+}
+
+impl LineDiff {
+    pub fn new(old: &str, new: &str) -> LineDiff {
+        let diff = TextDiff::from_lines(old, new);
+        let mut hunks = Vec::new();
+        let mut insertions = 0;
+        let mut deletions = 0;
+        for ops in diff.grouped_ops(CONTEXT_LINES) {
+            let (Some(first), Some(last)) = (ops.first(), ops.last()) else {
+                continue;
+            };
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+            let header = format!(
+                "@@ -{} +{} @@",
+                hunk_range(old_range.start, old_range.len()),
+                hunk_range(new_range.start, new_range.len()),
+            );
+            let mut lines = Vec::new();
+            for change in ops.iter().flat_map(|op| diff.iter_changes(op)) {
+                let kind = match change.tag() {
+                    ChangeTag::Equal => DiffLineKind::Equal,
+                    ChangeTag::Insert => {
+                        insertions += 1;
+                        DiffLineKind::Insert
+                    }
+                    ChangeTag::Delete => {
+                        deletions += 1;
+                        DiffLineKind::Delete
+                    }
+                };
+                let text = change.value().trim_end_matches(['\r', '\n']).to_string();
+                lines.push(DiffLine {
+                    kind,
+                    old_number: change.old_index().map(|i| i + 1),
+                    new_number: change.new_index().map(|i| i + 1),
+                    text,
+                });
+            }
+            hunks.push(DiffHunk { header, lines });
+        }
+        LineDiff {
+            hunks,
+            insertions,
+            deletions,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Returns the rows of the unified view, each hunk starts with its header row.
+    pub fn unified_rows(&self) -> Vec<DiffRow> {
+        let mut rows = Vec::new();
+        for hunk in &self.hunks {
+            rows.push(DiffRow::Hunk(hunk.header.clone()));
+            rows.extend(hunk.lines.iter().cloned().map(DiffRow::Line));
+        }
+        rows
+    }
+
+    /// Returns the rows of the side-by-side view, deleted and inserted lines in a change are paired in order.
+    pub fn side_by_side_rows(&self) -> Vec<DiffRow> {
+        let mut rows = Vec::new();
+        for hunk in &self.hunks {
+            rows.push(DiffRow::Hunk(hunk.header.clone()));
+            let mut deleted = Vec::new();
+            let mut inserted = Vec::new();
+            for line in &hunk.lines {
+                match line.kind {
+                    DiffLineKind::Delete => deleted.push(line.clone()),
+                    DiffLineKind::Insert => inserted.push(line.clone()),
+                    DiffLineKind::Equal => {
+                        push_pairs(&mut rows, &mut deleted, &mut inserted);
+                        rows.push(DiffRow::Pair(Some(line.clone()), Some(line.clone())));
+                    }
+                }
+            }
+            push_pairs(&mut rows, &mut deleted, &mut inserted);
+        }
+        rows
+    }
+}
+
+fn push_pairs(rows: &mut Vec<DiffRow>, deleted: &mut Vec<DiffLine>, inserted: &mut Vec<DiffLine>) {
+    let n = deleted.len().max(inserted.len());
+    let mut deleted = deleted.drain(..);
+    let mut inserted = inserted.drain(..);
+    for _ in 0..n {
+        rows.push(DiffRow::Pair(deleted.next(), inserted.next()));
+    }
+}
+
+// same as the format of `diff -u`
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(kind: DiffLineKind, old: Option<usize>, new: Option<usize>, text: &str) -> DiffLine {
+        DiffLine {
+            kind,
+            old_number: old,
+            new_number: new,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn test_line_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        let diff = LineDiff::new(old, new);
+
+        assert_eq!(diff.insertions, 2);
+        assert_eq!(diff.deletions, 1);
+        assert_eq!(diff.hunks.len(), 2);
+        assert_eq!(diff.hunks[0].header, "@@ -1,5 +1,5 @@");
+        assert_eq!(diff.hunks[1].header, "@@ -9,3 +9,4 @@");
+
+        use DiffLineKind::*;
+        assert_eq!(
+            diff.hunks[0].lines,
+            vec![
+                line(Equal, Some(1), Some(1), "a"),
+                line(Delete, Some(2), None, "b"),
+                line(Insert, None, Some(2), "B"),
+                line(Equal, Some(3), Some(3), "c"),
+                line(Equal, Some(4), Some(4), "d"),
+                line(Equal, Some(5), Some(5), "e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_diff_no_changes() {
+        let diff = LineDiff::new("a\nb\n", "a\nb\n");
+        assert!(diff.is_empty());
+        assert!(diff.unified_rows().is_empty());
+    }
+
+    #[test]
+    fn test_side_by_side_rows() {
+        let diff = LineDiff::new("a\nb\nc\nd\n", "a\nB\nC\nX\nd\n");
+        let rows = diff.side_by_side_rows();
+
+        use DiffLineKind::*;
+        assert_eq!(
+            rows,
+            vec![
+                DiffRow::Hunk("@@ -1,4 +1,5 @@".into()),
+                DiffRow::Pair(
+                    Some(line(Equal, Some(1), Some(1), "a")),
+                    Some(line(Equal, Some(1), Some(1), "a"))
+                ),
+                DiffRow::Pair(
+                    Some(line(Delete, Some(2), None, "b")),
+                    Some(line(Insert, None, Some(2), "B"))
+                ),
+                DiffRow::Pair(
+                    Some(line(Delete, Some(3), None, "c")),
+                    Some(line(Insert, None, Some(3), "C"))
+                ),
+                DiffRow::Pair(None, Some(line(Insert, None, Some(4), "X"))),
+                DiffRow::Pair(
+                    Some(line(Equal, Some(4), Some(5), "d")),
+                    Some(line(Equal, Some(4), Some(5), "d"))
+                ),
+            ]
+        );
+    }
+}
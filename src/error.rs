@@ -2,9 +2,47 @@ use std::error::Error;
 
 pub type Result<T> = std::result::Result<T, AppError>;
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    #[default]
+    Unknown,
+    ExpiredCredentials,
+    InvalidCredentials,
+    MfaTokenCodeRequired,
+    InvalidMfaTokenCode,
+    AccessDenied,
+    NoSuchBucket,
+    NoSuchKey,
+    Throttling,
+    Timeout,
+    Network,
+    ServiceUnavailable,
+}
+
+impl ErrorKind {
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorKind::Throttling
+                | ErrorKind::Timeout
+                | ErrorKind::Network
+                | ErrorKind::ServiceUnavailable
+        )
+    }
+}
+
+/// The operation and the target resource that caused the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub action: &'static str,
+    pub resource: String,
+}
+
 #[derive(Debug)]
 pub struct AppError {
     pub msg: String,
+    pub kind: ErrorKind,
+    pub context: Option<ErrorContext>,
     pub cause: Option<Box<dyn Error + Send + 'static>>,
 }
 
@@ -12,6 +50,8 @@ impl AppError {
     pub fn new<E: Error + Send + 'static>(msg: impl Into<String>, e: E) -> AppError {
         AppError {
             msg: msg.into(),
+            kind: ErrorKind::default(),
+            context: None,
             cause: Some(Box::new(e)),
         }
     }
@@ -19,6 +59,8 @@ impl AppError {
     pub fn msg(msg: impl Into<String>) -> AppError {
         AppError {
             msg: msg.into(),
+            kind: ErrorKind::default(),
+            context: None,
             cause: None,
         }
     }
@@ -26,7 +68,92 @@ impl AppError {
     pub fn error<E: Error + Send + 'static>(e: E) -> AppError {
         AppError {
             msg: e.to_string(),
+            kind: ErrorKind::default(),
+            context: None,
             cause: Some(Box::new(e)),
         }
     }
+
+    pub fn with_kind(mut self, kind: ErrorKind) -> AppError {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_context(mut self, action: &'static str, resource: impl Into<String>) -> AppError {
+        self.context = Some(ErrorContext {
+            action,
+            resource: resource.into(),
+        });
+        self
+    }
+
+    /// Returns a message that describes what went wrong and what can be done about it.
+    pub fn detailed_msg(&self) -> String {
+        let resource = self.context.as_ref().map(|c| c.resource.as_str());
+        let detail = match (self.kind, resource) {
+            (ErrorKind::Unknown, _) => return self.msg.clone(),
+            (ErrorKind::ExpiredCredentials, _) => "credentials have expired".to_string(),
+            (ErrorKind::InvalidCredentials, _) => {
+                "credentials could not be loaded, check the profile configuration".to_string()
+            }
+            (ErrorKind::MfaTokenCodeRequired, _) => {
+                "MFA session has expired, enter a new MFA token code".to_string()
+            }
+            (ErrorKind::InvalidMfaTokenCode, _) => "MFA token code is invalid".to_string(),
+            (ErrorKind::AccessDenied, None) => "access denied".to_string(),
+            (ErrorKind::AccessDenied, Some(resource)) => {
+                let action = self.context.as_ref().unwrap().action;
+                format!("access denied, {action} permission is required for {resource}")
+            }
+            (ErrorKind::NoSuchBucket, None) => return self.msg.clone(),
+            (ErrorKind::NoSuchBucket, Some(resource)) => format!("bucket not found ({resource})"),
+            (ErrorKind::NoSuchKey, None) => "object not found".to_string(),
+            (ErrorKind::NoSuchKey, Some(resource)) => format!("{resource} not found"),
+            (ErrorKind::Throttling, _) => "request was throttled by S3".to_string(),
+            (ErrorKind::Timeout, _) => "request timed out".to_string(),
+            (ErrorKind::Network, _) => "network error, check your connection".to_string(),
+            (ErrorKind::ServiceUnavailable, _) => "service is temporarily unavailable".to_string(),
+        };
+        if self.kind.is_retryable() {
+            format!("{}: {} (retry may succeed)", self.msg, detail)
+        } else {
+            format!("{}: {}", self.msg, detail)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(ErrorKind::Unknown, None, "Failed to load objects")]
+    #[case(
+        ErrorKind::AccessDenied,
+        Some(("s3:ListBucket", "s3://bucket/a/")),
+        "Failed to load objects: access denied, s3:ListBucket permission is required for s3://bucket/a/"
+    )]
+    #[case(ErrorKind::AccessDenied, None, "Failed to load objects: access denied")]
+    #[case(
+        ErrorKind::NoSuchKey,
+        Some(("s3:GetObject", "s3://bucket/a/b.txt")),
+        "Failed to load objects: s3://bucket/a/b.txt not found"
+    )]
+    #[case(
+        ErrorKind::Throttling,
+        Some(("s3:ListBucket", "s3://bucket/a/")),
+        "Failed to load objects: request was throttled by S3 (retry may succeed)"
+    )]
+    fn test_detailed_msg(
+        #[case] kind: ErrorKind,
+        #[case] context: Option<(&'static str, &str)>,
+        #[case] expected: &str,
+    ) {
+        let mut e = AppError::msg("Failed to load objects").with_kind(kind);
+        if let Some((action, resource)) = context {
+            e = e.with_context(action, resource);
+        }
+        assert_eq!(e.detailed_msg(), expected);
+    }
 }
@@ -9,42 +9,68 @@ use ratatui::crossterm::event::KeyEvent;
 use tokio::{select, spawn, sync::mpsc};
 
 use crate::{
+    archive::{ArchiveEntry, ArchiveSource},
+    bookmark::Bookmark,
+    compression::Decompressed,
     error::{AppError, Result},
+    format::format_version,
+    history::Location,
     object::{
-        BucketItem, DownloadObjectInfo, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject,
+        BucketItem, BulkReport, ByteRange, DownloadObjectInfo, FileDetail, FileVersion, ObjectItem,
+        ObjectKey, RawObject,
     },
+    table::TableData,
 };
 
 #[derive(Debug)]
 pub enum AppEventType {
     Key(KeyEvent),
+    KeySequenceTimeout(usize),
     Resize,
     Initialize(Option<String>, Option<String>),
-    CompleteInitialize(Result<CompleteInitializeResult>),
+    OpenMfaTokenCodeDialog(Option<String>, Option<String>),
+    InputMfaTokenCode(String),
+    CompleteInitialize(RequestResult<CompleteInitializeResult>),
     ReloadBuckets,
-    CompleteReloadBuckets(Result<CompleteReloadBucketsResult>),
+    CompleteReloadBuckets(RequestResult<CompleteReloadBucketsResult>),
     LoadObjects(ObjectKey),
     // Direct navigation to a specific path within a bucket
     GoToPath(ObjectKey),
-    CompleteLoadObjects(Result<CompleteLoadObjectsResult>),
+    LoadGoToPathSuggestions(String),
+    CompleteLoadGoToPathSuggestions(Result<CompleteLoadGoToPathSuggestionsResult>),
+    CompleteLoadObjects(RequestResult<CompleteLoadObjectsResult>),
     ReloadObjects,
-    CompleteReloadObjects(Result<CompleteReloadObjectsResult>),
+    CompleteReloadObjects(RequestResult<CompleteReloadObjectsResult>),
     LoadObjectDetail,
-    CompleteLoadObjectDetail(Result<CompleteLoadObjectDetailResult>),
+    CompleteLoadObjectDetail(RequestResult<CompleteLoadObjectDetailResult>),
     LoadObjectVersions,
-    CompleteLoadObjectVersions(Result<CompleteLoadObjectVersionsResult>),
+    CompleteLoadObjectVersions(RequestResult<CompleteLoadObjectVersionsResult>),
     StartLoadAllDownloadObjectList(ObjectKey, bool),
     LoadAllDownloadObjectList(ObjectKey, bool),
-    CompleteLoadAllDownloadObjectList(Result<CompleteLoadAllDownloadObjectListResult>),
+    CompleteLoadAllDownloadObjectList(RequestResult<CompleteLoadAllDownloadObjectListResult>),
     StartDownloadObject(ObjectKey, String, usize, Option<String>),
     DownloadObject(ObjectKey, String, usize, Option<String>),
     StartDownloadObjectAs(ObjectKey, usize, String, Option<String>),
     DownloadObjectAs(ObjectKey, usize, String, Option<String>),
-    CompleteDownloadObject(Result<CompleteDownloadObjectResult>),
+    CompleteDownloadObject(RequestResult<CompleteDownloadObjectResult>),
     DownloadObjects(String, ObjectKey, String, Vec<DownloadObjectInfo>),
     CompleteDownloadObjects(Result<CompleteDownloadObjectsResult>),
     PreviewObject(ObjectKey, FileDetail, Option<String>),
-    CompletePreviewObject(Result<CompletePreviewObjectResult>),
+    CompletePreviewObject(RequestResult<CompletePreviewObjectResult>),
+    CompletePreviewArchive(RequestResult<CompletePreviewArchiveResult>),
+    CompletePreviewTable(RequestResult<CompletePreviewTableResult>),
+    OpenArchiveEntry(ObjectKey, ArchiveSource, ArchiveEntry),
+    CompleteOpenArchiveEntry(RequestResult<CompleteOpenArchiveEntryResult>),
+    ExtractArchiveEntries(ObjectKey, ArchiveSource, Vec<ArchiveEntry>),
+    CompleteExtractArchiveEntries(RequestResult<CompleteExtractArchiveEntriesResult>),
+    LoadPreviewRange(ObjectKey, Option<String>, ByteRange),
+    CompleteLoadPreviewRange(RequestResult<CompleteLoadPreviewRangeResult>),
+    FollowPreviewObject(FollowPreviewSpec),
+    CompleteFollowPreviewObject(Result<CompleteFollowPreviewObjectResult>),
+    StartDiffWithClipboard(ObjectKey, ObjectItem),
+    OpenDiff(DiffSpec),
+    DiffObjects(DiffSpec),
+    CompleteDiffObjects(RequestResult<CompleteDiffObjectsResult>),
     StartSaveObject(String, Arc<RawObject>),
     SaveObject(String, Arc<RawObject>),
     CompleteSaveObject(Result<CompleteSaveObjectResult>),
@@ -59,45 +85,154 @@ pub enum AppEventType {
     PreviewRerenderImage,
     CopyObject(ObjectKey, ObjectItem),
     StartPasteObject(ObjectKey),
+    StartPasteToOtherPane(ObjectKey, ObjectItem, PasteMode),
     OpenPasteConfirmDialog(PasteSpec),
     PasteObject(PasteSpec),
-    CompletePasteObject(Result<CompletePasteObjectResult>),
+    PasteObjects(PasteSpec, Vec<DownloadObjectInfo>),
+    CompletePasteObject(RequestResult<CompletePasteObjectResult>),
     BucketListOpenManagementConsole,
     ObjectListOpenManagementConsole(ObjectKey),
     ObjectDetailOpenManagementConsole(ObjectKey),
     CloseCurrentPage,
     OpenHelp,
+    OpenCommandLine,
+    OpenCommandPalette,
+    OpenBookmarks,
+    OpenBookmark(Bookmark),
+    AddBookmark(String, ObjectKey),
+    RemoveBookmark(String),
+    OpenRecentLocations,
+    OpenLocation(Location),
+    HistoryBack,
+    HistoryForward,
+    ToggleDualPane,
+    SwitchPane,
+    SwitchProfile(Option<String>, Option<ObjectKey>),
+    CompleteSwitchProfile(Result<CompleteSwitchProfileResult>),
+    ReloadCredentials,
+    CompleteReloadCredentials(Result<CompleteReloadCredentialsResult>),
     CopyToClipboard(String, String),
     NotifyInfo(String),
     NotifySuccess(String),
     NotifyWarn(String),
     NotifyError(AppError),
+    NotifyRequestError(RequestError),
 }
 
+/// The error of a request, which is sent again once the credentials have been reloaded.
+#[derive(Debug)]
+pub struct RequestError {
+    pub error: AppError,
+    pub request: Box<AppEventType>,
+}
+
+impl RequestError {
+    pub fn new(error: AppError, request: AppEventType) -> RequestError {
+        RequestError {
+            error,
+            request: Box::new(request),
+        }
+    }
+}
+
+pub type RequestResult<T> = std::result::Result<T, RequestError>;
+
 #[derive(Debug)]
 pub struct CompleteInitializeResult {
     pub buckets: Vec<BucketItem>,
     pub prefix: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasteMode {
+    Copy,
+    // copy and then delete the source objects
+    Move,
+}
+
+impl PasteMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PasteMode::Copy => "Copy",
+            PasteMode::Move => "Move",
+        }
+    }
+
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            PasteMode::Copy => "Copied",
+            PasteMode::Move => "Moved",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PasteSpec {
     pub src_bucket: String,
     pub src_key: String,
+    // the directory the object is pasted from
+    pub src_dir_key: ObjectKey,
     pub dst_bucket: String,
     pub dst_key: String,
+    // the directory the object is pasted into
+    pub dst_dir_key: ObjectKey,
     pub name: String,
+    pub mode: PasteMode,
 }
 
 #[derive(Debug)]
 pub struct CompletePasteObjectResult {
-    pub name: String,
+    pub spec: PasteSpec,
+    // set only when a prefix is copied
+    pub report: Option<BulkReport>,
 }
 
 impl CompletePasteObjectResult {
-    pub fn new(result: Result<()>, name: String) -> Result<CompletePasteObjectResult> {
-        result?;
-        Ok(CompletePasteObjectResult { name })
+    pub fn new(
+        result: Result<Option<BulkReport>>,
+        spec: PasteSpec,
+    ) -> Result<CompletePasteObjectResult> {
+        let report = result?;
+        Ok(CompletePasteObjectResult { spec, report })
+    }
+}
+
+/// An operation on multiple objects, which can be run again for the failed objects.
+#[derive(Debug, Clone)]
+pub enum BulkOperation {
+    Download {
+        bucket: String,
+        key: ObjectKey,
+        dir: String,
+        download_dir: PathBuf,
+    },
+    Paste(PasteSpec),
+}
+
+impl BulkOperation {
+    pub fn description(&self) -> String {
+        match self {
+            BulkOperation::Download { download_dir, .. } => {
+                format!("Download to {}", download_dir.to_string_lossy())
+            }
+            BulkOperation::Paste(spec) => format!(
+                "{} s3://{}/{} to s3://{}/{}",
+                spec.mode.name(),
+                spec.src_bucket,
+                spec.src_key,
+                spec.dst_bucket,
+                spec.dst_key
+            ),
+        }
+    }
+
+    pub fn retry_event(&self, objs: Vec<DownloadObjectInfo>) -> AppEventType {
+        match self {
+            BulkOperation::Download {
+                bucket, key, dir, ..
+            } => AppEventType::DownloadObjects(bucket.clone(), key.clone(), dir.clone(), objs),
+            BulkOperation::Paste(spec) => AppEventType::PasteObjects(spec.clone(), objs),
+        }
     }
 }
 
@@ -148,6 +283,26 @@ impl CompleteLoadObjectsResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadGoToPathSuggestionsResult {
+    pub input: String,
+    pub suggestions: Vec<String>,
+}
+
+impl CompleteLoadGoToPathSuggestionsResult {
+    pub fn new(
+        names: Result<Vec<String>>,
+        input: String,
+        dir: String,
+    ) -> Result<CompleteLoadGoToPathSuggestionsResult> {
+        let suggestions = names?
+            .into_iter()
+            .map(|name| format!("{dir}{name}/"))
+            .collect();
+        Ok(CompleteLoadGoToPathSuggestionsResult { input, suggestions })
+    }
+}
+
 impl From<CompleteReloadObjectsResult> for CompleteLoadObjectsResult {
     fn from(result: CompleteReloadObjectsResult) -> Self {
         CompleteLoadObjectsResult {
@@ -235,37 +390,247 @@ impl CompleteDownloadObjectResult {
 
 #[derive(Debug)]
 pub struct CompleteDownloadObjectsResult {
-    pub download_dir: PathBuf,
+    pub operation: BulkOperation,
+    pub report: BulkReport,
 }
 
 impl CompleteDownloadObjectsResult {
-    pub fn new(download_dir: PathBuf) -> Result<CompleteDownloadObjectsResult> {
-        Ok(CompleteDownloadObjectsResult { download_dir })
+    pub fn new(
+        operation: BulkOperation,
+        report: BulkReport,
+    ) -> Result<CompleteDownloadObjectsResult> {
+        Ok(CompleteDownloadObjectsResult { operation, report })
     }
 }
 
 #[derive(Debug)]
 pub struct CompletePreviewObjectResult {
+    pub object_key: ObjectKey,
     pub obj: RawObject,
+    // set only when a part of the object is loaded
+    pub range: Option<ByteRange>,
     pub file_detail: FileDetail,
     pub file_version_id: Option<String>,
 }
 
 impl CompletePreviewObjectResult {
     pub fn new(
+        object_key: ObjectKey,
         obj: Result<RawObject>,
+        range: Option<ByteRange>,
         file_detail: FileDetail,
         file_version_id: Option<String>,
     ) -> Result<CompletePreviewObjectResult> {
         let obj = obj?;
         Ok(CompletePreviewObjectResult {
+            object_key,
             obj,
+            range,
+            file_detail,
+            file_version_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompletePreviewArchiveResult {
+    pub object_key: ObjectKey,
+    pub entries: Vec<ArchiveEntry>,
+    pub file_detail: FileDetail,
+    pub file_version_id: Option<String>,
+}
+
+impl CompletePreviewArchiveResult {
+    pub fn new(
+        object_key: ObjectKey,
+        entries: Result<Vec<ArchiveEntry>>,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+    ) -> Result<CompletePreviewArchiveResult> {
+        let entries = entries?;
+        Ok(CompletePreviewArchiveResult {
+            object_key,
+            entries,
             file_detail,
             file_version_id,
         })
     }
 }
 
+#[derive(Debug)]
+pub struct CompletePreviewTableResult {
+    pub object_key: ObjectKey,
+    pub data: TableData,
+    pub file_detail: FileDetail,
+    pub file_version_id: Option<String>,
+}
+
+impl CompletePreviewTableResult {
+    pub fn new(
+        object_key: ObjectKey,
+        data: Result<TableData>,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+    ) -> Result<CompletePreviewTableResult> {
+        let data = data?;
+        Ok(CompletePreviewTableResult {
+            object_key,
+            data,
+            file_detail,
+            file_version_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteOpenArchiveEntryResult {
+    pub object_key: ObjectKey,
+    pub entry: ArchiveEntry,
+    pub data: Decompressed,
+}
+
+impl CompleteOpenArchiveEntryResult {
+    pub fn new(
+        object_key: ObjectKey,
+        entry: ArchiveEntry,
+        data: Result<Decompressed>,
+    ) -> Result<CompleteOpenArchiveEntryResult> {
+        let data = data?;
+        Ok(CompleteOpenArchiveEntryResult {
+            object_key,
+            entry,
+            data,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteExtractArchiveEntriesResult {
+    pub count: usize,
+    pub dir: PathBuf,
+}
+
+impl CompleteExtractArchiveEntriesResult {
+    pub fn new(count: Result<usize>, dir: PathBuf) -> Result<CompleteExtractArchiveEntriesResult> {
+        let count = count?;
+        Ok(CompleteExtractArchiveEntriesResult { count, dir })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadPreviewRangeResult {
+    pub object_key: ObjectKey,
+    pub version_id: Option<String>,
+    pub range: ByteRange,
+    pub bytes: Vec<u8>,
+}
+
+impl CompleteLoadPreviewRangeResult {
+    pub fn new(
+        bytes: Result<Vec<u8>>,
+        object_key: ObjectKey,
+        version_id: Option<String>,
+        range: ByteRange,
+    ) -> Result<CompleteLoadPreviewRangeResult> {
+        let bytes = bytes?;
+        Ok(CompleteLoadPreviewRangeResult {
+            object_key,
+            version_id,
+            range,
+            bytes,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FollowPreviewSpec {
+    pub id: usize,
+    pub object_key: ObjectKey,
+    pub file_detail: FileDetail,
+    pub loaded_range: ByteRange,
+}
+
+#[derive(Debug)]
+pub struct CompleteFollowPreviewObjectResult {
+    pub id: usize,
+    pub file_detail: FileDetail,
+    // set only when the object has changed
+    pub range: Option<ByteRange>,
+    pub bytes: Vec<u8>,
+}
+
+impl CompleteFollowPreviewObjectResult {
+    pub fn new(
+        id: usize,
+        file_detail: Result<FileDetail>,
+        range: Option<ByteRange>,
+        bytes: Result<Vec<u8>>,
+    ) -> Result<CompleteFollowPreviewObjectResult> {
+        let file_detail = file_detail?;
+        let bytes = bytes?;
+        Ok(CompleteFollowPreviewObjectResult {
+            id,
+            file_detail,
+            range,
+            bytes,
+        })
+    }
+}
+
+/// An object (or a version of an object) to compare.
+#[derive(Debug, Clone)]
+pub struct DiffTarget {
+    // contains the file name
+    pub object_key: ObjectKey,
+    pub name: String,
+    pub size_byte: usize,
+    pub version_id: Option<String>,
+}
+
+impl DiffTarget {
+    pub fn label(&self) -> String {
+        match &self.version_id {
+            Some(version_id) => {
+                format!("{} (Version ID: {})", self.name, format_version(version_id))
+            }
+            None => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffSpec {
+    pub old: DiffTarget,
+    pub new: DiffTarget,
+}
+
+#[derive(Debug)]
+pub struct CompleteDiffObjectsResult {
+    pub spec: DiffSpec,
+    pub old: RawObject,
+    pub new: RawObject,
+    // true if only the first part of the objects is loaded
+    pub truncated: bool,
+}
+
+impl CompleteDiffObjectsResult {
+    pub fn new(
+        spec: DiffSpec,
+        old: Result<RawObject>,
+        new: Result<RawObject>,
+        truncated: bool,
+    ) -> Result<CompleteDiffObjectsResult> {
+        let old = old?;
+        let new = new?;
+        Ok(CompleteDiffObjectsResult {
+            spec,
+            old,
+            new,
+            truncated,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct CompleteSaveObjectResult {
     pub path: PathBuf,
@@ -278,6 +643,38 @@ impl CompleteSaveObjectResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteReloadCredentialsResult;
+
+impl CompleteReloadCredentialsResult {
+    pub fn new(result: Result<()>) -> Result<CompleteReloadCredentialsResult> {
+        result?;
+        Ok(CompleteReloadCredentialsResult)
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteSwitchProfileResult {
+    pub profile: Option<String>,
+    pub buckets: Vec<BucketItem>,
+    pub object_key: Option<ObjectKey>,
+}
+
+impl CompleteSwitchProfileResult {
+    pub fn new(
+        buckets: Result<Vec<BucketItem>>,
+        profile: Option<String>,
+        object_key: Option<ObjectKey>,
+    ) -> Result<CompleteSwitchProfileResult> {
+        let buckets = buckets?;
+        Ok(CompleteSwitchProfileResult {
+            profile,
+            buckets,
+            object_key,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct Sender {
     tx: mpsc::UnboundedSender<AppEventType>,
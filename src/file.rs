@@ -6,7 +6,10 @@ use std::{
     path::Path,
 };
 
-use crate::error::{AppError, Result};
+use crate::{
+    error::{AppError, Result},
+    object::BulkReport,
+};
 
 pub fn create_binary_file<P: AsRef<Path>>(path: P) -> Result<BufWriter<File>> {
     create_dirs(&path)?;
@@ -22,17 +25,65 @@ pub fn save_error_log<P: AsRef<Path>>(path: P, e: &AppError) -> Result<()> {
 
     let now = Local::now();
 
+    let context = match &e.context {
+        Some(ctx) => format!(" (action: {}, resource: {})", ctx.action, ctx.resource),
+        None => "".to_string(),
+    };
+
     match &e.cause {
         Some(cause) => {
-            writeln!(f, "{} {}: {:?}", now, e.msg, cause)
+            writeln!(
+                f,
+                "{} [{:?}] {}{}: {:?}",
+                now, e.kind, e.msg, context, cause
+            )
         }
         None => {
-            writeln!(f, "{} {}", now, e.msg)
+            writeln!(f, "{} [{:?}] {}{}", now, e.kind, e.msg, context)
         }
     }
     .map_err(|e| AppError::new("Failed to write file", e))
 }
 
+pub fn save_bulk_report<P: AsRef<Path>>(
+    path: P,
+    description: &str,
+    report: &BulkReport,
+) -> Result<()> {
+    let mut f = create_binary_file(path)?;
+
+    let mut lines = vec![
+        description.to_string(),
+        format!("succeeded: {}", report.succeeded_count),
+        format!("failed: {}", report.failed.len()),
+        format!("skipped: {}", report.skipped.len()),
+    ];
+    if report.credentials_expired {
+        lines.push("stopped because the credentials have expired".to_string());
+    }
+    if !report.failed.is_empty() {
+        lines.push("".to_string());
+        lines.push("[failed]".to_string());
+        lines.extend(
+            report
+                .failed
+                .iter()
+                .map(|f| format!("{}\t{}", f.obj.key, f.error)),
+        );
+    }
+    if !report.skipped.is_empty() {
+        lines.push("".to_string());
+        lines.push("[skipped]".to_string());
+        lines.extend(report.skipped.iter().map(|obj| obj.key.clone()));
+    }
+
+    for line in lines {
+        writeln!(f, "{line}").map_err(|e| AppError::new("Failed to write file", e))?;
+    }
+    f.flush()
+        .map_err(|e| AppError::new("Failed to write file", e))
+}
+
 pub fn open_or_create_append_file<P: AsRef<Path>>(path: P) -> std::io::Result<File> {
     OpenOptions::new().create(true).append(true).open(path)
 }
@@ -3,7 +3,7 @@ use ratatui::{
     text::Span,
 };
 
-use crate::keys::{key_event_to_string, UserEvent, UserEventMapper};
+use crate::keys::{key_sequence_to_string, UserEvent, UserEventMapper};
 
 pub struct BuildShortHelpsItem {
     event: BuildShortHelpsItemEvent,
@@ -44,7 +44,7 @@ pub fn build_short_help_spans(
             BuildShortHelpsItemEvent::Single(event) => mapper.find_first_key(event).map(|key| {
                 let spans = vec![
                     "<".into(),
-                    key_event_to_string(key, true).into(),
+                    key_sequence_to_string(&key, true).into(),
                     ">".into(),
                     ": ".into(),
                     item.description.into(),
@@ -64,7 +64,7 @@ pub fn build_short_help_spans(
                 } else {
                     let keys_str = keys
                         .into_iter()
-                        .map(|k| key_event_to_string(k, true))
+                        .map(|k| key_sequence_to_string(&k, true))
                         .collect::<Vec<String>>()
                         .join("/");
                     let spans = vec![
@@ -113,7 +113,7 @@ pub fn prune_spans_to_fit_width(
     let mut spans_with_priority_with_index: Vec<(usize, &SpansWithPriority)> =
         spans_with_priorities.iter().enumerate().collect();
 
-    spans_with_priority_with_index.sort_by(|(_, sp1), (_, sp2)| sp2.priority.cmp(&sp1.priority));
+    spans_with_priority_with_index.sort_by_key(|(_, sp)| std::cmp::Reverse(sp.priority));
 
     let mut prune: Vec<usize> = Vec::new();
     for (i, sp) in &spans_with_priority_with_index {
@@ -153,6 +153,14 @@ impl BuildHelpsItem {
             description: description.into(),
         }
     }
+
+    pub fn event(&self) -> UserEvent {
+        self.event
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
 }
 
 pub fn build_help_spans(
@@ -172,7 +180,7 @@ pub fn build_help_spans(
                     .map(|key| {
                         vec![
                             "<".into(),
-                            key_event_to_string(key, false).fg(key_fg).bold(),
+                            key_sequence_to_string(&key, false).fg(key_fg).bold(),
                             ">".into(),
                         ]
                     })
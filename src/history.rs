@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, object::ObjectKey};
+
+const MAX_HISTORY_ENTRIES: usize = 100;
+const MAX_RECENT_LOCATIONS: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    // `None` means the default credentials
+    pub profile: Option<String>,
+    pub object_key: ObjectKey,
+}
+
+impl Location {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(false)
+        )
+    }
+}
+
+/// Browser-style history of the visited directories.
+#[derive(Debug, Default)]
+pub struct NavigationHistory {
+    entries: Vec<Location>,
+    current: usize,
+}
+
+impl NavigationHistory {
+    /// Records the location as the current one, discarding the entries ahead of the current one.
+    pub fn visit(&mut self, location: Location) {
+        if self.entries.get(self.current) == Some(&location) {
+            return;
+        }
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.current + 1);
+        }
+        self.entries.push(location);
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.current = self.entries.len() - 1;
+    }
+
+    pub fn back(&mut self) -> Option<&Location> {
+        if self.current == 0 {
+            return None;
+        }
+        self.current -= 1;
+        self.entries.get(self.current)
+    }
+
+    pub fn forward(&mut self) -> Option<&Location> {
+        if self.current + 1 >= self.entries.len() {
+            return None;
+        }
+        self.current += 1;
+        self.entries.get(self.current)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RecentLocationsFile {
+    #[serde(default)]
+    locations: Vec<LocationEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LocationEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+    bucket: String,
+    #[serde(default)]
+    prefix: String,
+}
+
+impl From<LocationEntry> for Location {
+    fn from(entry: LocationEntry) -> Self {
+        Location {
+            profile: entry.profile,
+            object_key: ObjectKey::with_prefix(entry.bucket, entry.prefix),
+        }
+    }
+}
+
+impl From<&Location> for LocationEntry {
+    fn from(location: &Location) -> Self {
+        LocationEntry {
+            profile: location.profile.clone(),
+            bucket: location.object_key.bucket_name.clone(),
+            prefix: location.object_key.joined_object_path(false),
+        }
+    }
+}
+
+/// Recently visited directories, the most recent first.
+#[derive(Debug, Default)]
+pub struct RecentLocations {
+    locations: Vec<Location>,
+}
+
+impl RecentLocations {
+    pub fn load() -> anyhow::Result<RecentLocations> {
+        let path = Config::recent_locations_file_path()?;
+        if path.exists() {
+            let content = std::fs::read_to_string(path)?;
+            RecentLocations::from_toml(&content)
+        } else {
+            Ok(RecentLocations::default())
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Config::recent_locations_file_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_toml()?)?;
+        Ok(())
+    }
+
+    fn from_toml(content: &str) -> anyhow::Result<RecentLocations> {
+        let file: RecentLocationsFile = toml::from_str(content)?;
+        let locations = file.locations.into_iter().map(Location::from).collect();
+        Ok(RecentLocations { locations })
+    }
+
+    fn to_toml(&self) -> anyhow::Result<String> {
+        let file = RecentLocationsFile {
+            locations: self.locations.iter().map(LocationEntry::from).collect(),
+        };
+        Ok(toml::to_string(&file)?)
+    }
+
+    pub fn list(&self) -> &[Location] {
+        &self.locations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    /// Moves the location to the front, and drops the oldest ones over the limit.
+    pub fn visit(&mut self, location: Location) {
+        self.locations.retain(|l| *l != location);
+        self.locations.insert(0, location);
+        self.locations.truncate(MAX_RECENT_LOCATIONS);
+    }
+
+    /// Merges the other locations as more recent ones.
+    pub fn merge(&mut self, other: &RecentLocations) {
+        for location in other.list().iter().rev() {
+            self.visit(location.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_history() {
+        let mut history = NavigationHistory::default();
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), None);
+
+        history.visit(location("a"));
+        history.visit(location("b"));
+        history.visit(location("b"));
+        history.visit(location("c"));
+
+        assert_eq!(history.back(), Some(&location("b")));
+        assert_eq!(history.back(), Some(&location("a")));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), Some(&location("b")));
+
+        // visiting the current one keeps the entries ahead
+        history.visit(location("b"));
+        assert_eq!(history.forward(), Some(&location("c")));
+        assert_eq!(history.forward(), None);
+
+        // visiting another one discards the entries ahead
+        history.back();
+        history.back();
+        history.visit(location("d"));
+        assert_eq!(history.forward(), None);
+        assert_eq!(history.back(), Some(&location("a")));
+        assert_eq!(history.back(), None);
+    }
+
+    #[test]
+    fn test_recent_locations() {
+        let mut recent = RecentLocations::default();
+        recent.visit(location("a"));
+        recent.visit(location("b"));
+        recent.visit(location("a"));
+        assert_eq!(recent.list(), &[location("a"), location("b")]);
+
+        for i in 0..MAX_RECENT_LOCATIONS {
+            recent.visit(location(&format!("x{i}")));
+        }
+        assert_eq!(recent.list().len(), MAX_RECENT_LOCATIONS);
+        assert_eq!(recent.list()[0], location("x19"));
+
+        let mut session = RecentLocations::default();
+        session.visit(location("b"));
+        session.visit(location("c"));
+        let mut recent = RecentLocations::default();
+        recent.visit(location("a"));
+        recent.visit(location("b"));
+        recent.merge(&session);
+        assert_eq!(
+            recent.list(),
+            &[location("c"), location("b"), location("a")]
+        );
+    }
+
+    #[test]
+    fn test_recent_locations_toml() {
+        let content = r#"
+[[locations]]
+profile = "prod"
+bucket = "bucket-1"
+prefix = "logs/2024/"
+
+[[locations]]
+bucket = "bucket-2"
+"#;
+        let recent = RecentLocations::from_toml(content).unwrap();
+        assert_eq!(
+            recent.list(),
+            &[
+                Location {
+                    profile: Some("prod".into()),
+                    object_key: ObjectKey::with_prefix("bucket-1", "logs/2024/".into()),
+                },
+                Location {
+                    profile: None,
+                    object_key: ObjectKey::bucket("bucket-2"),
+                },
+            ]
+        );
+        assert_eq!(recent.list()[0].s3_uri(), "s3://bucket-1/logs/2024/");
+
+        let actual = RecentLocations::from_toml(&recent.to_toml().unwrap()).unwrap();
+        assert_eq!(actual.list(), recent.list());
+    }
+
+    fn location(prefix: &str) -> Location {
+        Location {
+            profile: None,
+            object_key: ObjectKey::with_prefix("bucket", prefix.into()),
+        }
+    }
+}
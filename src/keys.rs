@@ -10,6 +10,9 @@ pub enum UserEvent {
     Quit,
     Help,
     DumpApp,
+    CommandPalette,
+    CommandLine,
+    InitializingRecentLocations,
     BucketListDown,
     BucketListUp,
     BucketListGoToTop,
@@ -25,6 +28,10 @@ pub enum UserEvent {
     BucketListRefresh,
     BucketListResetFilter,
     BucketListManagementConsole,
+    BucketListBookmarks,
+    BucketListRecentLocations,
+    BucketListDualPane,
+    BucketListSwitchPane,
     ObjectListDown,
     ObjectListUp,
     ObjectListGoToTop,
@@ -45,6 +52,16 @@ pub enum UserEvent {
     ObjectListRefresh,
     ObjectListResetFilter,
     ObjectListManagementConsole,
+    ObjectListDiff,
+    ObjectListBookmarks,
+    ObjectListAddBookmark,
+    ObjectListRecentLocations,
+    ObjectListHistoryBack,
+    ObjectListHistoryForward,
+    ObjectListDualPane,
+    ObjectListSwitchPane,
+    ObjectListCopyToOtherPane,
+    ObjectListMoveToOtherPane,
     ObjectDetailDown,
     ObjectDetailUp,
     ObjectDetailRight,
@@ -57,6 +74,7 @@ pub enum UserEvent {
     ObjectDetailPreview,
     ObjectDetailCopyDetails,
     ObjectDetailManagementConsole,
+    ObjectDetailDiff,
     ObjectPreviewDown,
     ObjectPreviewUp,
     ObjectPreviewRight,
@@ -71,20 +89,78 @@ pub enum UserEvent {
     ObjectPreviewEncoding,
     ObjectPreviewToggleWrap,
     ObjectPreviewToggleNumber,
+    ObjectPreviewLoadNextChunk,
+    ObjectPreviewLoadLastChunk,
+    ObjectPreviewToggleFollow,
+    ObjectPreviewOpenEntry,
+    ObjectPreviewToggleMark,
+    ObjectPreviewToggleSchema,
+    ObjectPreviewToggleFormat,
+    ObjectPreviewToggleFold,
+    ObjectPreviewToggleFoldAll,
+    ObjectPreviewToggleHex,
+    ObjectPreviewGoTo,
+    ObjectPreviewSearch,
+    ObjectPreviewSearchNext,
+    ObjectPreviewSearchPrev,
+    ObjectPreviewToggleSearchCase,
+    ObjectPreviewToggleSearchRegex,
+    ObjectPreviewSetMark,
+    ObjectPreviewJumpToMark,
+    ObjectDiffDown,
+    ObjectDiffUp,
+    ObjectDiffRight,
+    ObjectDiffLeft,
+    ObjectDiffGoToTop,
+    ObjectDiffGoToBottom,
+    ObjectDiffPageDown,
+    ObjectDiffPageUp,
+    ObjectDiffBack,
+    ObjectDiffNextHunk,
+    ObjectDiffPrevHunk,
+    ObjectDiffToggleView,
+    ObjectDiffEncoding,
+    BookmarkListDown,
+    BookmarkListUp,
+    BookmarkListGoToTop,
+    BookmarkListGoToBottom,
+    BookmarkListSelect,
+    BookmarkListDelete,
+    BookmarkListBack,
+    RecentLocationListDown,
+    RecentLocationListUp,
+    RecentLocationListGoToTop,
+    RecentLocationListGoToBottom,
+    RecentLocationListSelect,
+    RecentLocationListBack,
     HelpClose,
     InputDialogClose,
     InputDialogApply,
+    InputDialogComplete,
+    InputDialogDown,
+    InputDialogUp,
     SelectDialogDown,
     SelectDialogUp,
     SelectDialogRight,
     SelectDialogLeft,
     SelectDialogClose,
     SelectDialogSelect,
+    CommandPaletteClose,
+    CommandPaletteExecute,
+    CommandPaletteDown,
+    CommandPaletteUp,
+    CommandLineClose,
+    CommandLineExecute,
+    CommandLineComplete,
+    CommandLinePrevHistory,
+    CommandLineNextHistory,
 }
 
 #[derive(Debug, Default)]
 pub struct UserEventMapper {
-    map: IndexMap<KeyEvent, Vec<UserEvent>>,
+    map: IndexMap<Vec<KeyEvent>, Vec<UserEvent>>,
+    // key sequences of two or more keys, with the section they are bound in
+    sequences: Vec<(String, Vec<KeyEvent>)>,
 }
 
 impl UserEventMapper {
@@ -95,21 +171,133 @@ impl UserEventMapper {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
-    pub fn find_events(&self, e: KeyEvent) -> Vec<UserEvent> {
-        self.map.get(&e).cloned().unwrap_or_default()
+    pub fn find_events(&self, keys: &[KeyEvent]) -> Vec<UserEvent> {
+        self.map.get(keys).cloned().unwrap_or_default()
     }
 
-    pub fn find_keys(&self, e: UserEvent) -> Vec<KeyEvent> {
+    pub fn find_keys(&self, e: UserEvent) -> Vec<Vec<KeyEvent>> {
         self.map
             .iter()
-            .filter_map(|(k, v)| if v.contains(&e) { Some(*k) } else { None })
+            .filter_map(|(k, v)| {
+                if v.contains(&e) {
+                    Some(k.clone())
+                } else {
+                    None
+                }
+            })
             .collect()
     }
 
-    pub fn find_first_key(&self, e: UserEvent) -> Option<KeyEvent> {
-        self.map
+    pub fn find_first_key(&self, e: UserEvent) -> Option<Vec<KeyEvent>> {
+        self.map.iter().find_map(|(k, v)| {
+            if v.contains(&e) {
+                Some(k.clone())
+            } else {
+                None
+            }
+        })
+    }
+
+    // the key sequences that can be typed in the section, the common ones are available in every section
+    fn section_sequences<'a>(&'a self, section: &'a str) -> impl Iterator<Item = &'a [KeyEvent]> {
+        self.sequences
             .iter()
-            .find_map(|(k, v)| if v.contains(&e) { Some(*k) } else { None })
+            .filter(move |(s, _)| s == section || s == "common")
+            .map(|(_, keys)| keys.as_slice())
+    }
+
+    // whether the keys can be continued to a longer key sequence in the section
+    fn is_prefix(&self, section: &str, keys: &[KeyEvent]) -> bool {
+        self.section_sequences(section)
+            .any(|k| k.len() > keys.len() && k.starts_with(keys))
+    }
+
+    fn find_section_events(&self, section: &str, keys: &[KeyEvent]) -> Vec<UserEvent> {
+        if keys.len() > 1 && !self.section_sequences(section).any(|k| k == keys) {
+            return Vec::new();
+        }
+        self.find_events(keys)
+    }
+}
+
+// keys typed in these sections are inserted into the input, so they are never held for a key sequence
+fn is_input_section(section: &str) -> bool {
+    matches!(section, "input_dialog" | "command_palette" | "command_line")
+}
+
+/// A key input resolved to the user events, the key event is the last key of the sequence.
+pub type KeyInput = (KeyEvent, Vec<UserEvent>);
+
+/// Keys typed so far that are waiting for the rest of a key sequence.
+#[derive(Debug, Default)]
+pub struct PendingKeys {
+    keys: Vec<KeyEvent>,
+    // incremented every time a key is pended, to ignore timeouts of the previous keys
+    id: usize,
+}
+
+impl PendingKeys {
+    /// Resolves the key typed in the section (the key binding section of the focused page or dialog).
+    pub fn push(
+        &mut self,
+        mapper: &UserEventMapper,
+        section: &str,
+        key: KeyEvent,
+    ) -> Vec<KeyInput> {
+        if is_input_section(section) {
+            let mut inputs = self.flush(mapper, section);
+            inputs.push((key, mapper.find_events(&[key])));
+            return inputs;
+        }
+
+        let mut keys = self.keys.clone();
+        keys.push(key);
+
+        if mapper.is_prefix(section, &keys) {
+            self.keys = keys;
+            self.id += 1;
+            return Vec::new();
+        }
+
+        let events = mapper.find_section_events(section, &keys);
+        if !events.is_empty() {
+            self.keys.clear();
+            return vec![(key, events)];
+        }
+
+        if self.keys.is_empty() {
+            return vec![(key, Vec::new())];
+        }
+
+        // the key does not continue the sequence, so handle the pending keys first
+        let mut inputs = self.flush(mapper, section);
+        inputs.extend(self.push(mapper, section, key));
+        inputs
+    }
+
+    /// Resolves the pending keys as they are, each key is handled separately if the keys are not bound.
+    pub fn flush(&mut self, mapper: &UserEventMapper, section: &str) -> Vec<KeyInput> {
+        let keys = std::mem::take(&mut self.keys);
+        let events = mapper.find_section_events(section, &keys);
+        match keys.last() {
+            Some(key) if !events.is_empty() => vec![(*key, events)],
+            _ => keys
+                .into_iter()
+                .map(|key| (key, mapper.find_events(&[key])))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn keys(&self) -> &[KeyEvent] {
+        &self.keys
     }
 }
 
@@ -119,11 +307,17 @@ fn build_user_event_mapper(
     custom_bindings_str: &str
 ) -> Result<UserEventMapper, String> {
     let bindings = deserialize_and_merge_bindings(default_bindings_str, custom_bindings_str)?;
+    validate_key_sequence_prefixes(&bindings)?;
+    let sequences = collect_key_sequences(&bindings)?;
     let mut map = IndexMap::new();
 
     set_event_to_map(&mut map, &bindings, "common", "quit", UserEvent::Quit)?;
     set_event_to_map(&mut map, &bindings, "common", "help", UserEvent::Help)?;
     set_event_to_map(&mut map, &bindings, "common", "dump", UserEvent::DumpApp)?;
+    set_event_to_map(&mut map, &bindings, "common", "command_palette", UserEvent::CommandPalette)?;
+    set_event_to_map(&mut map, &bindings, "common", "command_line", UserEvent::CommandLine)?;
+
+    set_event_to_map(&mut map, &bindings, "initializing", "recent_locations", UserEvent::InitializingRecentLocations)?;
 
     set_event_to_map(&mut map, &bindings, "bucket_list", "down", UserEvent::BucketListDown)?;
     set_event_to_map(&mut map, &bindings, "bucket_list", "up", UserEvent::BucketListUp)?;
@@ -140,6 +334,10 @@ fn build_user_event_mapper(
     set_event_to_map(&mut map, &bindings, "bucket_list", "refresh", UserEvent::BucketListRefresh)?;
     set_event_to_map(&mut map, &bindings, "bucket_list", "reset_filter", UserEvent::BucketListResetFilter)?;
     set_event_to_map(&mut map, &bindings, "bucket_list", "management_console", UserEvent::BucketListManagementConsole)?;
+    set_event_to_map(&mut map, &bindings, "bucket_list", "bookmarks", UserEvent::BucketListBookmarks)?;
+    set_event_to_map(&mut map, &bindings, "bucket_list", "recent_locations", UserEvent::BucketListRecentLocations)?;
+    set_event_to_map(&mut map, &bindings, "bucket_list", "dual_pane", UserEvent::BucketListDualPane)?;
+    set_event_to_map(&mut map, &bindings, "bucket_list", "switch_pane", UserEvent::BucketListSwitchPane)?;
 
     set_event_to_map(&mut map, &bindings, "object_list", "down", UserEvent::ObjectListDown)?;
     set_event_to_map(&mut map, &bindings, "object_list", "up", UserEvent::ObjectListUp)?;
@@ -161,6 +359,16 @@ fn build_user_event_mapper(
     set_event_to_map(&mut map, &bindings, "object_list", "refresh", UserEvent::ObjectListRefresh)?;
     set_event_to_map(&mut map, &bindings, "object_list", "reset_filter", UserEvent::ObjectListResetFilter)?;
     set_event_to_map(&mut map, &bindings, "object_list", "management_console", UserEvent::ObjectListManagementConsole)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "diff", UserEvent::ObjectListDiff)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "bookmarks", UserEvent::ObjectListBookmarks)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "add_bookmark", UserEvent::ObjectListAddBookmark)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "recent_locations", UserEvent::ObjectListRecentLocations)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "history_back", UserEvent::ObjectListHistoryBack)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "history_forward", UserEvent::ObjectListHistoryForward)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "dual_pane", UserEvent::ObjectListDualPane)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "switch_pane", UserEvent::ObjectListSwitchPane)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "copy_to_other_pane", UserEvent::ObjectListCopyToOtherPane)?;
+    set_event_to_map(&mut map, &bindings, "object_list", "move_to_other_pane", UserEvent::ObjectListMoveToOtherPane)?;
     
     set_event_to_map(&mut map, &bindings, "object_detail", "down", UserEvent::ObjectDetailDown)?;
     set_event_to_map(&mut map, &bindings, "object_detail", "up", UserEvent::ObjectDetailUp)?;
@@ -174,6 +382,7 @@ fn build_user_event_mapper(
     set_event_to_map(&mut map, &bindings, "object_detail", "preview", UserEvent::ObjectDetailPreview)?;
     set_event_to_map(&mut map, &bindings, "object_detail", "copy_details", UserEvent::ObjectDetailCopyDetails)?;
     set_event_to_map(&mut map, &bindings, "object_detail", "management_console", UserEvent::ObjectDetailManagementConsole)?;
+    set_event_to_map(&mut map, &bindings, "object_detail", "diff", UserEvent::ObjectDetailDiff)?;
 
     set_event_to_map(&mut map, &bindings, "object_preview", "down", UserEvent::ObjectPreviewDown)?;
     set_event_to_map(&mut map, &bindings, "object_preview", "up", UserEvent::ObjectPreviewUp)?;
@@ -189,11 +398,61 @@ fn build_user_event_mapper(
     set_event_to_map(&mut map, &bindings, "object_preview", "encoding", UserEvent::ObjectPreviewEncoding)?;
     set_event_to_map(&mut map, &bindings, "object_preview", "toggle_wrap", UserEvent::ObjectPreviewToggleWrap)?;
     set_event_to_map(&mut map, &bindings, "object_preview", "toggle_number", UserEvent::ObjectPreviewToggleNumber)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "load_next_chunk", UserEvent::ObjectPreviewLoadNextChunk)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "load_last_chunk", UserEvent::ObjectPreviewLoadLastChunk)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_follow", UserEvent::ObjectPreviewToggleFollow)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "open_entry", UserEvent::ObjectPreviewOpenEntry)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_mark", UserEvent::ObjectPreviewToggleMark)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_schema", UserEvent::ObjectPreviewToggleSchema)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_format", UserEvent::ObjectPreviewToggleFormat)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_fold", UserEvent::ObjectPreviewToggleFold)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_fold_all", UserEvent::ObjectPreviewToggleFoldAll)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_hex", UserEvent::ObjectPreviewToggleHex)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "go_to", UserEvent::ObjectPreviewGoTo)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "search", UserEvent::ObjectPreviewSearch)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "search_next", UserEvent::ObjectPreviewSearchNext)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "search_prev", UserEvent::ObjectPreviewSearchPrev)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_search_case", UserEvent::ObjectPreviewToggleSearchCase)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "toggle_search_regex", UserEvent::ObjectPreviewToggleSearchRegex)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "set_mark", UserEvent::ObjectPreviewSetMark)?;
+    set_event_to_map(&mut map, &bindings, "object_preview", "jump_to_mark", UserEvent::ObjectPreviewJumpToMark)?;
+
+    set_event_to_map(&mut map, &bindings, "object_diff", "down", UserEvent::ObjectDiffDown)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "up", UserEvent::ObjectDiffUp)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "right", UserEvent::ObjectDiffRight)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "left", UserEvent::ObjectDiffLeft)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "go_to_top", UserEvent::ObjectDiffGoToTop)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "go_to_bottom", UserEvent::ObjectDiffGoToBottom)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "page_down", UserEvent::ObjectDiffPageDown)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "page_up", UserEvent::ObjectDiffPageUp)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "back", UserEvent::ObjectDiffBack)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "next_hunk", UserEvent::ObjectDiffNextHunk)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "prev_hunk", UserEvent::ObjectDiffPrevHunk)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "toggle_view", UserEvent::ObjectDiffToggleView)?;
+    set_event_to_map(&mut map, &bindings, "object_diff", "encoding", UserEvent::ObjectDiffEncoding)?;
+
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "down", UserEvent::BookmarkListDown)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "up", UserEvent::BookmarkListUp)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "go_to_top", UserEvent::BookmarkListGoToTop)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "go_to_bottom", UserEvent::BookmarkListGoToBottom)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "select", UserEvent::BookmarkListSelect)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "delete", UserEvent::BookmarkListDelete)?;
+    set_event_to_map(&mut map, &bindings, "bookmark_list", "back", UserEvent::BookmarkListBack)?;
+
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "down", UserEvent::RecentLocationListDown)?;
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "up", UserEvent::RecentLocationListUp)?;
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "go_to_top", UserEvent::RecentLocationListGoToTop)?;
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "go_to_bottom", UserEvent::RecentLocationListGoToBottom)?;
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "select", UserEvent::RecentLocationListSelect)?;
+    set_event_to_map(&mut map, &bindings, "recent_location_list", "back", UserEvent::RecentLocationListBack)?;
 
     set_event_to_map(&mut map, &bindings, "help", "close", UserEvent::HelpClose)?;
 
     set_event_to_map(&mut map, &bindings, "input_dialog", "close", UserEvent::InputDialogClose)?;
     set_event_to_map(&mut map, &bindings, "input_dialog", "apply", UserEvent::InputDialogApply)?;
+    set_event_to_map(&mut map, &bindings, "input_dialog", "complete", UserEvent::InputDialogComplete)?;
+    set_event_to_map(&mut map, &bindings, "input_dialog", "down", UserEvent::InputDialogDown)?;
+    set_event_to_map(&mut map, &bindings, "input_dialog", "up", UserEvent::InputDialogUp)?;
 
     set_event_to_map(&mut map, &bindings, "select_dialog", "down", UserEvent::SelectDialogDown)?;
     set_event_to_map(&mut map, &bindings, "select_dialog", "up", UserEvent::SelectDialogUp)?;
@@ -202,11 +461,22 @@ fn build_user_event_mapper(
     set_event_to_map(&mut map, &bindings, "select_dialog", "close", UserEvent::SelectDialogClose)?;
     set_event_to_map(&mut map, &bindings, "select_dialog", "select", UserEvent::SelectDialogSelect)?;
 
-    Ok(UserEventMapper { map })
+    set_event_to_map(&mut map, &bindings, "command_palette", "close", UserEvent::CommandPaletteClose)?;
+    set_event_to_map(&mut map, &bindings, "command_palette", "execute", UserEvent::CommandPaletteExecute)?;
+    set_event_to_map(&mut map, &bindings, "command_palette", "down", UserEvent::CommandPaletteDown)?;
+    set_event_to_map(&mut map, &bindings, "command_palette", "up", UserEvent::CommandPaletteUp)?;
+
+    set_event_to_map(&mut map, &bindings, "command_line", "close", UserEvent::CommandLineClose)?;
+    set_event_to_map(&mut map, &bindings, "command_line", "execute", UserEvent::CommandLineExecute)?;
+    set_event_to_map(&mut map, &bindings, "command_line", "complete", UserEvent::CommandLineComplete)?;
+    set_event_to_map(&mut map, &bindings, "command_line", "prev_history", UserEvent::CommandLinePrevHistory)?;
+    set_event_to_map(&mut map, &bindings, "command_line", "next_history", UserEvent::CommandLineNextHistory)?;
+
+    Ok(UserEventMapper { map, sequences })
 }
 
 fn set_event_to_map(
-    map: &mut IndexMap<KeyEvent, Vec<UserEvent>>,
+    map: &mut IndexMap<Vec<KeyEvent>, Vec<UserEvent>>,
     bindings: &KeyMap,
     section: &str,
     event: &str,
@@ -218,12 +488,60 @@ fn set_event_to_map(
         .ok_or_else(|| {
             format!("No keybindings found for section '{section}' and event '{event}'")
         })?;
-    for key_event in parse_key_events(keys)? {
-        map.entry(key_event).or_default().push(user_event);
+    for key_sequence in parse_key_sequences(keys)? {
+        map.entry(key_sequence).or_default().push(user_event);
     }
     Ok(())
 }
 
+// A key sequence must not be a prefix of another key sequence in the same section (or the common section),
+// otherwise the longer one could never be typed.
+fn validate_key_sequence_prefixes(bindings: &KeyMap) -> Result<(), String> {
+    let parse_section = |section: &str| -> Result<Vec<(String, Vec<KeyEvent>)>, String> {
+        let mut sequences = Vec::new();
+        for (event, keys) in bindings.get(section).into_iter().flatten() {
+            for key_sequence in parse_key_sequences(keys)? {
+                sequences.push((format!("{section}.{event}"), key_sequence));
+            }
+        }
+        Ok(sequences)
+    };
+
+    let common = parse_section("common")?;
+    for section in bindings.keys() {
+        let mut sequences = parse_section(section)?;
+        if section != "common" {
+            sequences.extend(common.iter().cloned());
+        }
+        for (name, keys) in &sequences {
+            for (other_name, other_keys) in &sequences {
+                if other_keys.len() > keys.len() && other_keys.starts_with(keys) {
+                    return Err(format!(
+                        "Key '{}' of '{name}' conflicts with key sequence '{}' of '{other_name}'",
+                        key_sequence_to_string(keys, false),
+                        key_sequence_to_string(other_keys, false),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_key_sequences(bindings: &KeyMap) -> Result<Vec<(String, Vec<KeyEvent>)>, String> {
+    let mut sequences = Vec::new();
+    for (section, section_bindings) in bindings {
+        for keys in section_bindings.values() {
+            for key_sequence in parse_key_sequences(keys)? {
+                if key_sequence.len() > 1 {
+                    sequences.push((section.clone(), key_sequence));
+                }
+            }
+        }
+    }
+    Ok(sequences)
+}
+
 type KeyMap = IndexMap<String, IndexMap<String, Vec<String>>>;
 
 fn deserialize_and_merge_bindings(
@@ -248,12 +566,24 @@ fn deserialize_and_merge_bindings(
     Ok(bindings)
 }
 
-fn parse_key_events(raws: &[String]) -> Result<Vec<KeyEvent>, String> {
+fn parse_key_sequences(raws: &[String]) -> Result<Vec<Vec<KeyEvent>>, String> {
     raws.iter()
-        .map(|raw| parse_key_event(raw))
+        .map(|raw| parse_key_sequence(raw))
         .collect::<Result<Vec<_>, String>>()
 }
 
+// keys of a sequence are separated by whitespace (e.g. `g g`, `space y`)
+fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
+    let keys = raw
+        .split_whitespace()
+        .map(parse_key_event)
+        .collect::<Result<Vec<_>, String>>()?;
+    if keys.is_empty() {
+        return Err(format!("Unable to parse '{raw}'"));
+    }
+    Ok(keys)
+}
+
 fn parse_key_event(raw: &str) -> Result<KeyEvent, String> {
     let raw_lower = raw.to_ascii_lowercase().replace(' ', "");
     let (remaining, modifiers) = extract_modifiers(&raw_lower);
@@ -333,6 +663,13 @@ fn parse_key_code_with_modifiers(
     Ok(KeyEvent::new(c, modifiers))
 }
 
+pub fn key_sequence_to_string(keys: &[KeyEvent], short: bool) -> String {
+    keys.iter()
+        .map(|key| key_event_to_string(*key, short))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub fn key_event_to_string(key: KeyEvent, short: bool) -> String {
     if let KeyCode::Char(c) = key.code {
         if key.modifiers == KeyModifiers::SHIFT {
@@ -431,3 +768,124 @@ pub fn key_event_to_string(key: KeyEvent, short: bool) -> String {
 
     key
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty())
+    }
+
+    #[test]
+    fn test_pending_keys() {
+        let custom = r#"
+[object_list]
+refresh = ["space r"]
+
+[object_preview]
+go_to_top = ["g g"]
+"#;
+        let mapper = build_user_event_mapper(DEFAULT_KEYBINDINGS, custom).unwrap();
+        let mut pending = PendingKeys::default();
+
+        assert!(pending.push(&mapper, "object_preview", key('g')).is_empty());
+        assert_eq!(pending.keys(), &[key('g')]);
+        let inputs = pending.push(&mapper, "object_preview", key('g'));
+        assert_eq!(
+            inputs,
+            vec![(key('g'), vec![UserEvent::ObjectPreviewGoToTop])]
+        );
+        assert!(pending.is_empty());
+
+        // not continued: the pending key is handled alone, then the new key
+        assert!(pending.push(&mapper, "object_list", key(' ')).is_empty());
+        let inputs = pending.push(&mapper, "object_list", key('j'));
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].0, key(' '));
+        assert!(inputs[0].1.contains(&UserEvent::ObjectPreviewToggleMark));
+        assert_eq!(inputs[1].0, key('j'));
+        assert!(inputs[1].1.contains(&UserEvent::ObjectListDown));
+
+        // timeout
+        assert!(pending.push(&mapper, "object_preview", key('g')).is_empty());
+        let id = pending.id();
+        let inputs = pending.flush(&mapper, "object_preview");
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].1.contains(&UserEvent::ObjectListGoToTop));
+        assert!(!inputs[0].1.contains(&UserEvent::ObjectPreviewGoToTop));
+        assert!(pending.push(&mapper, "object_preview", key('g')).is_empty());
+        assert_ne!(pending.id(), id);
+    }
+
+    #[test]
+    fn test_pending_keys_sequence_of_other_section() {
+        let custom = r#"
+[object_preview]
+go_to_top = ["g g"]
+"#;
+        let mapper = build_user_event_mapper(DEFAULT_KEYBINDINGS, custom).unwrap();
+        let mut pending = PendingKeys::default();
+
+        // the sequence of the preview does not hold the key in the object list
+        let inputs = pending.push(&mapper, "object_list", key('g'));
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].1.contains(&UserEvent::ObjectListGoToTop));
+        assert!(pending.is_empty());
+
+        let inputs = pending.push(&mapper, "object_list", key('g'));
+        assert_eq!(inputs.len(), 1);
+        assert!(!inputs[0].1.contains(&UserEvent::ObjectPreviewGoToTop));
+    }
+
+    #[rstest]
+    #[case("input_dialog")]
+    #[case("command_palette")]
+    #[case("command_line")]
+    fn test_pending_keys_typed_into_input(#[case] section: &str) {
+        let custom = r#"
+[object_list]
+go_to_top = ["g g"]
+copy_details = ["space y"]
+"#;
+        let mapper = build_user_event_mapper(DEFAULT_KEYBINDINGS, custom).unwrap();
+        let mut pending = PendingKeys::default();
+
+        let mut typed = Vec::new();
+        for c in "gg y".chars() {
+            typed.extend(pending.push(&mapper, section, key(c)));
+            assert!(pending.is_empty());
+        }
+        let keys: Vec<KeyEvent> = typed.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, vec![key('g'), key('g'), key(' '), key('y')]);
+        assert!(typed
+            .iter()
+            .all(|(_, events)| !events.contains(&UserEvent::ObjectListCopyDetails)));
+    }
+
+    #[rstest]
+    #[case(
+        "[object_list]\nrefresh = [\"g r\"]",
+        "Key 'g' of 'object_list.go_to_top' conflicts with key sequence 'g r' of 'object_list.refresh'"
+    )]
+    #[case(
+        "[object_list]\nrefresh = [\"ctrl-c r\"]",
+        "Key 'Ctrl-c' of 'common.quit' conflicts with key sequence 'Ctrl-c r' of 'object_list.refresh'"
+    )]
+    fn test_validate_key_sequence_prefixes(#[case] custom: &str, #[case] expected: &str) {
+        let err = build_user_event_mapper(DEFAULT_KEYBINDINGS, custom).unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    #[rstest]
+    #[case("g g", vec![key('g'), key('g')])]
+    #[case("space  y", vec![key(' '), key('y')])]
+    #[case("ctrl-w shift-j", vec![
+        KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
+        KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT),
+    ])]
+    fn test_parse_key_sequence(#[case] raw: &str, #[case] expected: Vec<KeyEvent>) {
+        assert_eq!(parse_key_sequence(raw), Ok(expected));
+    }
+}
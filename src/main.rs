@@ -1,23 +1,35 @@
 mod app;
+mod archive;
+mod bookmark;
+mod cli;
 mod client;
 mod color;
+mod command;
+mod compression;
 mod config;
 mod constant;
+mod credentials;
+mod diff;
 mod environment;
 mod error;
 mod event;
 mod file;
 mod format;
 mod help;
+mod history;
 mod keys;
 mod macros;
 mod object;
 mod pages;
+mod pretty;
 mod run;
+mod search;
+mod table;
 mod util;
 mod widget;
 
-use clap::{arg, Parser, ValueEnum};
+use clap::{Parser, ValueEnum};
+use cli::Command;
 use event::AppEventType;
 use file::open_or_create_append_file;
 use std::sync::Mutex;
@@ -25,10 +37,15 @@ use tracing_subscriber::fmt::time::ChronoLocal;
 
 use crate::{
     app::{App, AppContext},
+    bookmark::Bookmarks,
+    client::Client,
     color::ColorTheme,
+    command::{parse_location_target, LocationTarget},
     config::Config,
+    credentials::AssumeRoleOptions,
     environment::Environment,
     keys::UserEventMapper,
+    object::ObjectKey,
 };
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -52,6 +69,13 @@ impl From<PathStyle> for client::AddressingStyle {
 #[derive(Parser)]
 #[command(version)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Target location (S3 URI, ARN or URL) of a directory or an object
+    #[arg(value_name = "TARGET", conflicts_with_all = ["bucket", "prefix", "bookmark"])]
+    target: Option<String>,
+
     /// AWS region
     #[arg(short, long)]
     region: Option<String>,
@@ -72,10 +96,38 @@ struct Args {
     #[arg(short = 'P', long, value_name = "PREFIX", requires = "bucket")]
     prefix: Option<String>,
 
+    /// Bookmark name to open
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["bucket", "prefix"])]
+    bookmark: Option<String>,
+
     /// Path style type for object paths
     #[arg(long, value_name = "TYPE", default_value = "auto")]
     path_style: PathStyle,
 
+    /// ARN of the IAM role to assume
+    #[arg(long, value_name = "ARN")]
+    role_arn: Option<String>,
+
+    /// External ID to use when assuming the role
+    #[arg(long, value_name = "ID")]
+    external_id: Option<String>,
+
+    /// Session name to use when assuming the role
+    #[arg(long, value_name = "NAME")]
+    role_session_name: Option<String>,
+
+    /// Serial number or ARN of the MFA device
+    #[arg(long, value_name = "SERIAL")]
+    mfa_serial: Option<String>,
+
+    /// Open the preview of the target object instead of its detail
+    #[arg(long, requires = "target")]
+    preview: bool,
+
+    /// Color theme preset name (dark, light)
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
+
     /// Enable debug logs
     #[arg(long)]
     debug: bool,
@@ -83,35 +135,127 @@ struct Args {
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    apply_bookmark(&mut args)?;
+    let target = apply_target(&mut args)?;
     let config = Config::load()?;
     let mapper = UserEventMapper::load()?;
     let env = Environment::new(&config);
-    let theme = ColorTheme::default();
+    let theme = ColorTheme::load(args.theme.as_deref().unwrap_or(&config.ui.theme))?;
     let ctx = AppContext::new(config, env, theme);
 
     initialize_debug_log(&args)?;
 
+    let assume_role = build_assume_role_options(&args, &ctx.config)?;
+    let requires_mfa = assume_role.requires_mfa();
+
     let client = client::new(
         args.region,
         args.endpoint_url,
         args.profile,
         ctx.config.default_region.clone(),
         args.path_style.into(),
+        assume_role,
+        ctx.config.request.clone(),
     )
     .await;
 
+    if let Some(command) = args.command {
+        if requires_mfa {
+            client.set_mfa_token_code(cli::read_mfa_token_code()?);
+        }
+        return cli::run(&client, command, &ctx.config).await;
+    }
+
     let (tx, rx) = event::new();
     let mut app = App::new(mapper, client, ctx, tx.clone());
-    tx.send(AppEventType::Initialize(args.bucket, args.prefix));
+    if let Some(target) = target {
+        let object_key = ObjectKey::with_prefix(target.bucket, target.key);
+        app.set_initial_object(object_key, target.version_id, args.preview);
+    }
+    if requires_mfa {
+        tx.send(AppEventType::OpenMfaTokenCodeDialog(
+            args.bucket,
+            args.prefix,
+        ));
+    } else {
+        tx.send(AppEventType::Initialize(args.bucket, args.prefix));
+    }
 
     let mut terminal = ratatui::try_init()?;
     let ret = run::run(&mut app, &mut terminal, rx).await;
     ratatui::try_restore()?;
+    app.save_recent_locations()?;
 
     ret
 }
 
+fn apply_bookmark(args: &mut Args) -> anyhow::Result<()> {
+    let Some(name) = &args.bookmark else {
+        return Ok(());
+    };
+    let bookmarks = Bookmarks::load()?;
+    let bookmark = bookmarks
+        .find(name)
+        .ok_or_else(|| anyhow::anyhow!("Bookmark not found: {name}"))?;
+    // the profile specified on the command line takes precedence
+    if args.profile.is_none() {
+        args.profile = bookmark.profile.clone();
+    }
+    args.bucket = Some(bookmark.object_key.bucket_name.clone());
+    args.prefix = Some(bookmark.object_key.joined_object_path(false));
+    Ok(())
+}
+
+// Returns the target object, which is opened after its directory is loaded
+fn apply_target(args: &mut Args) -> anyhow::Result<Option<LocationTarget>> {
+    let Some(input) = &args.target else {
+        return Ok(None);
+    };
+    let target =
+        parse_location_target(input).ok_or_else(|| anyhow::anyhow!("Invalid target: {input}"))?;
+    args.bucket = Some(target.bucket.clone());
+    args.prefix = Some(target.dir_prefix().to_string());
+    if target.is_dir() {
+        if args.preview {
+            anyhow::bail!("Target is not an object: {input}");
+        }
+        return Ok(None);
+    }
+    Ok(Some(target))
+}
+
+fn build_assume_role_options(args: &Args, config: &Config) -> anyhow::Result<AssumeRoleOptions> {
+    // command line options take precedence over the config file
+    let config = &config.assume_role;
+    let options = AssumeRoleOptions {
+        role_arn: args.role_arn.clone().or_else(|| config.role_arn.clone()),
+        external_id: args
+            .external_id
+            .clone()
+            .or_else(|| config.external_id.clone()),
+        role_session_name: args
+            .role_session_name
+            .clone()
+            .unwrap_or_else(|| config.role_session_name.clone()),
+        mfa_serial: args
+            .mfa_serial
+            .clone()
+            .or_else(|| config.mfa_serial.clone()),
+    };
+    if options.role_arn.is_none() {
+        if args.external_id.is_some() || options.external_id.is_some() {
+            anyhow::bail!("External ID requires a role ARN (--role-arn or assume_role.role_arn)");
+        }
+        if args.role_session_name.is_some() {
+            anyhow::bail!(
+                "Role session name requires a role ARN (--role-arn or assume_role.role_arn)"
+            );
+        }
+    }
+    Ok(options)
+}
+
 fn initialize_debug_log(args: &Args) -> anyhow::Result<()> {
     if args.debug {
         let path = Config::debug_log_path()?;
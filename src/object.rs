@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{self, Debug, Formatter},
 };
 
@@ -56,7 +56,7 @@ impl ObjectItem {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct FileDetail {
     pub name: String,
     pub size_byte: usize,
@@ -100,6 +100,137 @@ pub struct DownloadObjectInfo {
     pub size_byte: usize,
 }
 
+/// A range of bytes in an object, `end` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl ByteRange {
+    /// Returns the first `limit` bytes of an object of `size` bytes.
+    pub fn head(size: usize, limit: usize) -> ByteRange {
+        ByteRange {
+            start: 0,
+            end: size.min(limit),
+        }
+    }
+
+    /// Returns the last `limit` bytes of an object of `size` bytes.
+    pub fn tail(size: usize, limit: usize) -> ByteRange {
+        ByteRange {
+            start: size.saturating_sub(limit),
+            end: size,
+        }
+    }
+
+    /// Returns the next `limit` bytes after this range, or `None` if this range reaches the end.
+    pub fn next(&self, size: usize, limit: usize) -> Option<ByteRange> {
+        (self.end < size).then(|| ByteRange {
+            start: self.end,
+            end: size.min(self.end + limit),
+        })
+    }
+
+    /// Returns the previous `limit` bytes before this range, or `None` if this range starts from the beginning.
+    pub fn prev(&self, limit: usize) -> Option<ByteRange> {
+        (self.start > 0).then(|| ByteRange {
+            start: self.start.saturating_sub(limit),
+            end: self.start,
+        })
+    }
+
+    /// Returns `limit` bytes from `offset`, or the last `limit` bytes if they exceed the end of an object of `size` bytes.
+    pub fn from_offset(offset: usize, size: usize, limit: usize) -> ByteRange {
+        if offset.saturating_add(limit) >= size {
+            ByteRange::tail(size, limit)
+        } else {
+            ByteRange {
+                start: offset,
+                end: offset + limit,
+            }
+        }
+    }
+
+    /// Returns the range to load after an object that this range was loaded from has changed to `size` bytes.
+    /// If the object has grown, only the appended bytes are returned, otherwise the object is considered to be rewritten.
+    pub fn follow(&self, size: usize, limit: usize) -> ByteRange {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        if size > self.end && size - self.end <= limit {
+            ByteRange {
+                start: self.end,
+                end: size,
+            }
+        } else {
+            ByteRange::tail(size, limit)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn is_entire(&self, size: usize) -> bool {
+        self.start == 0 && self.end >= size
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FailedObject {
+    pub obj: DownloadObjectInfo,
+    pub error: String,
+}
+
+/// The result of an operation on multiple objects, such as a recursive download or copy.
+#[derive(Debug, Clone, Default)]
+pub struct BulkReport {
+    pub succeeded_count: usize,
+    pub succeeded_size_byte: usize,
+    pub failed: Vec<FailedObject>,
+    // objects that were not processed because the operation was stopped
+    pub skipped: Vec<DownloadObjectInfo>,
+    pub credentials_expired: bool,
+}
+
+impl BulkReport {
+    pub fn processed_count(&self) -> usize {
+        self.succeeded_count + self.failed.len()
+    }
+
+    pub fn is_all_succeeded(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+
+    /// Returns the objects to process again, i.e. the failed and skipped objects.
+    pub fn retry_targets(&self) -> Vec<DownloadObjectInfo> {
+        let failed = self.failed.iter().map(|f| f.obj.clone());
+        failed.chain(self.skipped.iter().cloned()).collect()
+    }
+
+    /// Returns the objects of `objs` that were processed successfully.
+    pub fn succeeded_objects(&self, objs: Vec<DownloadObjectInfo>) -> Vec<DownloadObjectInfo> {
+        let unsucceeded: HashSet<String> = self
+            .retry_targets()
+            .into_iter()
+            .map(|obj| obj.key)
+            .collect();
+        objs.into_iter()
+            .filter(|obj| !unsucceeded.contains(&obj.key))
+            .collect()
+    }
+
+    /// Combines this report with the report of the next step run for the succeeded objects,
+    /// so that an object succeeds only if it succeeded in both steps.
+    pub fn followed_by(mut self, next: BulkReport) -> BulkReport {
+        self.succeeded_count = next.succeeded_count;
+        self.succeeded_size_byte = next.succeeded_size_byte;
+        self.failed.extend(next.failed);
+        self.skipped.extend(next.skipped);
+        self.credentials_expired |= next.credentials_expired;
+        self
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct AppObjects {
     bucket_items: Vec<BucketItem>,
@@ -248,6 +379,63 @@ mod tests {
         assert_eq!(key.has_prefix(&prefix), expected);
     }
 
+    #[rstest]
+    #[case((0, 100), 150, 100, (100, 150))] // appended
+    #[case((0, 100), 250, 100, (150, 250))] // appended more than the limit
+    #[case((50, 150), 120, 100, (20, 120))] // rewritten to be smaller
+    #[case((0, 100), 100, 100, (0, 100))] // rewritten with the same size
+    #[case((0, 100), 1000, 0, (100, 1000))] // no limit
+    fn test_byte_range_follow(
+        #[case] loaded: (usize, usize),
+        #[case] size: usize,
+        #[case] limit: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        let loaded = ByteRange {
+            start: loaded.0,
+            end: loaded.1,
+        };
+        let expected = ByteRange {
+            start: expected.0,
+            end: expected.1,
+        };
+        assert_eq!(loaded.follow(size, limit), expected);
+    }
+
+    #[rstest]
+    #[case((200, 300), 100, Some((100, 200)))]
+    #[case((50, 150), 100, Some((0, 50)))]
+    #[case((0, 100), 100, None)]
+    fn test_byte_range_prev(
+        #[case] loaded: (usize, usize),
+        #[case] limit: usize,
+        #[case] expected: Option<(usize, usize)>,
+    ) {
+        let loaded = ByteRange {
+            start: loaded.0,
+            end: loaded.1,
+        };
+        let expected = expected.map(|(start, end)| ByteRange { start, end });
+        assert_eq!(loaded.prev(limit), expected);
+    }
+
+    #[rstest]
+    #[case(100, 1000, 100, (100, 200))]
+    #[case(950, 1000, 100, (900, 1000))]
+    #[case(0, 50, 100, (0, 50))]
+    fn test_byte_range_from_offset(
+        #[case] offset: usize,
+        #[case] size: usize,
+        #[case] limit: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        let expected = ByteRange {
+            start: expected.0,
+            end: expected.1,
+        };
+        assert_eq!(ByteRange::from_offset(offset, size, limit), expected);
+    }
+
     #[test]
     fn test_clear_object_items_under() {
         let mut app_objects = AppObjects::default();
@@ -286,6 +474,44 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_bulk_report_followed_by() {
+        let obj = |key: &str| DownloadObjectInfo {
+            key: key.to_string(),
+            size_byte: 10,
+        };
+        let failed = |key: &str| FailedObject {
+            obj: obj(key),
+            error: "error".to_string(),
+        };
+        let objs = vec![obj("a"), obj("b"), obj("c"), obj("d")];
+
+        let copy_report = BulkReport {
+            succeeded_count: 2,
+            succeeded_size_byte: 20,
+            failed: vec![failed("b")],
+            skipped: vec![obj("d")],
+            credentials_expired: false,
+        };
+        let copied = copy_report.succeeded_objects(objs);
+        let copied_keys: Vec<String> = copied.into_iter().map(|o| o.key).collect();
+        assert_eq!(copied_keys, vec!["a", "c"]);
+
+        let delete_report = BulkReport {
+            succeeded_count: 1,
+            succeeded_size_byte: 10,
+            failed: vec![failed("c")],
+            skipped: vec![],
+            credentials_expired: true,
+        };
+        let report = copy_report.followed_by(delete_report);
+        assert_eq!(report.succeeded_count, 1);
+        assert_eq!(report.succeeded_size_byte, 10);
+        let retry_keys: Vec<String> = report.retry_targets().into_iter().map(|o| o.key).collect();
+        assert_eq!(retry_keys, vec!["b", "c", "d"]);
+        assert!(report.credentials_expired);
+    }
+
     fn object_key(bucket_name: &str, object_path: &[&str]) -> ObjectKey {
         ObjectKey {
             bucket_name: bucket_name.to_string(),
@@ -1,8 +1,11 @@
 pub mod page;
 
+pub mod bookmark_list;
 pub mod bucket_list;
 pub mod help;
 pub mod initializing;
 pub mod object_detail;
+pub mod object_diff;
 pub mod object_list;
 pub mod object_preview;
+pub mod recent_location_list;
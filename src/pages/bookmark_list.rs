@@ -0,0 +1,314 @@
+use std::rc::Rc;
+
+use ratatui::{
+    crossterm::event::KeyEvent, layout::Rect, style::Style, text::Line, widgets::ListItem, Frame,
+};
+
+use crate::{
+    app::AppContext,
+    bookmark::Bookmark,
+    color::ColorTheme,
+    event::{AppEventType, Sender},
+    handle_user_events,
+    help::{
+        build_help_spans, build_short_help_spans, BuildHelpsItem, BuildShortHelpsItem, Spans,
+        SpansWithPriority,
+    },
+    keys::{UserEvent, UserEventMapper},
+    widget::{ScrollList, ScrollListState},
+};
+
+const ELLIPSIS: &str = "...";
+
+#[derive(Debug)]
+pub struct BookmarkListPage {
+    bookmarks: Vec<Bookmark>,
+
+    list_state: ScrollListState,
+
+    ctx: Rc<AppContext>,
+    tx: Sender,
+}
+
+impl BookmarkListPage {
+    pub fn new(bookmarks: Vec<Bookmark>, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        let items_len = bookmarks.len();
+        Self {
+            bookmarks,
+            list_state: ScrollListState::new(items_len),
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, user_events: Vec<UserEvent>, _key_event: KeyEvent) {
+        handle_user_events! { user_events =>
+            UserEvent::BookmarkListSelect if self.non_empty() => {
+                let bookmark = self.current_selected_bookmark().clone();
+                self.tx.send(AppEventType::OpenBookmark(bookmark));
+            }
+            UserEvent::BookmarkListDown if self.non_empty() => {
+                self.list_state.select_next();
+            }
+            UserEvent::BookmarkListUp if self.non_empty() => {
+                self.list_state.select_prev();
+            }
+            UserEvent::BookmarkListGoToTop if self.non_empty() => {
+                self.list_state.select_first();
+            }
+            UserEvent::BookmarkListGoToBottom if self.non_empty() => {
+                self.list_state.select_last();
+            }
+            UserEvent::BookmarkListDelete if self.non_empty() => {
+                self.delete_selected_bookmark();
+            }
+            UserEvent::BookmarkListBack => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            UserEvent::Help => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            UserEvent::CommandLine => {
+                self.tx.send(AppEventType::OpenCommandLine);
+            }
+            UserEvent::CommandPalette => {
+                self.tx.send(AppEventType::OpenCommandPalette);
+            }
+        }
+    }
+
+    pub fn key_section(&self) -> &'static str {
+        "bookmark_list"
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let list_items = build_list_items(
+            &self.bookmarks,
+            &self.ctx.theme,
+            self.list_state.offset,
+            self.list_state.selected,
+            area,
+        );
+
+        let list = ScrollList::new(list_items)
+            .title("Bookmarks")
+            .theme(&self.ctx.theme);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+            BuildHelpsItem::new(UserEvent::BookmarkListDown, "Select next item"),
+            BuildHelpsItem::new(UserEvent::BookmarkListUp, "Select previous item"),
+            BuildHelpsItem::new(UserEvent::BookmarkListGoToTop, "Go to top"),
+            BuildHelpsItem::new(UserEvent::BookmarkListGoToBottom, "Go to bottom"),
+            BuildHelpsItem::new(UserEvent::BookmarkListSelect, "Open bookmark"),
+            BuildHelpsItem::new(UserEvent::BookmarkListDelete, "Delete bookmark"),
+            BuildHelpsItem::new(UserEvent::BookmarkListBack, "Close bookmark list"),
+        ];
+        helps
+    }
+
+    pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+            BuildShortHelpsItem::group(vec![UserEvent::BookmarkListDown, UserEvent::BookmarkListUp], "Select", 1),
+            BuildShortHelpsItem::group(vec![UserEvent::BookmarkListGoToTop, UserEvent::BookmarkListGoToBottom], "Top/Bottom", 5),
+            BuildShortHelpsItem::single(UserEvent::BookmarkListSelect, "Open", 2),
+            BuildShortHelpsItem::single(UserEvent::BookmarkListDelete, "Delete", 4),
+            BuildShortHelpsItem::single(UserEvent::BookmarkListBack, "Close", 3),
+            BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+        ];
+        build_short_help_spans(helps, mapper)
+    }
+}
+
+impl BookmarkListPage {
+    fn non_empty(&self) -> bool {
+        !self.bookmarks.is_empty()
+    }
+
+    fn current_selected_bookmark(&self) -> &Bookmark {
+        &self.bookmarks[self.list_state.selected]
+    }
+
+    fn delete_selected_bookmark(&mut self) {
+        let bookmark = self.bookmarks.remove(self.list_state.selected);
+        self.tx.send(AppEventType::RemoveBookmark(bookmark.name));
+
+        let ScrollListState {
+            selected, offset, ..
+        } = self.list_state;
+        self.list_state = ScrollListState::new(self.bookmarks.len());
+        self.list_state.selected = selected.min(self.bookmarks.len().saturating_sub(1));
+        self.list_state.offset = offset.min(self.list_state.selected);
+    }
+}
+
+fn build_list_items<'a>(
+    bookmarks: &'a [Bookmark],
+    theme: &'a ColorTheme,
+    offset: usize,
+    selected: usize,
+    area: Rect,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize) - 2 /* border */;
+    let name_w = bookmarks
+        .iter()
+        .map(|b| console::measure_text_width(&b.name))
+        .max()
+        .unwrap_or_default();
+    bookmarks
+        .iter()
+        .skip(offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(idx, bookmark)| {
+            let selected = idx + offset == selected;
+            build_list_item(bookmark, name_w, selected, area.width, theme)
+        })
+        .collect()
+}
+
+fn build_list_item<'a>(
+    bookmark: &'a Bookmark,
+    name_w: usize,
+    selected: bool,
+    width: u16,
+    theme: &'a ColorTheme,
+) -> ListItem<'a> {
+    let name = console::pad_str(&bookmark.name, name_w, console::Alignment::Left, None);
+    let location = match &bookmark.profile {
+        Some(profile) => format!("{} ({})", bookmark.s3_uri(), profile),
+        None => bookmark.s3_uri(),
+    };
+    let content = format!("{name}  {location}");
+
+    let content_w = (width as usize) - 4 /* border + pad */;
+    let pad_content = console::pad_str(
+        &content,
+        content_w,
+        console::Alignment::Left,
+        Some(ELLIPSIS),
+    )
+    .to_string();
+    let line = Line::from(vec![" ".into(), pad_content.into(), " ".into()]);
+
+    let style = if selected {
+        Style::default()
+            .bg(theme.list_selected_bg)
+            .fg(theme.list_selected_fg)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{object::ObjectKey, set_cells};
+
+    use super::*;
+    use ratatui::{
+        backend::TestBackend, buffer::Buffer, crossterm::event::KeyCode, style::Color, Terminal,
+    };
+
+    #[tokio::test]
+    async fn test_render() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = sender();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let mut page = BookmarkListPage::new(bookmarks(), ctx, tx);
+            let area = Rect::new(0, 0, 46, 8);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Bookmarks──────────────────────────── 1 / 3 ┐",
+            "│  logs    s3://bucket-1/logs/2024/ (prod)   │",
+            "│  root    s3://bucket-2/                    │",
+            "│  images  s3://bucket-1/assets/images/      │",
+            "│                                            │",
+            "│                                            │",
+            "│                                            │",
+            "└────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..44, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_select_and_delete() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = sender();
+        let mut page = BookmarkListPage::new(bookmarks(), ctx, tx);
+
+        page.handle_key(
+            vec![UserEvent::BookmarkListGoToBottom],
+            KeyEvent::from(KeyCode::Char('G')),
+        );
+        page.handle_key(
+            vec![UserEvent::BookmarkListDelete],
+            KeyEvent::from(KeyCode::Char('d')),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::RemoveBookmark(name)) if name == "images"
+        ));
+        assert_eq!(page.bookmarks.len(), 2);
+        assert_eq!(page.list_state.selected, 1);
+
+        page.handle_key(
+            vec![UserEvent::BookmarkListUp],
+            KeyEvent::from(KeyCode::Char('k')),
+        );
+        page.handle_key(
+            vec![UserEvent::BookmarkListSelect],
+            KeyEvent::from(KeyCode::Enter),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::OpenBookmark(bookmark)) if bookmark.name == "logs"
+        ));
+    }
+
+    fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
+        let backend = TestBackend::new(46, 8);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+        Ok(terminal)
+    }
+
+    fn sender() -> (Sender, tokio::sync::mpsc::UnboundedReceiver<AppEventType>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (Sender::new(tx), rx)
+    }
+
+    fn bookmarks() -> Vec<Bookmark> {
+        let bookmark = |name: &str, profile: Option<&str>, bucket: &str, prefix: &str| Bookmark {
+            name: name.into(),
+            profile: profile.map(String::from),
+            object_key: ObjectKey::with_prefix(bucket, prefix.into()),
+        };
+        vec![
+            bookmark("logs", Some("prod"), "bucket-1", "logs/2024/"),
+            bookmark("root", None, "bucket-2", ""),
+            bookmark("images", None, "bucket-1", "assets/images/"),
+        ]
+    }
+}
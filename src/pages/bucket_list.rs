@@ -22,6 +22,7 @@ use crate::{
     },
     keys::{UserEvent, UserEventMapper},
     object::{BucketItem, DownloadObjectInfo, ObjectKey},
+    search::ListFilter,
     widget::{
         BucketListSortDialog, BucketListSortDialogState, BucketListSortType, ConfirmDialog,
         ConfirmDialogState, CopyDetailDialog, CopyDetailDialogState, InputDialog, InputDialogState,
@@ -109,6 +110,18 @@ impl BucketListPage {
                     UserEvent::BucketListSort => {
                         self.open_sort_dialog();
                     }
+                    UserEvent::BucketListBookmarks => {
+                        self.tx.send(AppEventType::OpenBookmarks);
+                    }
+                    UserEvent::BucketListRecentLocations => {
+                        self.tx.send(AppEventType::OpenRecentLocations);
+                    }
+                    UserEvent::BucketListDualPane => {
+                        self.tx.send(AppEventType::ToggleDualPane);
+                    }
+                    UserEvent::BucketListSwitchPane => {
+                        self.tx.send(AppEventType::SwitchPane);
+                    }
                     UserEvent::BucketListCopyDetails => {
                         self.open_copy_detail_dialog();
                     }
@@ -124,6 +137,12 @@ impl BucketListPage {
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
                 }
             }
             ViewState::FilterDialog => {
@@ -218,6 +237,16 @@ impl BucketListPage {
         }
     }
 
+    pub fn key_section(&self) -> &'static str {
+        match self.view_state {
+            ViewState::Default => "bucket_list",
+            ViewState::FilterDialog | ViewState::SaveDialog(..) => "input_dialog",
+            ViewState::SortDialog
+            | ViewState::CopyDetailDialog(_)
+            | ViewState::DownloadConfirmDialog(..) => "select_dialog",
+        }
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
@@ -225,7 +254,7 @@ impl BucketListPage {
         let list_items = build_list_items(
             &self.bucket_items,
             &self.view_indices,
-            self.filter_input_state.input(),
+            &ListFilter::new(self.filter_input_state.input()),
             &self.ctx.theme,
             offset,
             selected,
@@ -276,6 +305,10 @@ impl BucketListPage {
     }
 
     pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
         #[rustfmt::skip]
         let helps = match self.view_state {
             ViewState::Default => {
@@ -291,6 +324,10 @@ impl BucketListPage {
                         BuildHelpsItem::new(UserEvent::BucketListSelect, "Open bucket"),
                         BuildHelpsItem::new(UserEvent::BucketListFilter, "Filter bucket list"),
                         BuildHelpsItem::new(UserEvent::BucketListSort, "Sort bucket list"),
+                        BuildHelpsItem::new(UserEvent::BucketListBookmarks, "Open bookmark list"),
+                        BuildHelpsItem::new(UserEvent::BucketListRecentLocations, "Open recent locations"),
+                        BuildHelpsItem::new(UserEvent::BucketListDualPane, "Toggle dual pane"),
+                        BuildHelpsItem::new(UserEvent::BucketListSwitchPane, "Switch pane"),
                         BuildHelpsItem::new(UserEvent::BucketListCopyDetails, "Open copy dialog"),
                         BuildHelpsItem::new(UserEvent::BucketListDownloadObject, "Download object"),
                         BuildHelpsItem::new(UserEvent::BucketListDownloadObjectAs, "Download object as"),
@@ -310,6 +347,10 @@ impl BucketListPage {
                         BuildHelpsItem::new(UserEvent::BucketListSelect, "Open bucket"),
                         BuildHelpsItem::new(UserEvent::BucketListFilter, "Filter bucket list"),
                         BuildHelpsItem::new(UserEvent::BucketListSort, "Sort bucket list"),
+                        BuildHelpsItem::new(UserEvent::BucketListBookmarks, "Open bookmark list"),
+                        BuildHelpsItem::new(UserEvent::BucketListRecentLocations, "Open recent locations"),
+                        BuildHelpsItem::new(UserEvent::BucketListDualPane, "Toggle dual pane"),
+                        BuildHelpsItem::new(UserEvent::BucketListSwitchPane, "Switch pane"),
                         BuildHelpsItem::new(UserEvent::BucketListCopyDetails, "Open copy dialog"),
                         BuildHelpsItem::new(UserEvent::BucketListDownloadObject, "Download object"),
                         BuildHelpsItem::new(UserEvent::BucketListDownloadObjectAs, "Download object as"),
@@ -360,7 +401,7 @@ impl BucketListPage {
                 ]
             }
         };
-        build_help_spans(helps, mapper, self.ctx.theme.help_key_fg)
+        helps
     }
 
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
@@ -509,12 +550,12 @@ impl BucketListPage {
     }
 
     fn filter_view_indices(&mut self) {
-        let filter = self.filter_input_state.input();
+        let filter = ListFilter::new(self.filter_input_state.input());
         self.view_indices = self
             .bucket_items
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.name.contains(filter))
+            .filter(|(_, item)| filter.find(&item.name).is_some())
             .map(|(idx, _)| idx)
             .collect();
         // reset list state
@@ -523,6 +564,18 @@ impl BucketListPage {
         self.sort_view_indices();
     }
 
+    pub fn filter_by(&mut self, filter: &str) {
+        self.filter_input_state = InputDialogState::new(filter.into());
+
+        self.filter_view_indices();
+    }
+
+    pub fn sort_by(&mut self, sort_type: BucketListSortType) {
+        self.sort_dialog_state.select(sort_type);
+
+        self.sort_view_indices();
+    }
+
     fn apply_sort(&mut self) {
         self.view_state = ViewState::Default;
 
@@ -670,7 +723,7 @@ impl BucketListPage {
 fn build_list_items<'a>(
     current_items: &'a [BucketItem],
     view_indices: &'a [usize],
-    filter: &'a str,
+    filter: &ListFilter,
     theme: &'a ColorTheme,
     offset: usize,
     selected: usize,
@@ -693,7 +746,7 @@ fn build_list_items<'a>(
 fn build_list_item<'a>(
     name: &'a str,
     selected: bool,
-    filter: &'a str,
+    filter: &ListFilter,
     width: u16,
     theme: &'a ColorTheme,
 ) -> ListItem<'a> {
@@ -701,19 +754,19 @@ fn build_list_item<'a>(
     let pad_name =
         console::pad_str(name, name_w, console::Alignment::Left, Some(ELLIPSIS)).to_string();
 
-    let line = if filter.is_empty() {
-        Line::from(vec![" ".into(), pad_name.into(), " ".into()])
-    } else {
-        let i = name.find(filter).unwrap();
+    let matched = filter.find(name).filter(|_| !filter.is_empty());
+    let line = if let Some(range) = matched {
         let mut spans = highlight_matched_text(pad_name)
             .ellipsis(ELLIPSIS)
-            .matched_range(i, i + filter.len())
+            .matched_range(range.start, range.end)
             .not_matched_style(Style::default())
             .matched_style(Style::default().fg(theme.list_filter_match))
             .into_spans();
         spans.insert(0, " ".into());
         spans.push(" ".into());
         Line::from(spans)
+    } else {
+        Line::from(vec![" ".into(), pad_name.into(), " ".into()])
     };
 
     let style = if selected {
@@ -16,8 +16,8 @@ use crate::{
     event::{AppEventType, Sender},
     handle_user_events,
     help::{
-        build_short_help_spans, group_spans_to_fit_width, BuildShortHelpsItem, Spans,
-        SpansWithPriority,
+        build_short_help_spans, group_spans_to_fit_width, BuildHelpsItem, BuildShortHelpsItem,
+        Spans, SpansWithPriority,
     },
     keys::{UserEvent, UserEventMapper},
     widget::Divider,
@@ -44,6 +44,10 @@ impl HelpPage {
         }
     }
 
+    pub fn key_section(&self) -> &'static str {
+        "help"
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let block = Block::bordered()
             .padding(Padding::horizontal(1))
@@ -79,6 +83,10 @@ impl HelpPage {
         Vec::new()
     }
 
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        Vec::new()
+    }
+
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
         #[rustfmt::skip]
         let helps = vec![
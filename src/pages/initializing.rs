@@ -4,23 +4,34 @@ use ratatui::{crossterm::event::KeyEvent, layout::Rect, style::Stylize, widgets:
 
 use crate::{
     app::AppContext,
-    event::Sender,
-    help::{build_short_help_spans, BuildShortHelpsItem, Spans, SpansWithPriority},
+    event::{AppEventType, Sender},
+    handle_user_events,
+    help::{build_short_help_spans, BuildHelpsItem, BuildShortHelpsItem, Spans, SpansWithPriority},
     keys::{UserEvent, UserEventMapper},
 };
 
 #[derive(Debug)]
 pub struct InitializingPage {
     ctx: Rc<AppContext>,
-    _tx: Sender,
+    tx: Sender,
 }
 
 impl InitializingPage {
     pub fn new(ctx: Rc<AppContext>, tx: Sender) -> Self {
-        Self { ctx, _tx: tx }
+        Self { ctx, tx }
     }
 
-    pub fn handle_key(&mut self, _user_events: Vec<UserEvent>, _key_event: KeyEvent) {}
+    pub fn handle_key(&mut self, user_events: Vec<UserEvent>, _key_event: KeyEvent) {
+        handle_user_events! { user_events =>
+            UserEvent::InitializingRecentLocations => {
+                self.tx.send(AppEventType::OpenRecentLocations);
+            }
+        }
+    }
+
+    pub fn key_section(&self) -> &'static str {
+        "initializing"
+    }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let content = Block::bordered().fg(self.ctx.theme.fg);
@@ -31,10 +42,15 @@ impl InitializingPage {
         Vec::new()
     }
 
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        Vec::new()
+    }
+
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
         #[rustfmt::skip]
         let helps = vec![
             BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+            BuildShortHelpsItem::single(UserEvent::InitializingRecentLocations, "Recent locations", 1),
         ];
         build_short_help_spans(helps, mapper)
     }
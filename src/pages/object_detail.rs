@@ -14,7 +14,7 @@ use crate::{
     app::AppContext,
     color::ColorTheme,
     config::UiConfig,
-    event::{AppEventType, Sender},
+    event::{AppEventType, DiffSpec, DiffTarget, Sender},
     format::{format_datetime, format_size_byte, format_version},
     handle_user_events, handle_user_events_with_default,
     help::{
@@ -37,6 +37,8 @@ pub struct ObjectDetailPage {
 
     tab: Tab,
     view_state: ViewState,
+    // index of the version selected as the base of the comparison
+    diff_base: Option<usize>,
 
     object_items: Vec<ObjectItem>,
     list_state: ScrollListState,
@@ -83,6 +85,7 @@ impl ObjectDetailPage {
             object_key,
             tab: Tab::Detail(detail_tab_state),
             view_state: ViewState::Default,
+            diff_base: None,
             object_items,
             list_state,
             ctx,
@@ -145,9 +148,20 @@ impl ObjectDetailPage {
                     UserEvent::ObjectDetailManagementConsole => {
                         self.open_management_console();
                     }
+                    UserEvent::ObjectDetailDiff => {
+                        if let Tab::Version(_) = self.tab {
+                            self.diff_versions();
+                        }
+                    }
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
                 }
             }
             ViewState::SaveDialog(ref mut state) => {
@@ -190,6 +204,14 @@ impl ObjectDetailPage {
         }
     }
 
+    pub fn key_section(&self) -> &'static str {
+        match self.view_state {
+            ViewState::Default => "object_detail",
+            ViewState::SaveDialog(_) => "input_dialog",
+            ViewState::CopyDetailDialog(_) => "select_dialog",
+        }
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let chunks = Layout::horizontal(Constraint::from_percentages([50, 50])).split(area);
 
@@ -246,6 +268,10 @@ impl ObjectDetailPage {
     }
 
     pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
         #[rustfmt::skip]
         let helps = match self.view_state {
             ViewState::Default => match self.tab {
@@ -278,6 +304,7 @@ impl ObjectDetailPage {
                         BuildHelpsItem::new(UserEvent::ObjectDetailDownload, "Download object"),
                         BuildHelpsItem::new(UserEvent::ObjectDetailDownloadAs, "Download object as"),
                         BuildHelpsItem::new(UserEvent::ObjectDetailPreview, "Preview object"),
+                        BuildHelpsItem::new(UserEvent::ObjectDetailDiff, "Mark version to compare / Compare versions"),
                         BuildHelpsItem::new(UserEvent::ObjectDetailManagementConsole, "Open management console in browser"),
                     ]
                 },
@@ -299,7 +326,7 @@ impl ObjectDetailPage {
                 ]
             },
         };
-        build_help_spans(helps, mapper, self.ctx.theme.help_key_fg)
+        helps
     }
 
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
@@ -325,6 +352,7 @@ impl ObjectDetailPage {
                             BuildShortHelpsItem::group(vec![UserEvent::ObjectDetailDown, UserEvent::ObjectDetailUp], "Select", 5),
                             BuildShortHelpsItem::group(vec![UserEvent::ObjectDetailDownload, UserEvent::ObjectDetailDownloadAs], "Download", 1),
                             BuildShortHelpsItem::single(UserEvent::ObjectDetailPreview, "Preview", 4),
+                            BuildShortHelpsItem::single(UserEvent::ObjectDetailDiff, "Compare", 6),
                             BuildShortHelpsItem::single(UserEvent::ObjectDetailBack, "Close", 2),
                             BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
                         ]
@@ -380,6 +408,24 @@ impl ObjectDetailPage {
         self.file_versions = versions;
     }
 
+    /// Selects the version in the versions tab, returns false if it is not found.
+    pub fn select_version(&mut self, version_id: &str) -> bool {
+        let Tab::Version(state) = &mut self.tab else {
+            return false;
+        };
+        match self
+            .file_versions
+            .iter()
+            .position(|v| v.version_id == version_id)
+        {
+            Some(i) => {
+                state.select(i);
+                true
+            }
+            None => false,
+        }
+    }
+
     fn open_save_dialog(&mut self) {
         let name = self.file_detail.name.clone();
         self.view_state = ViewState::SaveDialog(InputDialogState::new(name));
@@ -449,6 +495,47 @@ impl ObjectDetailPage {
         ));
     }
 
+    fn diff_versions(&mut self) {
+        let Tab::Version(ref state) = self.tab else {
+            return;
+        };
+        let selected = state.selected;
+
+        let Some(base) = self.diff_base else {
+            self.diff_base = Some(selected);
+            let msg = "Version marked, select another version to compare".to_string();
+            self.tx.send(AppEventType::NotifyInfo(msg));
+            return;
+        };
+        if base == selected {
+            self.diff_base = None;
+            let msg = "Version unmarked".to_string();
+            self.tx.send(AppEventType::NotifyInfo(msg));
+            return;
+        }
+
+        // versions are listed from newest to oldest
+        let (old, new) = if base > selected {
+            (base, selected)
+        } else {
+            (selected, base)
+        };
+        let (Some(old), Some(new)) = (self.diff_target(old), self.diff_target(new)) else {
+            return;
+        };
+        self.diff_base = None;
+        self.tx.send(AppEventType::OpenDiff(DiffSpec { old, new }));
+    }
+
+    fn diff_target(&self, idx: usize) -> Option<DiffTarget> {
+        self.file_versions.get(idx).map(|version| DiffTarget {
+            object_key: self.object_key.clone(),
+            name: self.file_detail.name.clone(),
+            size_byte: version.size_byte,
+            version_id: Some(version.version_id.clone()),
+        })
+    }
+
     fn open_management_console(&self) {
         let object_key = self.object_key.clone();
         self.tx
@@ -702,6 +789,11 @@ impl VersionTabState {
         self.offset = 0;
     }
 
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.offset = index;
+    }
+
     fn select_last(&mut self) {
         self.selected = self.lines.len() - 1;
 
@@ -1310,6 +1402,46 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_diff_versions() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let tx = Sender::new(tx);
+
+        let (items, file_detail, file_versions, object_key) = fixtures();
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            items,
+            object_key,
+            ScrollListState::new(items_len),
+            ctx,
+            tx,
+        );
+        page.set_versions(file_versions);
+        page.select_versions_tab();
+
+        let key_event = KeyEvent::from(KeyCode::Char('d'));
+        page.handle_key(vec![UserEvent::ObjectDetailDiff], key_event);
+        assert!(matches!(rx.try_recv(), Ok(AppEventType::NotifyInfo(_))));
+        assert_eq!(page.diff_base, Some(0));
+
+        page.handle_key(vec![UserEvent::ObjectDetailDown], key_event);
+        page.handle_key(vec![UserEvent::ObjectDetailDiff], key_event);
+        let Ok(AppEventType::OpenDiff(spec)) = rx.try_recv() else {
+            panic!("OpenDiff is not sent");
+        };
+        assert_eq!(
+            spec.old.version_id.as_deref(),
+            Some("1c5d3bcc-2bb3-4cd5-875f-a95a6ae53f65")
+        );
+        assert_eq!(
+            spec.new.version_id.as_deref(),
+            Some("60f36bc2-0f38-47b8-9bf0-e24e334b86d5")
+        );
+        assert_eq!(page.diff_base, None);
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 20);
         let mut terminal = Terminal::new(backend)?;
@@ -0,0 +1,344 @@
+use std::rc::Rc;
+
+use ratatui::{crossterm::event::KeyEvent, layout::Rect, Frame};
+
+use crate::{
+    app::AppContext,
+    event::{AppEventType, DiffSpec, Sender},
+    handle_user_events,
+    help::{
+        build_help_spans, build_short_help_spans, BuildHelpsItem, BuildShortHelpsItem, Spans,
+        SpansWithPriority,
+    },
+    keys::{UserEvent, UserEventMapper},
+    object::RawObject,
+    widget::{
+        decode_text, guess_encoding, DiffPreview, DiffPreviewState, EncodingDialog,
+        EncodingDialogState, EncodingType,
+    },
+};
+
+#[derive(Debug)]
+pub struct ObjectDiffPage {
+    state: DiffPreviewState,
+    view_state: ViewState,
+
+    spec: DiffSpec,
+    old_object: RawObject,
+    new_object: RawObject,
+    encoding: EncodingType,
+    encoding_dialog_state: EncodingDialogState,
+
+    ctx: Rc<AppContext>,
+    tx: Sender,
+}
+
+#[derive(Debug)]
+enum ViewState {
+    Default,
+    EncodingDialog,
+}
+
+impl ObjectDiffPage {
+    pub fn new(
+        spec: DiffSpec,
+        old_object: RawObject,
+        new_object: RawObject,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        let mut encoding_dialog_state = EncodingDialogState::new(&ctx.config.preview.encodings);
+        if ctx.config.preview.auto_detect_encoding {
+            if let Some(encoding) = guess_encoding(&new_object.bytes) {
+                encoding_dialog_state.add_guessed_encoding(encoding);
+            }
+        }
+        let encoding = encoding_dialog_state.selected();
+
+        let state = DiffPreviewState::new(
+            &decode_text(&old_object.bytes, encoding),
+            &decode_text(&new_object.bytes, encoding),
+            false,
+        );
+
+        Self {
+            state,
+            view_state: ViewState::Default,
+            spec,
+            old_object,
+            new_object,
+            encoding,
+            encoding_dialog_state,
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, user_events: Vec<UserEvent>, _key_event: KeyEvent) {
+        match self.view_state {
+            ViewState::Default => {
+                handle_user_events! { user_events =>
+                    UserEvent::ObjectDiffBack => {
+                        self.tx.send(AppEventType::CloseCurrentPage);
+                    }
+                    UserEvent::ObjectDiffDown => {
+                        self.state.scroll_forward();
+                    }
+                    UserEvent::ObjectDiffUp => {
+                        self.state.scroll_backward();
+                    }
+                    UserEvent::ObjectDiffPageDown => {
+                        self.state.scroll_page_forward();
+                    }
+                    UserEvent::ObjectDiffPageUp => {
+                        self.state.scroll_page_backward();
+                    }
+                    UserEvent::ObjectDiffGoToTop => {
+                        self.state.scroll_to_top();
+                    }
+                    UserEvent::ObjectDiffGoToBottom => {
+                        self.state.scroll_to_end();
+                    }
+                    UserEvent::ObjectDiffRight => {
+                        self.state.scroll_right();
+                    }
+                    UserEvent::ObjectDiffLeft => {
+                        self.state.scroll_left();
+                    }
+                    UserEvent::ObjectDiffNextHunk => {
+                        self.state.next_hunk();
+                    }
+                    UserEvent::ObjectDiffPrevHunk => {
+                        self.state.prev_hunk();
+                    }
+                    UserEvent::ObjectDiffToggleView => {
+                        self.state.toggle_side_by_side();
+                    }
+                    UserEvent::ObjectDiffEncoding => {
+                        self.open_encoding_dialog();
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
+                }
+            }
+            ViewState::EncodingDialog => {
+                handle_user_events! { user_events =>
+                    UserEvent::SelectDialogClose => {
+                        self.close_encoding_dialog();
+                    }
+                    UserEvent::SelectDialogDown => {
+                        self.encoding_dialog_state.select_next();
+                    }
+                    UserEvent::SelectDialogUp => {
+                        self.encoding_dialog_state.select_prev();
+                    }
+                    UserEvent::SelectDialogSelect => {
+                        self.apply_encoding();
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn key_section(&self) -> &'static str {
+        match self.view_state {
+            ViewState::Default => "object_diff",
+            ViewState::EncodingDialog => "select_dialog",
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let old_label = self.spec.old.label();
+        let new_label = self.spec.new.label();
+        let preview = DiffPreview::new(&old_label, &new_label, &self.ctx.theme);
+        f.render_stateful_widget(preview, area, &mut self.state);
+
+        if let ViewState::EncodingDialog = self.view_state {
+            let encoding_dialog =
+                EncodingDialog::new(&self.encoding_dialog_state).theme(&self.ctx.theme);
+            f.render_widget(encoding_dialog, area);
+        }
+    }
+
+    pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        #[rustfmt::skip]
+        let helps = match self.view_state {
+            ViewState::Default => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffBack, "Close diff"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffDown, "Scroll forward"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffUp, "Scroll backward"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffPageDown, "Scroll page forward"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffPageUp, "Scroll page backward"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffGoToTop, "Scroll to top"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffGoToBottom, "Scroll to end"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffRight, "Scroll right"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffLeft, "Scroll left"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffNextHunk, "Go to next hunk"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffPrevHunk, "Go to previous hunk"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffToggleView, "Toggle unified/side-by-side view"),
+                    BuildHelpsItem::new(UserEvent::ObjectDiffEncoding, "Open encoding dialog"),
+                ]
+            },
+            ViewState::EncodingDialog => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogClose, "Close encoding dialog"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogDown, "Select next item"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogUp, "Select previous item"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogSelect, "Reopen with encoding"),
+                ]
+            },
+        };
+        helps
+    }
+
+    pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = match self.view_state {
+            ViewState::Default => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+                    BuildShortHelpsItem::single(UserEvent::ObjectDiffBack, "Close", 1),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectDiffDown, UserEvent::ObjectDiffUp], "Scroll", 2),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectDiffNextHunk, UserEvent::ObjectDiffPrevHunk], "Next/Prev hunk", 3),
+                    BuildShortHelpsItem::single(UserEvent::ObjectDiffToggleView, "Toggle view", 4),
+                    BuildShortHelpsItem::single(UserEvent::ObjectDiffEncoding, "Encoding", 5),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectDiffGoToTop, UserEvent::ObjectDiffGoToBottom], "Top/End", 6),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            ViewState::EncodingDialog => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::SelectDialogClose, "Close", 2),
+                    BuildShortHelpsItem::group(vec![UserEvent::SelectDialogDown, UserEvent::SelectDialogUp], "Select", 3),
+                    BuildShortHelpsItem::single(UserEvent::SelectDialogSelect, "Encode", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+        };
+        build_short_help_spans(helps, mapper)
+    }
+}
+
+impl ObjectDiffPage {
+    fn open_encoding_dialog(&mut self) {
+        self.view_state = ViewState::EncodingDialog;
+    }
+
+    fn close_encoding_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.encoding_dialog_state.reset();
+    }
+
+    fn apply_encoding(&mut self) {
+        self.encoding = self.encoding_dialog_state.selected();
+        self.state.set_texts(
+            &decode_text(&self.old_object.bytes, self.encoding),
+            &decode_text(&self.new_object.bytes, self.encoding),
+        );
+        self.close_encoding_dialog();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::DiffTarget, object::ObjectKey, set_cells};
+
+    use super::*;
+    use ratatui::{
+        backend::TestBackend,
+        buffer::Buffer,
+        crossterm::event::{KeyCode, KeyModifiers},
+        style::Color,
+        Terminal,
+    };
+
+    #[tokio::test]
+    async fn test_render_version_diff() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let tx = sender();
+        let mut terminal = Terminal::new(TestBackend::new(60, 7))?;
+
+        let old = object("foo\nbar\nbaz\n");
+        let new = object("foo\nBAR\nbaz\n");
+        let mut page = ObjectDiffPage::new(spec(), old, new, ctx, tx);
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 60, 7);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Diff [file.txt (Version ID: v1)] → [file.txt (Version ID: ┐",
+            "│ @@ -1,3 +1,3 @@                                          │",
+            "│ 1 1  foo                                                 │",
+            "│ 2   -bar                                                 │",
+            "│   2 +BAR                                                 │",
+            "│ 3 3  baz                                                 │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..17, [1]) => fg: Color::Cyan,
+            (2..6, [2, 3, 4, 5]) => fg: Color::DarkGray,
+            (6..10, [3]) => fg: Color::Red,
+            (6..10, [4]) => fg: Color::Green,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        page.handle_key(vec![UserEvent::ObjectDiffEncoding], key_event());
+        assert!(matches!(page.view_state, ViewState::EncodingDialog));
+        page.handle_key(vec![UserEvent::SelectDialogSelect], key_event());
+        assert!(matches!(page.view_state, ViewState::Default));
+
+        Ok(())
+    }
+
+    fn spec() -> DiffSpec {
+        let target = |version_id: &str| DiffTarget {
+            object_key: ObjectKey {
+                bucket_name: "bucket".into(),
+                object_path: vec!["file.txt".into()],
+            },
+            name: "file.txt".into(),
+            size_byte: 0,
+            version_id: Some(version_id.into()),
+        };
+        DiffSpec {
+            old: target("v1"),
+            new: target("v2"),
+        }
+    }
+
+    fn object(s: &str) -> RawObject {
+        RawObject {
+            bytes: s.as_bytes().to_vec(),
+        }
+    }
+
+    fn key_event() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty())
+    }
+
+    fn sender() -> Sender {
+        let (tx, _) = tokio::sync::mpsc::unbounded_channel();
+        Sender::new(tx)
+    }
+}
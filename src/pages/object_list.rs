@@ -14,8 +14,9 @@ use ratatui::{
 use crate::{
     app::AppContext,
     color::ColorTheme,
+    command::parse_go_to_path,
     config::UiConfig,
-    event::{AppEventType, Sender},
+    event::{AppEventType, PasteMode, Sender},
     format::{format_datetime, format_size_byte},
     handle_user_events, handle_user_events_with_default,
     help::{
@@ -24,10 +25,11 @@ use crate::{
     },
     keys::{UserEvent, UserEventMapper},
     object::{DownloadObjectInfo, ObjectItem, ObjectKey},
+    search::ListFilter,
     widget::{
         ConfirmDialog, ConfirmDialogState, CopyDetailDialog, CopyDetailDialogState, InputDialog,
         InputDialogState, ObjectListSortDialog, ObjectListSortDialogState, ObjectListSortType,
-        ScrollList, ScrollListState,
+        ScrollList, ScrollListState, SuggestionList, SuggestionListState,
     },
 };
 
@@ -54,7 +56,8 @@ enum ViewState {
     Default,
     FilterDialog,
     SortDialog,
-    GoToPathDialog(InputDialogState),
+    GoToPathDialog(InputDialogState, SuggestionListState),
+    AddBookmarkDialog(InputDialogState),
     CopyDetailDialog(Box<CopyDetailDialogState>),
     DownloadConfirmDialog(Vec<DownloadObjectInfo>, ConfirmDialogState, bool),
     SaveDialog(InputDialogState, Option<Vec<DownloadObjectInfo>>),
@@ -120,6 +123,9 @@ impl ObjectListPage {
                     UserEvent::ObjectListManagementConsole if self.non_empty() => {
                         self.open_management_console();
                     }
+                    UserEvent::ObjectListDiff if self.non_empty() => {
+                        self.start_diff();
+                    }
                     UserEvent::ObjectListFilter => {
                         self.open_filter_dialog();
                     }
@@ -129,6 +135,37 @@ impl ObjectListPage {
                     UserEvent::ObjectListGoToPath => {
                         self.open_go_to_path_dialog();
                     }
+                    UserEvent::ObjectListBookmarks => {
+                        self.tx.send(AppEventType::OpenBookmarks);
+                    }
+                    UserEvent::ObjectListAddBookmark => {
+                        self.open_add_bookmark_dialog();
+                    }
+                    UserEvent::ObjectListRecentLocations => {
+                        self.tx.send(AppEventType::OpenRecentLocations);
+                    }
+                    UserEvent::ObjectListHistoryBack => {
+                        self.tx.send(AppEventType::HistoryBack);
+                    }
+                    UserEvent::ObjectListHistoryForward => {
+                        self.tx.send(AppEventType::HistoryForward);
+                    }
+                    UserEvent::ObjectListDualPane => {
+                        self.tx.send(AppEventType::ToggleDualPane);
+                    }
+                    UserEvent::ObjectListSwitchPane => {
+                        self.tx.send(AppEventType::SwitchPane);
+                    }
+                    UserEvent::ObjectListCopyToOtherPane if self.non_empty() => {
+                        let object_key = self.current_selected_object_key();
+                        let object_item = self.current_selected_item().to_owned();
+                        self.tx.send(AppEventType::StartPasteToOtherPane(object_key, object_item, PasteMode::Copy));
+                    }
+                    UserEvent::ObjectListMoveToOtherPane if self.non_empty() => {
+                        let object_key = self.current_selected_object_key();
+                        let object_item = self.current_selected_item().to_owned();
+                        self.tx.send(AppEventType::StartPasteToOtherPane(object_key, object_item, PasteMode::Move));
+                    }
                     UserEvent::ObjectListCopyObject if self.non_empty() => {
                         let object_key = self.current_selected_object_key();
                         let object_item = self.current_selected_item().to_owned();
@@ -150,28 +187,73 @@ impl ObjectListPage {
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
                     UserEvent::ObjectListResetFilter => {
                         self.reset_filter();
                     }
                 }
             }
-            ViewState::GoToPathDialog(ref mut state) => {
+            ViewState::GoToPathDialog(ref mut state, ref mut suggestion_state) => {
                 handle_user_events_with_default! { user_events =>
                     UserEvent::InputDialogClose => {
                         self.view_state = ViewState::Default;
                     }
                     UserEvent::InputDialogApply => {
-                        let mut input = state.input().trim().to_string();
-                        if !input.is_empty() && !input.ends_with('/') {
-                            input.push('/');
+                        let input = suggestion_state.selected_item().unwrap_or(state.input()).trim();
+                        match parse_go_to_path(input, &self.object_key.bucket_name) {
+                            Ok(object_key) => {
+                                self.tx.send(AppEventType::GoToPath(object_key));
+                                self.view_state = ViewState::Default;
+                            }
+                            Err(msg) => {
+                                self.tx.send(AppEventType::NotifyWarn(msg));
+                            }
+                        }
+                    }
+                    UserEvent::InputDialogComplete => {
+                        if let Some(completion) = suggestion_state.completion().map(String::from) {
+                            *state = InputDialogState::new(completion.clone());
+                            suggestion_state.clear();
+                            self.tx.send(AppEventType::LoadGoToPathSuggestions(completion));
                         }
-                        let object_key = ObjectKey::with_prefix(
-                            self.object_key.bucket_name.clone(),
-                            input,
-                        );
-                        self.tx.send(AppEventType::GoToPath(object_key));
+                    }
+                    UserEvent::InputDialogDown => {
+                        suggestion_state.select_next();
+                    }
+                    UserEvent::InputDialogUp => {
+                        suggestion_state.select_prev();
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    => {
+                        let prev_input = state.input().to_string();
+                        state.handle_key_event(key_event);
+                        if state.input() != prev_input {
+                            suggestion_state.clear();
+                            self.tx.send(AppEventType::LoadGoToPathSuggestions(state.input().to_string()));
+                        }
+                    }
+                }
+            }
+            ViewState::AddBookmarkDialog(ref mut state) => {
+                handle_user_events_with_default! { user_events =>
+                    UserEvent::InputDialogClose => {
                         self.view_state = ViewState::Default;
                     }
+                    UserEvent::InputDialogApply => {
+                        let name = state.input().trim().to_string();
+                        if !name.is_empty() {
+                            let object_key = self.current_dir_object_key().clone();
+                            self.tx.send(AppEventType::AddBookmark(name, object_key));
+                            self.view_state = ViewState::Default;
+                        }
+                    }
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
@@ -288,6 +370,20 @@ impl ObjectListPage {
         }
     }
 
+    pub fn key_section(&self) -> &'static str {
+        match self.view_state {
+            ViewState::Default => "object_list",
+            ViewState::FilterDialog
+            | ViewState::GoToPathDialog(..)
+            | ViewState::AddBookmarkDialog(_)
+            | ViewState::SaveDialog(..) => "input_dialog",
+            ViewState::SortDialog
+            | ViewState::CopyDetailDialog(_)
+            | ViewState::DownloadConfirmDialog(..)
+            | ViewState::PasteConfirmDialog(..) => "select_dialog",
+        }
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
@@ -295,7 +391,7 @@ impl ObjectListPage {
         let list_items = build_list_items(
             &self.object_items,
             &self.view_indices,
-            self.filter_input_state.input(),
+            &ListFilter::new(self.filter_input_state.input()),
             offset,
             selected,
             area,
@@ -317,13 +413,27 @@ impl ObjectListPage {
             f.set_cursor_position((cursor_x, cursor_y));
         }
 
-        if let ViewState::GoToPathDialog(state) = &mut self.view_state {
+        if let ViewState::GoToPathDialog(state, suggestion_state) = &mut self.view_state {
             let dialog = InputDialog::default()
                 .title("Go To Path")
                 .max_width(50)
                 .theme(&self.ctx.theme);
             f.render_stateful_widget(dialog, area, state);
 
+            let suggestion_list = SuggestionList::new(state.dialog_area()).theme(&self.ctx.theme);
+            f.render_stateful_widget(suggestion_list, area, suggestion_state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+
+        if let ViewState::AddBookmarkDialog(state) = &mut self.view_state {
+            let dialog = InputDialog::default()
+                .title("Bookmark Name")
+                .max_width(40)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(dialog, area, state);
+
             let (cursor_x, cursor_y) = state.cursor();
             f.set_cursor_position((cursor_x, cursor_y));
         }
@@ -364,6 +474,10 @@ impl ObjectListPage {
     }
 
     pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
         #[rustfmt::skip]
         let helps = match self.view_state {
             ViewState::Default => {
@@ -381,11 +495,21 @@ impl ObjectListPage {
                         BuildHelpsItem::new(UserEvent::ObjectListBucketList, "Go back to bucket list"),
                         BuildHelpsItem::new(UserEvent::ObjectListFilter, "Filter object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListGoToPath, "Go to path"),
+                        BuildHelpsItem::new(UserEvent::ObjectListBookmarks, "Open bookmark list"),
+                        BuildHelpsItem::new(UserEvent::ObjectListAddBookmark, "Bookmark current dir"),
+                        BuildHelpsItem::new(UserEvent::ObjectListRecentLocations, "Open recent locations"),
+                        BuildHelpsItem::new(UserEvent::ObjectListHistoryBack, "Go back in history"),
+                        BuildHelpsItem::new(UserEvent::ObjectListHistoryForward, "Go forward in history"),
+                        BuildHelpsItem::new(UserEvent::ObjectListDualPane, "Toggle dual pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListSwitchPane, "Switch pane"),
                         BuildHelpsItem::new(UserEvent::ObjectListDownloadObject, "Download object"),
                         BuildHelpsItem::new(UserEvent::ObjectListDownloadObjectAs, "Download object as"),
                         BuildHelpsItem::new(UserEvent::ObjectListSort, "Sort object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListCopyObject, "Copy selection"),
                         BuildHelpsItem::new(UserEvent::ObjectListPasteObject, "Paste to current dir"),
+                        BuildHelpsItem::new(UserEvent::ObjectListCopyToOtherPane, "Copy selection to other pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListMoveToOtherPane, "Move selection to other pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListDiff, "Compare with copied object"),
                         BuildHelpsItem::new(UserEvent::ObjectListCopyDetails, "Open copy dialog"),
                         BuildHelpsItem::new(UserEvent::ObjectListRefresh, "Refresh object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListManagementConsole, "Open management console in browser"),
@@ -405,22 +529,42 @@ impl ObjectListPage {
                         BuildHelpsItem::new(UserEvent::ObjectListBucketList, "Go back to bucket list"),
                         BuildHelpsItem::new(UserEvent::ObjectListFilter, "Filter object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListGoToPath, "Go to path"),
+                        BuildHelpsItem::new(UserEvent::ObjectListBookmarks, "Open bookmark list"),
+                        BuildHelpsItem::new(UserEvent::ObjectListAddBookmark, "Bookmark current dir"),
+                        BuildHelpsItem::new(UserEvent::ObjectListRecentLocations, "Open recent locations"),
+                        BuildHelpsItem::new(UserEvent::ObjectListHistoryBack, "Go back in history"),
+                        BuildHelpsItem::new(UserEvent::ObjectListHistoryForward, "Go forward in history"),
+                        BuildHelpsItem::new(UserEvent::ObjectListDualPane, "Toggle dual pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListSwitchPane, "Switch pane"),
                         BuildHelpsItem::new(UserEvent::ObjectListDownloadObject, "Download object"),
                         BuildHelpsItem::new(UserEvent::ObjectListDownloadObjectAs, "Download object as"),
                         BuildHelpsItem::new(UserEvent::ObjectListSort, "Sort object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListCopyObject, "Copy selection"),
                         BuildHelpsItem::new(UserEvent::ObjectListPasteObject, "Paste to current dir"),
+                        BuildHelpsItem::new(UserEvent::ObjectListCopyToOtherPane, "Copy selection to other pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListMoveToOtherPane, "Move selection to other pane"),
+                        BuildHelpsItem::new(UserEvent::ObjectListDiff, "Compare with copied object"),
                         BuildHelpsItem::new(UserEvent::ObjectListCopyDetails, "Open copy dialog"),
                         BuildHelpsItem::new(UserEvent::ObjectListRefresh, "Refresh object list"),
                         BuildHelpsItem::new(UserEvent::ObjectListManagementConsole, "Open management console in browser"),
                     ]
                 }
             },
-            ViewState::GoToPathDialog(_) => {
+            ViewState::GoToPathDialog(_, _) => {
                 vec![
                     BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
                     BuildHelpsItem::new(UserEvent::InputDialogClose, "Close go to path"),
                     BuildHelpsItem::new(UserEvent::InputDialogApply, "Go to path"),
+                    BuildHelpsItem::new(UserEvent::InputDialogComplete, "Complete path"),
+                    BuildHelpsItem::new(UserEvent::InputDialogDown, "Select next suggestion"),
+                    BuildHelpsItem::new(UserEvent::InputDialogUp, "Select previous suggestion"),
+                ]
+            },
+            ViewState::AddBookmarkDialog(_) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close bookmark dialog"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Add bookmark"),
                 ]
             },
             ViewState::FilterDialog => {
@@ -474,7 +618,7 @@ impl ObjectListPage {
                 ]
             }
         };
-        build_help_spans(helps, mapper, self.ctx.theme.help_key_fg)
+        helps
     }
 
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
@@ -513,10 +657,19 @@ impl ObjectListPage {
                     ]
                 }
             },
-            ViewState::GoToPathDialog(_) => {
+            ViewState::GoToPathDialog(_, _) => {
                 vec![
                     BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Close", 2),
                     BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Go", 1),
+                    BuildShortHelpsItem::single(UserEvent::InputDialogComplete, "Complete", 3),
+                    BuildShortHelpsItem::group(vec![UserEvent::InputDialogDown, UserEvent::InputDialogUp], "Select", 4),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            }
+            ViewState::AddBookmarkDialog(_) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Close", 2),
+                    BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Add", 1),
                     BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
                 ]
             }
@@ -618,7 +771,24 @@ impl ObjectListPage {
 
     fn open_go_to_path_dialog(&mut self) {
         let prefix = self.current_dir_object_key().joined_object_path(false);
-        self.view_state = ViewState::GoToPathDialog(InputDialogState::new(prefix));
+        let state = InputDialogState::new(prefix.clone());
+        self.view_state = ViewState::GoToPathDialog(state, SuggestionListState::default());
+        self.tx.send(AppEventType::LoadGoToPathSuggestions(prefix));
+    }
+
+    pub fn set_go_to_path_suggestions(&mut self, input: &str, suggestions: Vec<String>) {
+        if let ViewState::GoToPathDialog(state, suggestion_state) = &mut self.view_state {
+            // the suggestions for the outdated input are discarded
+            if state.input() == input {
+                suggestion_state.set_items(suggestions);
+            }
+        }
+    }
+
+    fn open_add_bookmark_dialog(&mut self) {
+        let key = self.current_dir_object_key();
+        let name = key.object_path.last().unwrap_or(&key.bucket_name).clone();
+        self.view_state = ViewState::AddBookmarkDialog(InputDialogState::new(name));
     }
 
     fn open_copy_detail_dialog(&mut self) {
@@ -647,12 +817,12 @@ impl ObjectListPage {
     }
 
     fn filter_view_indices(&mut self) {
-        let filter = self.filter_input_state.input();
+        let filter = ListFilter::new(self.filter_input_state.input());
         self.view_indices = self
             .object_items
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.name().contains(filter))
+            .filter(|(_, item)| filter.find(item.name()).is_some())
             .map(|(idx, _)| idx)
             .collect();
         // reset list state
@@ -661,6 +831,18 @@ impl ObjectListPage {
         self.sort_view_indices();
     }
 
+    pub fn filter_by(&mut self, filter: &str) {
+        self.filter_input_state = InputDialogState::new(filter.into());
+
+        self.filter_view_indices();
+    }
+
+    pub fn sort_by(&mut self, sort_type: ObjectListSortType) {
+        self.sort_dialog_state.select(sort_type);
+
+        self.sort_view_indices();
+    }
+
     fn apply_sort(&mut self) {
         self.view_state = ViewState::Default;
 
@@ -834,6 +1016,13 @@ impl ObjectListPage {
             .send(AppEventType::ObjectListOpenManagementConsole(object_key));
     }
 
+    fn start_diff(&self) {
+        let object_key = self.current_selected_object_key();
+        let item = self.current_selected_item().clone();
+        self.tx
+            .send(AppEventType::StartDiffWithClipboard(object_key, item));
+    }
+
     fn paste(&mut self) {
         if let ViewState::PasteConfirmDialog(spec, state) = &mut self.view_state {
             if state.is_ok() {
@@ -864,6 +1053,22 @@ impl ObjectListPage {
         })
     }
 
+    /// Selects the item with the name, returns false if it is not found.
+    pub fn select_item(&mut self, name: &str) -> bool {
+        let position = self
+            .view_indices
+            .iter()
+            .position(|&i| self.object_items[i].name() == name);
+        match position {
+            Some(i) => {
+                self.list_state.selected = i;
+                self.list_state.offset = i;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn current_dir_object_key(&self) -> &ObjectKey {
         // not include current selected item
         &self.object_key
@@ -899,7 +1104,7 @@ impl ObjectListPage {
 fn build_list_items<'a>(
     current_items: &'a [ObjectItem],
     view_indices: &'a [usize],
-    filter: &'a str,
+    filter: &ListFilter,
     offset: usize,
     selected: usize,
     area: Rect,
@@ -929,7 +1134,7 @@ fn build_list_items<'a>(
 fn build_list_item<'a>(
     item: &'a ObjectItem,
     selected: bool,
-    filter: &'a str,
+    filter: &ListFilter,
     area: Rect,
     ui_config: &UiConfig,
     theme: &ColorTheme,
@@ -964,28 +1169,28 @@ fn build_list_item<'a>(
 
 fn build_object_dir_line<'a>(
     name: &'a str,
-    filter: &'a str,
+    filter: &ListFilter,
     width: u16,
     theme: &ColorTheme,
 ) -> Line<'a> {
+    let matched = filter.find(name).filter(|_| !filter.is_empty());
     let name = format!("{name}/");
     let name_w = (width as usize) - 2 /* spaces */ - 4 /* border + pad */ - 1 /* slash */;
     let pad_name =
         console::pad_str(&name, name_w, console::Alignment::Left, Some(ELLIPSIS)).to_string();
 
-    if filter.is_empty() {
-        Line::from(vec![" ".into(), pad_name.bold(), " ".into()])
-    } else {
-        let i = name.find(filter).unwrap();
+    if let Some(range) = matched {
         let mut spans = highlight_matched_text(pad_name)
             .ellipsis(ELLIPSIS)
-            .matched_range(i, i + filter.len())
+            .matched_range(range.start, range.end)
             .not_matched_style(Style::default().bold())
             .matched_style(Style::default().fg(theme.list_filter_match).bold())
             .into_spans();
         spans.insert(0, " ".into());
         spans.push(" ".into());
         Line::from(spans)
+    } else {
+        Line::from(vec![" ".into(), pad_name.bold(), " ".into()])
     }
 }
 
@@ -993,7 +1198,7 @@ fn build_object_file_line<'a>(
     name: &'a str,
     size_byte: usize,
     last_modified: &'a DateTime<Local>,
-    filter: &'a str,
+    filter: &ListFilter,
     width: u16,
     ui_config: &UiConfig,
     theme: &ColorTheme,
@@ -1009,21 +1214,11 @@ fn build_object_file_line<'a>(
     let pad_date = console::pad_str(&date, date_w, console::Alignment::Left, None).to_string();
     let pad_size = console::pad_str(&size, size_w, console::Alignment::Right, None).to_string();
 
-    if filter.is_empty() {
-        Line::from(vec![
-            " ".into(),
-            pad_name.into(),
-            "    ".into(),
-            pad_date.into(),
-            "    ".into(),
-            pad_size.into(),
-            " ".into(),
-        ])
-    } else {
-        let i = name.find(filter).unwrap();
+    let matched = filter.find(name).filter(|_| !filter.is_empty());
+    if let Some(range) = matched {
         let mut spans = highlight_matched_text(pad_name)
             .ellipsis(ELLIPSIS)
-            .matched_range(i, i + filter.len())
+            .matched_range(range.start, range.end)
             .not_matched_style(Style::default())
             .matched_style(Style::default().fg(theme.list_filter_match))
             .into_spans();
@@ -1034,6 +1229,16 @@ fn build_object_file_line<'a>(
         spans.push(pad_size.into());
         spans.push(" ".into());
         Line::from(spans)
+    } else {
+        Line::from(vec![
+            " ".into(),
+            pad_name.into(),
+            "    ".into(),
+            pad_date.into(),
+            "    ".into(),
+            pad_size.into(),
+            " ".into(),
+        ])
     }
 }
 
@@ -1066,9 +1271,11 @@ fn build_paste_confirm_message_lines<'a>(
     let to = format!("s3://{}/{}", spec.dst_bucket, spec.dst_key);
 
     let mut lines: Vec<Line<'a>> = Vec::new();
-    lines.push(Line::from(
-        "You are about to copy the following object:".fg(theme.fg),
-    ));
+    let msg = format!(
+        "You are about to {} the following object:",
+        spec.mode.name().to_lowercase()
+    );
+    lines.push(Line::from(msg.fg(theme.fg)));
     lines.push(Line::from(""));
 
     for l in wrap_s3_path_for_dialog(&from, CONFIRM_DIALOG_TEXT_WIDTH) {
@@ -1246,7 +1453,7 @@ mod tests {
     use ratatui::{
         backend::TestBackend,
         buffer::Buffer,
-        crossterm::event::KeyCode,
+        crossterm::event::{KeyCode, KeyModifiers},
         style::{Color, Modifier},
         Terminal,
     };
@@ -1551,6 +1758,88 @@ mod tests {
         assert_eq!(page.view_indices, vec![3, 1, 4, 0, 2]);
     }
 
+    #[tokio::test]
+    async fn test_go_to_path_dialog() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let items = vec![object_dir_item("dir")];
+        let object_key = ObjectKey::with_prefix("test-bucket", "path/".into());
+        let mut page = ObjectListPage::new(items, object_key, ctx, Sender::new(tx));
+
+        page.handle_key(
+            vec![UserEvent::ObjectListGoToPath],
+            KeyEvent::from(KeyCode::Char('g')),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::LoadGoToPathSuggestions(input)) if input == "path/"
+        ));
+
+        page.set_go_to_path_suggestions("path/", vec!["path/a/".into(), "path/b/".into()]);
+        page.handle_key(
+            vec![UserEvent::InputDialogDown],
+            KeyEvent::from(KeyCode::Down),
+        );
+        page.handle_key(
+            vec![UserEvent::InputDialogDown],
+            KeyEvent::from(KeyCode::Down),
+        );
+        page.handle_key(
+            vec![UserEvent::InputDialogComplete],
+            KeyEvent::from(KeyCode::Tab),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::LoadGoToPathSuggestions(input)) if input == "path/b/"
+        ));
+
+        page.handle_key(
+            vec![UserEvent::InputDialogApply],
+            KeyEvent::from(KeyCode::Enter),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::GoToPath(key)) if key == ObjectKey::with_prefix("test-bucket", "path/b/".into())
+        ));
+
+        // a location with the bucket
+        page.view_state = ViewState::GoToPathDialog(
+            InputDialogState::new("arn:aws:s3:::other/x".into()),
+            SuggestionListState::default(),
+        );
+        page.handle_key(
+            vec![UserEvent::InputDialogApply],
+            KeyEvent::from(KeyCode::Enter),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::GoToPath(key)) if key == ObjectKey::with_prefix("other", "x/".into())
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_command_palette_not_opened_from_input_dialog() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let items = vec![object_dir_item("dir")];
+        let object_key = ObjectKey::with_prefix("test-bucket", "path/".into());
+        let mut page = ObjectListPage::new(items, object_key, ctx, Sender::new(tx));
+        let ctrl_p = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+
+        page.handle_key(vec![UserEvent::CommandPalette], ctrl_p);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::OpenCommandPalette)
+        ));
+
+        page.handle_key(
+            vec![UserEvent::ObjectListFilter],
+            KeyEvent::from(KeyCode::Char('/')),
+        );
+        page.handle_key(vec![UserEvent::CommandPalette], ctrl_p);
+        assert!(rx.try_recv().is_err());
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -1,31 +1,62 @@
-use std::{rc::Rc, sync::Arc};
+use std::{
+    borrow::Cow,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
-use ratatui::{crossterm::event::KeyEvent, layout::Rect, Frame};
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
 
 use crate::{
     app::AppContext,
+    archive::{parse_tar, ArchiveEntry, ArchiveFormat, ArchiveSource, EntryLocation},
+    compression::{strip_compression_extension, Compression},
     environment::ImagePicker,
-    event::{AppEventType, Sender},
+    event::{AppEventType, FollowPreviewSpec, Sender},
+    format::format_size_byte,
     handle_user_events, handle_user_events_with_default,
     help::{
         build_help_spans, build_short_help_spans, BuildHelpsItem, BuildShortHelpsItem, Spans,
         SpansWithPriority,
     },
     keys::{UserEvent, UserEventMapper},
-    object::{FileDetail, RawObject},
+    object::{ByteRange, FileDetail, ObjectKey, RawObject},
+    table::{parse_table, TableData, TableFormat},
     widget::{
-        self, EncodingDialog, EncodingDialogState, ImagePreview, ImagePreviewState, InputDialog,
-        InputDialogState, TextPreview, TextPreviewState,
+        self, align_row, guess_encoding, is_binary, parse_byte_pattern, parse_offset,
+        ArchivePreview, ArchivePreviewState, EncodingDialog, EncodingDialogState, HexPreview,
+        HexPreviewState, ImagePreview, ImagePreviewState, InputDialog, InputDialogState,
+        TablePreview, TablePreviewState, TextPreview, TextPreviewState,
     },
 };
 
+static NEXT_FOLLOW_ID: AtomicUsize = AtomicUsize::new(0);
+
 #[derive(Debug)]
 pub struct ObjectPreviewPage {
     preview_type: PreviewType,
 
+    object_key: ObjectKey,
     file_detail: FileDetail,
     file_version_id: Option<String>,
     object: Arc<RawObject>,
+    // set only when a part of the object is loaded
+    loaded_range: Option<ByteRange>,
+    // id of the current follow session, set only while following
+    follow_id: Option<usize>,
+    // set only when the object is decompressed for preview
+    decompressed: Option<DecompressedInfo>,
+    // true if the object is an entry in an archive, not an object in the bucket
+    archive_entry: bool,
 
     view_state: ViewState,
     encoding_dialog_state: EncodingDialogState,
@@ -34,10 +65,21 @@ pub struct ObjectPreviewPage {
     tx: Sender,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct DecompressedInfo {
+    compression: Compression,
+    compressed_size_byte: usize,
+    decompressed_size_byte: usize,
+    truncated: bool,
+}
+
 #[derive(Debug)]
 enum PreviewType {
     Text(TextPreviewState),
     Image(ImagePreviewState),
+    Archive(ArchivePreviewState),
+    Table(TablePreviewState),
+    Hex(HexPreviewState),
 }
 
 #[derive(Debug, Default)]
@@ -46,46 +88,148 @@ enum ViewState {
     Default,
     SaveDialog(InputDialogState),
     EncodingDialog,
+    GoToDialog(InputDialogState),
+    SearchDialog(InputDialogState),
 }
 
 impl ObjectPreviewPage {
     pub fn new(
+        object_key: ObjectKey,
         file_detail: FileDetail,
         file_version_id: Option<String>,
         object: RawObject,
+        loaded_range: Option<ByteRange>,
         ctx: Rc<AppContext>,
         tx: Sender,
     ) -> Self {
         let mut encoding_dialog_state = EncodingDialogState::new(&ctx.config.preview.encodings);
 
-        let preview_type = if infer::is_image(&object.bytes) {
+        let (object, decompressed) = decompress_object(
+            &file_detail,
+            object,
+            ctx.config.preview.max_decompressed_size_byte,
+            &tx,
+        );
+
+        let archive_entries = parse_tar_object(&file_detail, &object, &tx);
+
+        let partial = loaded_range.is_some_and(|r| !r.is_entire(file_detail.size_byte))
+            || decompressed.is_some_and(|d| d.truncated);
+        let table = parse_table_object(
+            &file_detail,
+            &object,
+            partial,
+            ctx.config.preview.max_table_rows,
+            &tx,
+        );
+
+        let is_image = infer::is_image(&object.bytes);
+        if is_image && loaded_range.is_some() {
+            let msg = "Object is too large to preview as an image".to_string();
+            tx.send(AppEventType::NotifyWarn(msg));
+        }
+        let encoding = if ctx.config.preview.auto_detect_encoding {
+            guess_encoding(&object.bytes)
+        } else {
+            None
+        };
+        let is_binary = is_binary(
+            &object.bytes,
+            encoding.unwrap_or(encoding_dialog_state.selected()),
+        );
+
+        let object = Arc::new(object);
+        let preview_type = if let Some(entries) = archive_entries {
+            PreviewType::Archive(ArchivePreviewState::new(entries))
+        } else if let Some(data) = table {
+            PreviewType::Table(TablePreviewState::new(data))
+        } else if is_image && loaded_range.is_none() {
             let (state, msg) =
                 ImagePreviewState::new(&object.bytes, ctx.env.image_picker.clone().into());
             if let Some(msg) = msg {
                 tx.send(AppEventType::NotifyWarn(msg));
             }
             PreviewType::Image(state)
+        } else if is_image || is_binary {
+            PreviewType::Hex(new_hex_preview_state(
+                &file_detail,
+                &object,
+                loaded_range,
+                decompressed,
+            ))
         } else {
-            let (state, guessed_encoding, msg) = TextPreviewState::new(
+            PreviewType::Text(new_text_preview_state(
                 &file_detail,
                 &object,
-                ctx.config.preview.highlight,
-                &ctx.config.preview.highlight_theme,
-                ctx.config.preview.auto_detect_encoding,
-                encoding_dialog_state.selected(),
-            );
-            if let Some(msg) = msg {
-                tx.send(AppEventType::NotifyWarn(msg));
-            }
-            if let Some(guessed_encoding) = guessed_encoding {
-                encoding_dialog_state.add_guessed_encoding(guessed_encoding);
-            }
-            PreviewType::Text(state)
+                decompressed,
+                &ctx,
+                &mut encoding_dialog_state,
+                &tx,
+            ))
         };
 
         Self {
             preview_type,
-            object: Arc::new(object),
+            object_key,
+            object,
+            loaded_range,
+            follow_id: None,
+            decompressed,
+            archive_entry: false,
+            file_detail,
+            file_version_id,
+            view_state: ViewState::Default,
+            encoding_dialog_state,
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn new_archive(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+        entries: Vec<ArchiveEntry>,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        let encoding_dialog_state = EncodingDialogState::new(&ctx.config.preview.encodings);
+        Self {
+            preview_type: PreviewType::Archive(ArchivePreviewState::new(entries)),
+            object_key,
+            // the entries are loaded from the object when opened
+            object: Arc::new(RawObject { bytes: Vec::new() }),
+            loaded_range: None,
+            follow_id: None,
+            decompressed: None,
+            archive_entry: false,
+            file_detail,
+            file_version_id,
+            view_state: ViewState::Default,
+            encoding_dialog_state,
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn new_table(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+        data: TableData,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        let encoding_dialog_state = EncodingDialogState::new(&ctx.config.preview.encodings);
+        Self {
+            preview_type: PreviewType::Table(TablePreviewState::new(data)),
+            object_key,
+            // only the required parts of the object are loaded
+            object: Arc::new(RawObject { bytes: Vec::new() }),
+            loaded_range: None,
+            follow_id: None,
+            decompressed: None,
+            archive_entry: false,
             file_detail,
             file_version_id,
             view_state: ViewState::Default,
@@ -95,6 +239,18 @@ impl ObjectPreviewPage {
         }
     }
 
+    pub fn new_archive_entry(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        object: RawObject,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        let mut page = Self::new(object_key, file_detail, None, object, None, ctx, tx);
+        page.archive_entry = true;
+        page
+    }
+
     pub fn handle_key(&mut self, user_events: Vec<UserEvent>, key_event: KeyEvent) {
         match (&mut self.view_state, &mut self.preview_type) {
             (ViewState::Default, PreviewType::Text(state)) => {
@@ -141,9 +297,215 @@ impl ObjectPreviewPage {
                     UserEvent::ObjectPreviewEncoding => {
                         self.open_encoding_dialog();
                     }
+                    UserEvent::ObjectPreviewLoadNextChunk => {
+                        self.load_next_chunk();
+                    }
+                    UserEvent::ObjectPreviewLoadLastChunk => {
+                        self.load_last_chunk();
+                    }
+                    UserEvent::ObjectPreviewToggleFollow => {
+                        self.toggle_follow();
+                    }
+                    UserEvent::ObjectPreviewToggleFormat => {
+                        self.toggle_format();
+                    }
+                    UserEvent::ObjectPreviewToggleFold => {
+                        self.toggle_fold(false);
+                    }
+                    UserEvent::ObjectPreviewToggleFoldAll => {
+                        self.toggle_fold(true);
+                    }
+                    UserEvent::ObjectPreviewToggleHex => {
+                        self.toggle_hex();
+                    }
+                    UserEvent::ObjectPreviewSearch => {
+                        self.open_search_dialog();
+                    }
+                    UserEvent::ObjectPreviewSearchNext => {
+                        self.search_next(true);
+                    }
+                    UserEvent::ObjectPreviewSearchPrev => {
+                        self.search_next(false);
+                    }
+                    UserEvent::ObjectPreviewGoTo => {
+                        self.open_go_to_dialog();
+                    }
+                    UserEvent::ObjectPreviewSetMark => {
+                        self.set_mark();
+                    }
+                    UserEvent::ObjectPreviewJumpToMark => {
+                        self.jump_to_mark();
+                    }
+                    UserEvent::ObjectPreviewToggleSearchCase => {
+                        self.toggle_search_option(false);
+                    }
+                    UserEvent::ObjectPreviewToggleSearchRegex => {
+                        self.toggle_search_option(true);
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
+                }
+            }
+            (ViewState::Default, PreviewType::Archive(state)) => {
+                handle_user_events! { user_events =>
+                    UserEvent::ObjectPreviewBack => {
+                        self.tx.send(AppEventType::CloseCurrentPage);
+                    }
+                    UserEvent::ObjectPreviewDown => {
+                        state.list_state.select_next();
+                    }
+                    UserEvent::ObjectPreviewUp => {
+                        state.list_state.select_prev();
+                    }
+                    UserEvent::ObjectPreviewPageDown => {
+                        state.list_state.select_next_page();
+                    }
+                    UserEvent::ObjectPreviewPageUp => {
+                        state.list_state.select_prev_page();
+                    }
+                    UserEvent::ObjectPreviewGoToTop => {
+                        state.list_state.select_first();
+                    }
+                    UserEvent::ObjectPreviewGoToBottom => {
+                        state.list_state.select_last();
+                    }
+                    UserEvent::ObjectPreviewOpenEntry => {
+                        self.open_archive_entry();
+                    }
+                    UserEvent::ObjectPreviewToggleMark => {
+                        state.toggle_mark();
+                    }
+                    UserEvent::ObjectPreviewDownload => {
+                        self.extract_archive_entries();
+                    }
+                    UserEvent::ObjectPreviewDownloadAs => {
+                        self.open_save_dialog();
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
+                }
+            }
+            (ViewState::Default, PreviewType::Table(state)) => {
+                handle_user_events! { user_events =>
+                    UserEvent::ObjectPreviewBack => {
+                        self.tx.send(AppEventType::CloseCurrentPage);
+                    }
+                    UserEvent::ObjectPreviewDown => {
+                        state.current_view_mut().list_state.select_next();
+                    }
+                    UserEvent::ObjectPreviewUp => {
+                        state.current_view_mut().list_state.select_prev();
+                    }
+                    UserEvent::ObjectPreviewPageDown => {
+                        state.current_view_mut().list_state.select_next_page();
+                    }
+                    UserEvent::ObjectPreviewPageUp => {
+                        state.current_view_mut().list_state.select_prev_page();
+                    }
+                    UserEvent::ObjectPreviewGoToTop => {
+                        state.current_view_mut().list_state.select_first();
+                    }
+                    UserEvent::ObjectPreviewGoToBottom => {
+                        state.current_view_mut().list_state.select_last();
+                    }
+                    UserEvent::ObjectPreviewRight => {
+                        state.current_view_mut().scroll_right();
+                    }
+                    UserEvent::ObjectPreviewLeft => {
+                        state.current_view_mut().scroll_left();
+                    }
+                    UserEvent::ObjectPreviewToggleSchema => {
+                        state.toggle_schema();
+                    }
+                    UserEvent::ObjectPreviewDownload => {
+                        self.download();
+                    }
+                    UserEvent::ObjectPreviewDownloadAs => {
+                        self.open_save_dialog();
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
+                }
+            }
+            (ViewState::Default, PreviewType::Hex(state)) => {
+                handle_user_events! { user_events =>
+                    UserEvent::ObjectPreviewBack => {
+                        self.tx.send(AppEventType::CloseCurrentPage);
+                    }
+                    UserEvent::ObjectPreviewDown => {
+                        state.scroll_forward();
+                        self.load_adjacent_hex_range(true);
+                    }
+                    UserEvent::ObjectPreviewUp => {
+                        state.scroll_backward();
+                        self.load_adjacent_hex_range(false);
+                    }
+                    UserEvent::ObjectPreviewPageDown => {
+                        state.scroll_page_forward();
+                        self.load_adjacent_hex_range(true);
+                    }
+                    UserEvent::ObjectPreviewPageUp => {
+                        state.scroll_page_backward();
+                        self.load_adjacent_hex_range(false);
+                    }
+                    UserEvent::ObjectPreviewGoToTop => {
+                        self.go_to_offset(0);
+                    }
+                    UserEvent::ObjectPreviewGoToBottom => {
+                        let last = state.size().saturating_sub(1);
+                        self.go_to_offset(last);
+                    }
+                    UserEvent::ObjectPreviewGoTo => {
+                        self.open_go_to_dialog();
+                    }
+                    UserEvent::ObjectPreviewSearch => {
+                        self.open_search_dialog();
+                    }
+                    UserEvent::ObjectPreviewSearchNext => {
+                        self.search_next(true);
+                    }
+                    UserEvent::ObjectPreviewSearchPrev => {
+                        self.search_next(false);
+                    }
+                    UserEvent::ObjectPreviewToggleHex => {
+                        self.toggle_hex();
+                    }
+                    UserEvent::ObjectPreviewDownload => {
+                        self.download();
+                    }
+                    UserEvent::ObjectPreviewDownloadAs => {
+                        self.open_save_dialog();
+                    }
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
                 }
             }
             (ViewState::Default, PreviewType::Image(_)) => {
@@ -162,6 +524,12 @@ impl ObjectPreviewPage {
                     UserEvent::Help => {
                         self.tx.send(AppEventType::OpenHelp);
                     }
+                    UserEvent::CommandLine => {
+                        self.tx.send(AppEventType::OpenCommandLine);
+                    }
+                    UserEvent::CommandPalette => {
+                        self.tx.send(AppEventType::OpenCommandPalette);
+                    }
                 }
             }
             (ViewState::SaveDialog(state), _) => {
@@ -183,6 +551,47 @@ impl ObjectPreviewPage {
                     }
                 }
             }
+            (ViewState::GoToDialog(state), _) => {
+                handle_user_events_with_default! { user_events =>
+                    UserEvent::InputDialogClose => {
+                        self.close_input_dialog();
+                    }
+                    UserEvent::InputDialogApply => {
+                        let input = state.input().into();
+                        self.go_to(input);
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    => {
+                        state.handle_key_event(key_event);
+                    }
+                }
+            }
+            (ViewState::SearchDialog(state), _) => {
+                handle_user_events_with_default! { user_events =>
+                    UserEvent::InputDialogClose => {
+                        self.cancel_search();
+                    }
+                    UserEvent::InputDialogApply => {
+                        let input = state.input().into();
+                        self.search(input);
+                    }
+                    UserEvent::ObjectPreviewToggleSearchCase => {
+                        self.toggle_search_option(false);
+                    }
+                    UserEvent::ObjectPreviewToggleSearchRegex => {
+                        self.toggle_search_option(true);
+                    }
+                    UserEvent::Help => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    => {
+                        state.handle_key_event(key_event);
+                        self.update_incremental_search();
+                    }
+                }
+            }
             (ViewState::EncodingDialog, _) => {
                 handle_user_events! { user_events =>
                     UserEvent::SelectDialogClose => {
@@ -205,14 +614,44 @@ impl ObjectPreviewPage {
         }
     }
 
+    pub fn key_section(&self) -> &'static str {
+        match self.view_state {
+            ViewState::Default => "object_preview",
+            ViewState::SaveDialog(_) | ViewState::GoToDialog(_) | ViewState::SearchDialog(_) => {
+                "input_dialog"
+            }
+            ViewState::EncodingDialog => "select_dialog",
+        }
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let truncated_range = self.truncated_range();
+        let area = if truncated_range.is_some() || self.follow_id.is_some() {
+            let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+            let mut spans = Vec::new();
+            if let Some(range) = truncated_range {
+                let msg = build_truncated_message(range, self.file_detail.size_byte);
+                spans.push(msg.fg(self.ctx.theme.status_warn));
+            }
+            if self.follow_id.is_some() {
+                let interval = self.ctx.config.preview.follow_interval_secs;
+                let msg = build_follow_message(interval);
+                spans.push(msg.fg(self.ctx.theme.status_info));
+            }
+            f.render_widget(Paragraph::new(Line::from(spans)), chunks[0]);
+            chunks[1]
+        } else {
+            area
+        };
+
         match self.preview_type {
             PreviewType::Text(ref mut state) => {
                 let preview = TextPreview::new(
                     self.file_detail.name.as_str(),
                     self.file_version_id.as_deref(),
                     &self.ctx.theme,
-                );
+                )
+                .compression_info(self.decompressed.map(build_compression_info));
                 f.render_stateful_widget(preview, area, state);
             }
             PreviewType::Image(ref mut state) => {
@@ -222,14 +661,53 @@ impl ObjectPreviewPage {
                 );
                 f.render_stateful_widget(preview, area, state);
             }
+            PreviewType::Table(ref mut state) => {
+                let preview = TablePreview::new(
+                    self.file_detail.name.as_str(),
+                    self.file_version_id.as_deref(),
+                    &self.ctx.theme,
+                );
+                f.render_stateful_widget(preview, area, state);
+            }
+            PreviewType::Hex(ref mut state) => {
+                let preview = HexPreview::new(
+                    self.file_detail.name.as_str(),
+                    self.file_version_id.as_deref(),
+                    &self.ctx.theme,
+                );
+                f.render_stateful_widget(preview, area, state);
+            }
+            PreviewType::Archive(ref mut state) => {
+                let preview = ArchivePreview::new(
+                    self.file_detail.name.as_str(),
+                    self.file_version_id.as_deref(),
+                    &self.ctx.config.ui.object_list,
+                    &self.ctx.theme,
+                );
+                f.render_stateful_widget(preview, area, state);
+            }
         }
 
-        if let ViewState::SaveDialog(state) = &mut self.view_state {
-            let save_dialog = InputDialog::default()
-                .title("Save As")
+        let input_dialog = match &mut self.view_state {
+            ViewState::SaveDialog(state) => Some(("Save As", state)),
+            ViewState::GoToDialog(state) => {
+                let title = match self.preview_type {
+                    PreviewType::Text(_) => "Go to line",
+                    _ => "Go to offset",
+                };
+                Some((title, state))
+            }
+            ViewState::SearchDialog(state) => {
+                Some((search_dialog_title(&self.preview_type), state))
+            }
+            _ => None,
+        };
+        if let Some((title, state)) = input_dialog {
+            let input_dialog = InputDialog::default()
+                .title(title)
                 .max_width(40)
                 .theme(&self.ctx.theme);
-            f.render_stateful_widget(save_dialog, area, state);
+            f.render_stateful_widget(input_dialog, area, state);
 
             let (cursor_x, cursor_y) = state.cursor();
             f.set_cursor_position((cursor_x, cursor_y));
@@ -243,6 +721,10 @@ impl ObjectPreviewPage {
     }
 
     pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
         #[rustfmt::skip]
         let helps = match (&self.view_state, &self.preview_type) {
             (ViewState::Default, PreviewType::Text(_)) => {
@@ -262,34 +744,131 @@ impl ObjectPreviewPage {
                     BuildHelpsItem::new(UserEvent::ObjectPreviewDownload, "Download object"),
                     BuildHelpsItem::new(UserEvent::ObjectPreviewDownloadAs, "Download object as"),
                     BuildHelpsItem::new(UserEvent::ObjectPreviewEncoding, "Open encoding dialog"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewLoadNextChunk, "Load next chunk"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewLoadLastChunk, "Load last chunk"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleFollow, "Toggle follow mode"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleFormat, "Toggle formatting"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleFold, "Toggle fold of JSON node"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleFoldAll, "Toggle fold of all JSON nodes"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleHex, "Toggle hex view"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoTo, "Go to line"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSetMark, "Set mark"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewJumpToMark, "Jump to mark"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearch, "Search text"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearchNext, "Go to next match"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearchPrev, "Go to previous match"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleSearchCase, "Toggle case sensitive search"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleSearchRegex, "Toggle regex search"),
                 ]
             },
-            (ViewState::Default, PreviewType::Image(_)) => {
+            (ViewState::Default, PreviewType::Hex(_)) => {
                 vec![
                     BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDown, "Scroll forward"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewUp, "Scroll backward"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageDown, "Scroll page forward"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageUp, "Scroll page backward"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToTop, "Scroll to top"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToBottom, "Scroll to end"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoTo, "Go to offset"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearch, "Search bytes"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearchNext, "Go to next match"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewSearchPrev, "Go to previous match"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleHex, "Toggle hex view"),
                     BuildHelpsItem::new(UserEvent::ObjectPreviewBack, "Close preview"),
                     BuildHelpsItem::new(UserEvent::ObjectPreviewDownload, "Download object"),
                     BuildHelpsItem::new(UserEvent::ObjectPreviewDownloadAs, "Download object as"),
                 ]
             },
-            (ViewState::SaveDialog(_), _) => {
+            (ViewState::Default, PreviewType::Archive(_)) => {
                 vec![
                     BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
-                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close save dialog"),
-                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Download object"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDown, "Select next item"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewUp, "Select previous item"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageDown, "Select next page"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageUp, "Select previous page"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToTop, "Select first item"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToBottom, "Select last item"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewOpenEntry, "Open entry"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleMark, "Toggle mark"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewBack, "Close preview"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownload, "Extract marked entries"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownloadAs, "Download object as"),
                 ]
             },
-            (ViewState::EncodingDialog, _) => {
+            (ViewState::Default, PreviewType::Table(_)) => {
                 vec![
                     BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
-                    BuildHelpsItem::new(UserEvent::SelectDialogClose, "Close encoding dialog"),
-                    BuildHelpsItem::new(UserEvent::SelectDialogDown, "Select next item"),
-                    BuildHelpsItem::new(UserEvent::SelectDialogUp, "Select previous item"),
-                    BuildHelpsItem::new(UserEvent::SelectDialogSelect, "Reopen with encoding"),
-                ]
-            },
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDown, "Select next row"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewUp, "Select previous row"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageDown, "Select next page"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewPageUp, "Select previous page"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToTop, "Select first row"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewGoToBottom, "Select last row"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewRight, "Scroll right"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewLeft, "Scroll left"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleSchema, "Toggle schema view"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewBack, "Close preview"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownload, "Download object"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownloadAs, "Download object as"),
+                ]
+            },
+            (ViewState::Default, PreviewType::Image(_)) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewBack, "Close preview"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownload, "Download object"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewDownloadAs, "Download object as"),
+                ]
+            },
+            (ViewState::SaveDialog(_), _) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close save dialog"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Download object"),
+                ]
+            },
+            (ViewState::GoToDialog(_), PreviewType::Text(_)) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close go to dialog"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Go to line"),
+                ]
+            },
+            (ViewState::GoToDialog(_), _) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close go to dialog"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Go to offset"),
+                ]
+            },
+            (ViewState::SearchDialog(_), PreviewType::Text(_)) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Cancel search"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Apply search"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleSearchCase, "Toggle case sensitive search"),
+                    BuildHelpsItem::new(UserEvent::ObjectPreviewToggleSearchRegex, "Toggle regex search"),
+                ]
+            },
+            (ViewState::SearchDialog(_), _) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::InputDialogClose, "Close search dialog"),
+                    BuildHelpsItem::new(UserEvent::InputDialogApply, "Search next match"),
+                ]
+            },
+            (ViewState::EncodingDialog, _) => {
+                vec![
+                    BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogClose, "Close encoding dialog"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogDown, "Select next item"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogUp, "Select previous item"),
+                    BuildHelpsItem::new(UserEvent::SelectDialogSelect, "Reopen with encoding"),
+                ]
+            },
         };
-        build_help_spans(helps, mapper, self.ctx.theme.help_key_fg)
+        helps
     }
 
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
@@ -302,6 +881,45 @@ impl ObjectPreviewPage {
                     BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewGoToTop, UserEvent::ObjectPreviewGoToBottom], "Top/End", 5),
                     BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewDownload, UserEvent::ObjectPreviewDownloadAs], "Download", 3),
                     BuildShortHelpsItem::single(UserEvent::ObjectPreviewEncoding, "Encoding", 4),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewLoadNextChunk, UserEvent::ObjectPreviewLoadLastChunk], "Next/Last chunk", 6),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleFollow, "Follow", 7),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleFormat, "Format", 8),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewSearch, "Search", 9),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewBack, "Close", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::Default, PreviewType::Archive(_)) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewDown, UserEvent::ObjectPreviewUp], "Select", 3),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewOpenEntry, "Open", 2),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleMark, "Mark", 5),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewDownload, "Extract", 4),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewBack, "Close", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::Default, PreviewType::Table(_)) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewDown, UserEvent::ObjectPreviewUp], "Select", 3),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewLeft, UserEvent::ObjectPreviewRight], "Scroll", 4),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleSchema, "Schema", 2),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewDownload, "Download", 5),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewBack, "Close", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::Default, PreviewType::Hex(_)) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewDown, UserEvent::ObjectPreviewUp], "Scroll", 2),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewGoToTop, UserEvent::ObjectPreviewGoToBottom], "Top/End", 6),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewGoTo, "Go to", 3),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewSearch, "Search", 4),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleHex, "Text", 5),
+                    BuildShortHelpsItem::group(vec![UserEvent::ObjectPreviewDownload, UserEvent::ObjectPreviewDownloadAs], "Download", 7),
                     BuildShortHelpsItem::single(UserEvent::ObjectPreviewBack, "Close", 1),
                     BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
                 ]
@@ -321,14 +939,37 @@ impl ObjectPreviewPage {
                     BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
                 ]
             },
-             (ViewState::EncodingDialog, _) => {
+            (ViewState::GoToDialog(_), _) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Close", 2),
+                    BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Go to", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::SearchDialog(_), PreviewType::Text(_)) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Cancel", 2),
+                    BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Search", 1),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleSearchCase, "Case", 3),
+                    BuildShortHelpsItem::single(UserEvent::ObjectPreviewToggleSearchRegex, "Regex", 4),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::SearchDialog(_), _) => {
+                vec![
+                    BuildShortHelpsItem::single(UserEvent::InputDialogClose, "Close", 2),
+                    BuildShortHelpsItem::single(UserEvent::InputDialogApply, "Search", 1),
+                    BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+                ]
+            },
+            (ViewState::EncodingDialog, _) => {
                 vec![
                     BuildShortHelpsItem::single(UserEvent::SelectDialogClose, "Close", 2),
                     BuildShortHelpsItem::group(vec![UserEvent::SelectDialogDown, UserEvent::SelectDialogUp], "Select", 3),
                     BuildShortHelpsItem::single(UserEvent::SelectDialogSelect, "Encode", 1),
                     BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
                 ]
-             },
+            },
         };
         build_short_help_spans(helps, mapper)
     }
@@ -360,7 +1001,7 @@ impl ObjectPreviewPage {
             if let PreviewType::Text(state) = &mut self.preview_type {
                 state.set_encoding(self.encoding_dialog_state.selected());
                 state.update_lines(
-                    &self.file_detail,
+                    &highlight_file_detail(&self.file_detail, self.decompressed.is_some()),
                     &self.object,
                     self.ctx.config.preview.highlight,
                     &self.ctx.config.preview.highlight_theme,
@@ -370,6 +1011,278 @@ impl ObjectPreviewPage {
         self.close_encoding_dialog();
     }
 
+    fn toggle_format(&mut self) {
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            let msg = state.toggle_pretty(
+                &highlight_file_detail(&self.file_detail, self.decompressed.is_some()),
+                &self.object,
+                self.ctx.config.preview.highlight,
+                &self.ctx.config.preview.highlight_theme,
+            );
+            if let Some(msg) = msg {
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
+    fn toggle_fold(&mut self, all: bool) {
+        let PreviewType::Text(state) = &mut self.preview_type else {
+            return;
+        };
+        if !state.is_foldable() {
+            let msg = "Folding is supported only for formatted JSON".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if all {
+            state.toggle_fold_all();
+        } else {
+            state.toggle_fold();
+        }
+    }
+
+    fn toggle_hex(&mut self) {
+        let preview_type = match self.preview_type {
+            PreviewType::Text(_) => PreviewType::Hex(new_hex_preview_state(
+                &self.file_detail,
+                &self.object,
+                self.loaded_range,
+                self.decompressed,
+            )),
+            PreviewType::Hex(_) => PreviewType::Text(new_text_preview_state(
+                &self.file_detail,
+                &self.object,
+                self.decompressed,
+                &self.ctx,
+                &mut self.encoding_dialog_state,
+                &self.tx,
+            )),
+            _ => return,
+        };
+        self.preview_type = preview_type;
+    }
+
+    fn open_go_to_dialog(&mut self) {
+        if let PreviewType::Text(_) | PreviewType::Hex(_) = &self.preview_type {
+            self.view_state = ViewState::GoToDialog(InputDialogState::default());
+        }
+    }
+
+    fn open_search_dialog(&mut self) {
+        let input = match &mut self.preview_type {
+            PreviewType::Text(state) => {
+                state.start_search();
+                state.search_query().into()
+            }
+            PreviewType::Hex(state) => state.search_input().into(),
+            _ => return,
+        };
+        self.view_state = ViewState::SearchDialog(InputDialogState::new(input));
+    }
+
+    fn close_input_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn go_to(&mut self, input: String) {
+        self.close_input_dialog();
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            let input = input.trim();
+            if input.is_empty() {
+                return;
+            }
+            let line_count = state.line_count();
+            match input.parse::<usize>() {
+                Ok(n) if 0 < n && n <= line_count => {
+                    state.go_to_line(n - 1);
+                }
+                Ok(_) => {
+                    let msg = format!("Line is out of range (lines: {line_count})");
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                Err(_) => {
+                    let msg = format!("Invalid line number: {input}");
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+            }
+            return;
+        }
+        let PreviewType::Hex(state) = &self.preview_type else {
+            return;
+        };
+        if input.trim().is_empty() {
+            return;
+        }
+        match parse_offset(&input) {
+            Ok(offset) if offset < state.size() => {
+                self.go_to_offset(offset);
+            }
+            Ok(_) => {
+                let msg = format!("Offset is out of range (size: {} bytes)", state.size());
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyWarn(e.msg));
+            }
+        }
+    }
+
+    fn set_mark(&mut self) {
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            let line = state.set_mark();
+            let msg = format!("Mark set at line {}", line + 1);
+            self.tx.send(AppEventType::NotifyInfo(msg));
+        }
+    }
+
+    fn jump_to_mark(&mut self) {
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            if !state.jump_to_mark() {
+                let msg = "No mark is set".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
+    fn go_to_offset(&mut self, offset: usize) {
+        let size_byte = self.file_detail.size_byte;
+        let limit = self.ctx.config.preview.max_size_byte;
+        let PreviewType::Hex(state) = &mut self.preview_type else {
+            return;
+        };
+        if offset >= state.size() || state.jump_to(offset) {
+            return;
+        }
+        // the offset is not loaded yet, so load the bytes from the row that contains it
+        state.set_pending_jump(offset);
+        self.load_range(ByteRange::from_offset(align_row(offset), size_byte, limit));
+    }
+
+    // loads the bytes next to the loaded range when the view reaches its edge
+    fn load_adjacent_hex_range(&self, forward: bool) {
+        let PreviewType::Hex(state) = &self.preview_type else {
+            return;
+        };
+        let at_edge = if forward {
+            state.is_at_end()
+        } else {
+            state.is_at_top()
+        };
+        if !at_edge || self.decompressed.is_some() {
+            return;
+        }
+        let Some(range) = self.truncated_range() else {
+            return;
+        };
+        let size_byte = self.file_detail.size_byte;
+        let limit = self.ctx.config.preview.max_size_byte;
+        let adjacent = if forward {
+            range.next(size_byte, limit)
+        } else {
+            range.prev(limit)
+        };
+        if let Some(adjacent) = adjacent {
+            self.load_range(adjacent);
+        }
+    }
+
+    fn search(&mut self, input: String) {
+        self.close_input_dialog();
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            match state.search(&input) {
+                Ok(0) if !input.is_empty() => {
+                    let msg = "No matches found".to_string();
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.tx.send(AppEventType::NotifyWarn(e.msg));
+                }
+            }
+            return;
+        }
+        self.search_bytes(input.trim(), true);
+    }
+
+    fn cancel_search(&mut self) {
+        self.close_input_dialog();
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            state.cancel_search();
+        }
+    }
+
+    fn update_incremental_search(&mut self) {
+        let ViewState::SearchDialog(dialog_state) = &self.view_state else {
+            return;
+        };
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            // the query may be incomplete while typing, so errors are not notified
+            state.search(dialog_state.input()).ok();
+        }
+    }
+
+    fn toggle_search_option(&mut self, regex: bool) {
+        let PreviewType::Text(state) = &mut self.preview_type else {
+            return;
+        };
+        if regex {
+            state.toggle_search_regex();
+        } else {
+            state.toggle_search_case_sensitive();
+        }
+        if let ViewState::SearchDialog(dialog_state) = &self.view_state {
+            state.search(dialog_state.input()).ok();
+            return;
+        }
+        state.start_search();
+        let query = state.search_query().to_string();
+        if let Err(e) = state.search(&query) {
+            self.tx.send(AppEventType::NotifyWarn(e.msg));
+        }
+    }
+
+    fn search_next(&mut self, forward: bool) {
+        match &mut self.preview_type {
+            PreviewType::Text(state) => {
+                let found = state.search_next(forward);
+                if !found && !state.search_query().is_empty() {
+                    let msg = "No matches found".to_string();
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+            }
+            PreviewType::Hex(state) => {
+                let input = state.search_input().to_string();
+                self.search_bytes(&input, forward);
+            }
+            _ => {}
+        }
+    }
+
+    fn search_bytes(&mut self, input: &str, forward: bool) {
+        if input.is_empty() {
+            return;
+        }
+        let pattern = match parse_byte_pattern(input) {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyWarn(e.msg));
+                return;
+            }
+        };
+        let truncated = self.truncated_range().is_some();
+        let PreviewType::Hex(state) = &mut self.preview_type else {
+            return;
+        };
+        if state.find(input, &pattern, forward).is_none() {
+            let msg = if truncated {
+                "Pattern not found in the loaded bytes"
+            } else {
+                "Pattern not found"
+            };
+            self.tx.send(AppEventType::NotifyWarn(msg.into()));
+        }
+    }
+
     pub fn enable_image_render(&mut self) {
         if let PreviewType::Image(state) = &mut self.preview_type {
             state.set_render(true);
@@ -387,25 +1300,486 @@ impl ObjectPreviewPage {
     }
 
     fn download(&self) {
+        if self.is_modified_from_source() {
+            // the loaded bytes are not the same as the object, so download it again
+            self.tx.send(AppEventType::StartDownloadObject(
+                self.object_key.clone(),
+                self.file_detail.name.clone(),
+                self.file_detail.size_byte,
+                self.file_version_id.clone(),
+            ));
+            return;
+        }
         self.tx.send(AppEventType::StartSaveObject(
-            self.file_detail.name.clone(),
+            self.save_file_name(),
             Arc::clone(&self.object),
         ));
     }
 
+    fn save_file_name(&self) -> String {
+        if self.decompressed.is_some() {
+            // the decompressed bytes are saved for archive entries
+            strip_compression_extension(&self.file_detail.name).to_string()
+        } else {
+            self.file_detail.name.clone()
+        }
+    }
+
+    fn archive_source(&self, entry: &ArchiveEntry) -> ArchiveSource {
+        match entry.location {
+            EntryLocation::Zip { .. } => ArchiveSource::Object(self.file_version_id.clone()),
+            EntryLocation::Tar { .. } => ArchiveSource::Loaded(Arc::clone(&self.object)),
+        }
+    }
+
+    fn open_archive_entry(&self) {
+        let PreviewType::Archive(state) = &self.preview_type else {
+            return;
+        };
+        let Some(entry) = state.selected_entry().filter(|e| !e.is_dir) else {
+            return;
+        };
+        self.tx.send(AppEventType::OpenArchiveEntry(
+            self.object_key.clone(),
+            self.archive_source(entry),
+            entry.clone(),
+        ));
+    }
+
+    fn extract_archive_entries(&mut self) {
+        let PreviewType::Archive(state) = &self.preview_type else {
+            return;
+        };
+        let entries = state.target_entries();
+        let Some(entry) = entries.first() else {
+            return;
+        };
+        let source = self.archive_source(entry);
+        self.tx.send(AppEventType::ExtractArchiveEntries(
+            self.object_key.clone(),
+            source,
+            entries,
+        ));
+        if let PreviewType::Archive(state) = &mut self.preview_type {
+            state.clear_marks();
+        }
+    }
+
     fn download_as(&mut self, input: String) {
         let input: String = input.trim().into();
         if input.is_empty() {
             return;
         }
 
-        self.tx.send(AppEventType::StartSaveObject(
-            input,
-            Arc::clone(&self.object),
-        ));
+        if self.is_modified_from_source() {
+            self.tx.send(AppEventType::StartDownloadObjectAs(
+                self.object_key.clone(),
+                self.file_detail.size_byte,
+                input,
+                self.file_version_id.clone(),
+            ));
+        } else {
+            self.tx.send(AppEventType::StartSaveObject(
+                input,
+                Arc::clone(&self.object),
+            ));
+        }
 
         self.close_save_dialog();
     }
+
+    fn is_modified_from_source(&self) -> bool {
+        if self.archive_entry {
+            // there is no source object to download again
+            return false;
+        }
+        self.truncated_range().is_some()
+            || self.decompressed.is_some()
+            || matches!(self.preview_type, PreviewType::Archive(_))
+            || matches!(&self.preview_type, PreviewType::Table(s) if s.format() == TableFormat::Parquet)
+    }
+
+    fn is_partial_load_unsupported(&self) -> bool {
+        let msg = if self.archive_entry {
+            "Not supported for archive entries"
+        } else if self.decompressed.is_some() {
+            "Not supported for compressed objects"
+        } else {
+            return false;
+        };
+        self.tx.send(AppEventType::NotifyWarn(msg.into()));
+        true
+    }
+
+    fn current_loaded_range(&self) -> ByteRange {
+        let size_byte = self.file_detail.size_byte;
+        self.loaded_range
+            .unwrap_or(ByteRange::head(size_byte, size_byte))
+    }
+
+    fn toggle_follow(&mut self) {
+        if self.follow_id.take().is_some() || self.is_partial_load_unsupported() {
+            return;
+        }
+        if self.file_version_id.is_some() {
+            // a specific version never changes, so only the latest object can be followed
+            let msg = "Not supported for object versions".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let id = NEXT_FOLLOW_ID.fetch_add(1, Ordering::Relaxed);
+        self.follow_id = Some(id);
+        if let PreviewType::Text(state) = &mut self.preview_type {
+            state.scroll_lines_state.scroll_to_end();
+        }
+        self.send_follow_request(id);
+    }
+
+    fn send_follow_request(&self, id: usize) {
+        let spec = FollowPreviewSpec {
+            id,
+            object_key: self.object_key.clone(),
+            file_detail: self.file_detail.clone(),
+            loaded_range: self.current_loaded_range(),
+        };
+        self.tx.send(AppEventType::FollowPreviewObject(spec));
+    }
+
+    // the object previewed directly, not an entry of an archive
+    pub fn previewing_object(&self) -> Option<(&ObjectKey, &Option<String>)> {
+        (!self.archive_entry).then_some((&self.object_key, &self.file_version_id))
+    }
+
+    pub fn is_previewing(&self, object_key: &ObjectKey, version_id: &Option<String>) -> bool {
+        !self.archive_entry && self.object_key == *object_key && self.file_version_id == *version_id
+    }
+
+    pub fn is_following(&self, id: usize) -> bool {
+        self.follow_id == Some(id)
+    }
+
+    pub fn stop_follow(&mut self) {
+        self.follow_id = None;
+    }
+
+    pub fn complete_follow(
+        &mut self,
+        file_detail: FileDetail,
+        range: Option<ByteRange>,
+        bytes: Vec<u8>,
+    ) {
+        let Some(id) = self.follow_id else {
+            return;
+        };
+        if let Some(range) = range {
+            let (range, bytes) = self.cap_appended_bytes(range, bytes);
+            self.apply_range(range, bytes);
+            self.file_detail = file_detail;
+            if let PreviewType::Text(state) = &mut self.preview_type {
+                state.scroll_lines_state.scroll_to_end();
+            }
+        }
+        self.send_follow_request(id);
+    }
+
+    // drops the oldest lines so that following does not grow the buffer beyond the preview limit
+    fn cap_appended_bytes(&self, range: ByteRange, bytes: Vec<u8>) -> (ByteRange, Vec<u8>) {
+        let limit = self.ctx.config.preview.max_size_byte;
+        let loaded_range = self.current_loaded_range();
+        let total = self.object.bytes.len() + bytes.len();
+        if limit == 0 || range.start != loaded_range.end || total <= limit {
+            return (range, bytes);
+        }
+
+        let mut new_bytes = Vec::with_capacity(total);
+        new_bytes.extend_from_slice(&self.object.bytes);
+        new_bytes.extend(bytes);
+        let mut trimmed = total - limit;
+        if let PreviewType::Text(_) = self.preview_type {
+            // do not leave a partial line at the head
+            if let Some(i) = new_bytes[trimmed..].iter().position(|&b| b == b'\n') {
+                trimmed += i + 1;
+            }
+        }
+        new_bytes.drain(..trimmed);
+        let range = ByteRange {
+            start: loaded_range.start + trimmed,
+            end: range.end,
+        };
+        (range, new_bytes)
+    }
+
+    pub fn truncated_range(&self) -> Option<ByteRange> {
+        self.loaded_range
+            .filter(|r| !r.is_entire(self.file_detail.size_byte))
+    }
+
+    fn load_next_chunk(&self) {
+        let Some(range) = self.truncated_range() else {
+            return;
+        };
+        if self.is_partial_load_unsupported() {
+            return;
+        }
+        let size_byte = self.file_detail.size_byte;
+        match range.next(size_byte, self.ctx.config.preview.max_size_byte) {
+            Some(next) => self.load_range(next),
+            None => {
+                let msg = "Already loaded to the end of the object".to_string();
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+        }
+    }
+
+    fn load_last_chunk(&self) {
+        let Some(range) = self.truncated_range() else {
+            return;
+        };
+        if self.is_partial_load_unsupported() {
+            return;
+        }
+        let size_byte = self.file_detail.size_byte;
+        if range.end < size_byte {
+            self.load_range(ByteRange::tail(
+                size_byte,
+                self.ctx.config.preview.max_size_byte,
+            ));
+        } else if let PreviewType::Text(_) = self.preview_type {
+            let msg = "Already loaded to the end of the object".to_string();
+            self.tx.send(AppEventType::NotifyInfo(msg));
+        }
+    }
+
+    fn load_range(&self, range: ByteRange) {
+        self.tx.send(AppEventType::LoadPreviewRange(
+            self.object_key.clone(),
+            self.file_version_id.clone(),
+            range,
+        ));
+    }
+
+    pub fn apply_range(&mut self, range: ByteRange, bytes: Vec<u8>) {
+        let loaded_range = self.current_loaded_range();
+        if !matches!(
+            self.preview_type,
+            PreviewType::Text(_) | PreviewType::Hex(_)
+        ) {
+            return;
+        }
+
+        let appended = range.start == loaded_range.end;
+        let prepended = range.end == loaded_range.start;
+        if appended {
+            let mut new_bytes = Vec::with_capacity(self.object.bytes.len() + bytes.len());
+            new_bytes.extend_from_slice(&self.object.bytes);
+            new_bytes.extend(bytes);
+            self.object = Arc::new(RawObject { bytes: new_bytes });
+            self.loaded_range = Some(ByteRange {
+                start: loaded_range.start,
+                end: range.end,
+            });
+        } else if prepended {
+            let mut new_bytes = bytes;
+            new_bytes.extend_from_slice(&self.object.bytes);
+            self.object = Arc::new(RawObject { bytes: new_bytes });
+            self.loaded_range = Some(ByteRange {
+                start: range.start,
+                end: loaded_range.end,
+            });
+        } else {
+            self.object = Arc::new(RawObject { bytes });
+            self.loaded_range = Some(range);
+        }
+
+        let state = match &mut self.preview_type {
+            PreviewType::Text(state) => state,
+            PreviewType::Hex(state) => {
+                let start = self.loaded_range.map_or(0, |r| r.start);
+                state.set_object(Arc::clone(&self.object), start);
+                return;
+            }
+            _ => return,
+        };
+
+        let v_offset = state.scroll_lines_state.v_offset();
+        let msg = state.update_lines(
+            &highlight_file_detail(&self.file_detail, self.decompressed.is_some()),
+            &self.object,
+            self.ctx.config.preview.highlight,
+            &self.ctx.config.preview.highlight_theme,
+        );
+        if let Some(msg) = msg {
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        }
+        if appended {
+            // keep the current position so that the user can continue reading
+            state.scroll_lines_state.set_v_offset(v_offset);
+        } else {
+            state.scroll_lines_state.scroll_to_end();
+        }
+    }
+}
+
+fn search_dialog_title(preview_type: &PreviewType) -> &'static str {
+    match preview_type {
+        PreviewType::Text(state) => {
+            let options = state.search_options();
+            match (options.case_sensitive, options.regex) {
+                (false, false) => "Search",
+                (true, false) => "Search (case sensitive)",
+                (false, true) => "Search (regex)",
+                (true, true) => "Search (case sensitive, regex)",
+            }
+        }
+        _ => "Search bytes",
+    }
+}
+
+fn new_text_preview_state(
+    file_detail: &FileDetail,
+    object: &RawObject,
+    decompressed: Option<DecompressedInfo>,
+    ctx: &AppContext,
+    encoding_dialog_state: &mut EncodingDialogState,
+    tx: &Sender,
+) -> TextPreviewState {
+    let (state, guessed_encoding, msg) = TextPreviewState::new(
+        &highlight_file_detail(file_detail, decompressed.is_some()),
+        object,
+        ctx.config.preview.highlight,
+        &ctx.config.preview.highlight_theme,
+        ctx.config.preview.auto_detect_encoding,
+        encoding_dialog_state.selected(),
+    );
+    if let Some(msg) = msg {
+        tx.send(AppEventType::NotifyWarn(msg));
+    }
+    if let Some(guessed_encoding) = guessed_encoding {
+        encoding_dialog_state.add_guessed_encoding(guessed_encoding);
+    }
+    state
+}
+
+fn new_hex_preview_state(
+    file_detail: &FileDetail,
+    object: &Arc<RawObject>,
+    loaded_range: Option<ByteRange>,
+    decompressed: Option<DecompressedInfo>,
+) -> HexPreviewState {
+    match (decompressed, loaded_range) {
+        // the offsets in the loaded range do not apply to the decompressed bytes
+        (None, Some(range)) => {
+            HexPreviewState::new(Arc::clone(object), range.start, file_detail.size_byte)
+        }
+        _ => HexPreviewState::new(Arc::clone(object), 0, object.bytes.len()),
+    }
+}
+
+fn parse_tar_object(
+    file_detail: &FileDetail,
+    object: &RawObject,
+    tx: &Sender,
+) -> Option<Vec<ArchiveEntry>> {
+    let name = strip_compression_extension(&file_detail.name);
+    let is_tar = ArchiveFormat::from_file_name(&name) == Some(ArchiveFormat::Tar)
+        || infer::archive::is_tar(&object.bytes);
+    if !is_tar {
+        return None;
+    }
+    match parse_tar(&object.bytes) {
+        Ok(entries) => Some(entries),
+        Err(e) => {
+            // show the raw bytes as is
+            tx.send(AppEventType::NotifyWarn(e.msg));
+            None
+        }
+    }
+}
+
+fn parse_table_object(
+    file_detail: &FileDetail,
+    object: &RawObject,
+    partial: bool,
+    max_rows: usize,
+    tx: &Sender,
+) -> Option<TableData> {
+    let format = TableFormat::from_file_name(&strip_compression_extension(&file_detail.name))?;
+    match parse_table(format, &object.bytes, partial, max_rows) {
+        Ok(data) => Some(data),
+        Err(e) => {
+            // show the raw bytes as text
+            tx.send(AppEventType::NotifyWarn(e.msg));
+            None
+        }
+    }
+}
+
+fn decompress_object(
+    file_detail: &FileDetail,
+    object: RawObject,
+    limit: usize,
+    tx: &Sender,
+) -> (RawObject, Option<DecompressedInfo>) {
+    let content_encoding = file_detail.content_encoding.as_deref();
+    let Some(compression) = Compression::detect(&file_detail.name, content_encoding, &object.bytes)
+    else {
+        return (object, None);
+    };
+    match compression.decompress(&object.bytes, limit) {
+        Ok(decompressed) => {
+            let info = DecompressedInfo {
+                compression,
+                compressed_size_byte: object.bytes.len(),
+                decompressed_size_byte: decompressed.bytes.len(),
+                truncated: decompressed.truncated,
+            };
+            let object = RawObject {
+                bytes: decompressed.bytes,
+            };
+            (object, Some(info))
+        }
+        Err(e) => {
+            // show the raw bytes as is
+            let msg = format!("{} ({})", e.msg, compression.name());
+            tx.send(AppEventType::NotifyWarn(msg));
+            (object, None)
+        }
+    }
+}
+
+// use the file name without the compression extension to highlight the decompressed content
+fn highlight_file_detail(file_detail: &FileDetail, decompressed: bool) -> Cow<'_, FileDetail> {
+    if !decompressed {
+        return Cow::Borrowed(file_detail);
+    }
+    let name = strip_compression_extension(&file_detail.name).to_string();
+    Cow::Owned(FileDetail {
+        name,
+        ..file_detail.clone()
+    })
+}
+
+fn build_compression_info(info: DecompressedInfo) -> String {
+    format!(
+        "{}: {} -> {}{}",
+        info.compression.name(),
+        format_size_byte(info.compressed_size_byte),
+        format_size_byte(info.decompressed_size_byte),
+        if info.truncated { "+" } else { "" },
+    )
+}
+
+fn build_truncated_message(range: ByteRange, size_byte: usize) -> String {
+    format!(
+        " Truncated: showing {} - {} of {}",
+        format_size_byte(range.start),
+        format_size_byte(range.end),
+        format_size_byte(size_byte),
+    )
+}
+
+fn build_follow_message(interval_secs: u64) -> String {
+    format!(" Following (every {interval_secs}s)")
 }
 
 impl From<ImagePicker> for widget::ImagePicker {
@@ -424,7 +1798,13 @@ mod tests {
 
     use super::*;
     use chrono::{DateTime, Local, NaiveDateTime};
-    use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+    use ratatui::{
+        backend::TestBackend,
+        buffer::Buffer,
+        style::{Color, Modifier},
+        Terminal,
+    };
+    use std::io::Write;
 
     fn object(ss: &[&str]) -> RawObject {
         RawObject {
@@ -447,7 +1827,8 @@ mod tests {
                 "Thank you!",
             ];
             let object = object(&preview);
-            let mut page = ObjectPreviewPage::new(file_detail, None, object, ctx, tx);
+            let mut page =
+                ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
@@ -474,6 +1855,194 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_render_compressed_object() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let tx = sender();
+        let backend = TestBackend::new(50, 5);
+        let mut terminal = Terminal::new(backend)?;
+
+        terminal.draw(|f| {
+            let file_detail = FileDetail {
+                name: "file.json.gz".to_string(),
+                ..file_detail()
+            };
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(b"{\"hello\": \"world\"}").unwrap();
+            let object = RawObject {
+                bytes: encoder.finish().unwrap(),
+            };
+            let mut page =
+                ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
+            let area = Rect::new(0, 0, 50, 5);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.json.gz] (gzip: 38 B -> 18 B)─────┐",
+            "│ 1 {\"hello\": \"world\"}                           │",
+            "│                                                │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_tar_object() {
+        let ctx = Rc::default();
+        let tx = sender();
+
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(5);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "dir/a.txt", &b"hello"[..])
+            .unwrap();
+        let object = RawObject {
+            bytes: builder.into_inner().unwrap(),
+        };
+        let file_detail = FileDetail {
+            name: "file.tar".to_string(),
+            ..file_detail()
+        };
+
+        let page = ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
+
+        let PreviewType::Archive(state) = &page.preview_type else {
+            panic!("not an archive preview");
+        };
+        let entry = state.selected_entry().unwrap();
+        assert_eq!(entry.path, "dir/a.txt");
+        assert_eq!(entry.size_byte, 5);
+        assert!(page.is_modified_from_source());
+    }
+
+    #[tokio::test]
+    async fn test_new_partial_csv_object() {
+        let ctx = Rc::default();
+        let tx = sender();
+
+        let object = object(&["id,name", "1,foo", "2,ba"]);
+        let file_detail = FileDetail {
+            name: "file.csv".to_string(),
+            size_byte: 100,
+            ..file_detail()
+        };
+        let range = ByteRange::head(100, object.bytes.len());
+
+        let page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            None,
+            object,
+            Some(range),
+            ctx,
+            tx,
+        );
+
+        let PreviewType::Table(state) = &page.preview_type else {
+            panic!("not a table preview");
+        };
+        assert_eq!(state.format(), TableFormat::Csv);
+        assert!(page.is_modified_from_source());
+    }
+
+    #[tokio::test]
+    async fn test_new_binary_object() {
+        let ctx = Rc::default();
+        let tx = sender();
+
+        let object = RawObject {
+            bytes: b"\x7fELF\x02\x01\x01\x00\x00\x00".to_vec(),
+        };
+        let file_detail = FileDetail {
+            name: "file.bin".to_string(),
+            size_byte: 100,
+            ..file_detail()
+        };
+        let range = ByteRange { start: 50, end: 60 };
+
+        let mut page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            None,
+            object,
+            Some(range),
+            ctx,
+            tx,
+        );
+        assert!(matches!(page.preview_type, PreviewType::Hex(_)));
+
+        // the previous chunk is prepended
+        let prev = ByteRange { start: 40, end: 50 };
+        page.apply_range(prev, vec![0xff; 10]);
+        assert_eq!(page.object.bytes.len(), 20);
+        assert_eq!(
+            page.truncated_range(),
+            Some(ByteRange { start: 40, end: 60 })
+        );
+
+        page.toggle_hex();
+        assert!(matches!(page.preview_type, PreviewType::Text(_)));
+        page.toggle_hex();
+        assert!(matches!(page.preview_type, PreviewType::Hex(_)));
+    }
+
+    #[tokio::test]
+    async fn test_render_formatted_json() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let tx = sender();
+        let mut terminal = Terminal::new(TestBackend::new(40, 7))?;
+
+        let file_detail = FileDetail {
+            name: "file.json".to_string(),
+            ..file_detail()
+        };
+        let object = object(&[r#"{"a":[1,2],"b":{"c":true}}"#]);
+        let mut page =
+            ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
+        page.toggle_format();
+        page.toggle_fold(true);
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 40, 7);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.json] (formatted)───────┐",
+            "│ 1 {                                  │",
+            "│ 2   \"a\": [ … ], (2 items)            │",
+            "│ 6   \"b\": { … } (1 item)              │",
+            "│ 9 }                                  │",
+            "│                                      │",
+            "└──────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1, 2, 3, 4]) => fg: Color::DarkGray,
+            (12..15, [2]) => modifier: Modifier::DIM,
+            (17..27, [2]) => modifier: Modifier::DIM,
+            (12..15, [3]) => modifier: Modifier::DIM,
+            (16..25, [3]) => modifier: Modifier::DIM,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_render_with_scroll() -> std::io::Result<()> {
         let ctx = Rc::default();
@@ -484,7 +2053,8 @@ mod tests {
             let file_detail = file_detail();
             let preview = ["Hello, world!"; 20];
             let object = object(&preview);
-            let mut page = ObjectPreviewPage::new(file_detail, None, object, ctx, tx);
+            let mut page =
+                ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
@@ -511,6 +2081,94 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_go_to_line_and_mark() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let tx = Sender::new(tx);
+
+        let preview = ["Hello, world!"; 20];
+        let object = object(&preview);
+        let mut page =
+            ObjectPreviewPage::new(object_key(), file_detail(), None, object, None, ctx, tx);
+
+        fn top(page: &ObjectPreviewPage) -> usize {
+            match &page.preview_type {
+                PreviewType::Text(state) => state.scroll_lines_state.v_offset(),
+                _ => panic!("not a text preview"),
+            }
+        }
+
+        page.go_to("15".into());
+        assert_eq!(top(&page), 14);
+
+        page.go_to("21".into());
+        assert_eq!(top(&page), 14);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::NotifyWarn(msg)) if msg == "Line is out of range (lines: 20)"
+        ));
+        page.go_to("abc".into());
+        assert_eq!(top(&page), 14);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::NotifyWarn(msg)) if msg == "Invalid line number: abc"
+        ));
+
+        page.jump_to_mark();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::NotifyWarn(msg)) if msg == "No mark is set"
+        ));
+
+        page.set_mark();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::NotifyInfo(msg)) if msg == "Mark set at line 15"
+        ));
+        page.go_to("3".into());
+        assert_eq!(top(&page), 2);
+
+        page.jump_to_mark();
+        assert_eq!(top(&page), 14);
+        page.jump_to_mark();
+        assert_eq!(top(&page), 2);
+    }
+
+    #[tokio::test]
+    async fn test_go_to_folded_line() {
+        let ctx = Rc::default();
+        let tx = sender();
+
+        let file_detail = FileDetail {
+            name: "file.json".to_string(),
+            ..file_detail()
+        };
+        let object = object(&[r#"{"a":[1,2],"b":{"c":true}}"#]);
+        let mut page =
+            ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
+        page.toggle_format();
+        page.toggle_fold(true);
+
+        let PreviewType::Text(state) = &page.preview_type else {
+            panic!("not a text preview");
+        };
+        assert_eq!(state.line_count(), 9);
+
+        // line 4 is folded into line 2 (`"a": [ … ],`)
+        page.go_to("4".into());
+        let PreviewType::Text(state) = &page.preview_type else {
+            panic!("not a text preview");
+        };
+        assert_eq!(state.scroll_lines_state.v_offset(), 1);
+
+        page.go_to("9".into());
+        let PreviewType::Text(state) = &page.preview_type else {
+            panic!("not a text preview");
+        };
+        assert_eq!(state.scroll_lines_state.v_offset(), 3);
+    }
+
     #[tokio::test]
     async fn test_render_save_dialog_without_scroll() -> std::io::Result<()> {
         let ctx = Rc::default();
@@ -526,7 +2184,8 @@ mod tests {
                 "Thank you!",
             ];
             let object = object(&preview);
-            let mut page = ObjectPreviewPage::new(file_detail, None, object, ctx, tx);
+            let mut page =
+                ObjectPreviewPage::new(object_key(), file_detail, None, object, None, ctx, tx);
             page.open_save_dialog();
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
@@ -554,6 +2213,162 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_apply_range() {
+        let ctx = Rc::default();
+        let tx = sender();
+
+        let file_detail = file_detail();
+        let object = object(&["line 1", "line 2", ""]);
+        let range = ByteRange::head(file_detail.size_byte, 14);
+        let mut page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            None,
+            object,
+            Some(range),
+            ctx,
+            tx,
+        );
+        assert_eq!(
+            page.truncated_range(),
+            Some(ByteRange { start: 0, end: 14 })
+        );
+
+        // the next chunk is appended
+        let next = ByteRange { start: 14, end: 21 };
+        page.apply_range(next, b"line 3\n".to_vec());
+        assert_eq!(page.object.bytes, b"line 1\nline 2\nline 3\n".to_vec());
+        assert_eq!(
+            page.truncated_range(),
+            Some(ByteRange { start: 0, end: 21 })
+        );
+
+        // the last chunk replaces the loaded bytes
+        let last = ByteRange::tail(1034, 7);
+        page.apply_range(last, b"line 9\n".to_vec());
+        assert_eq!(page.object.bytes, b"line 9\n".to_vec());
+        assert_eq!(
+            page.truncated_range(),
+            Some(ByteRange {
+                start: 1027,
+                end: 1034
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_follow() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let tx = Sender::new(tx);
+
+        let file_detail = file_detail();
+        let object = object(&["line 1", "line 2", ""]);
+        let range = ByteRange::tail(file_detail.size_byte, 14);
+        let mut page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            None,
+            object,
+            Some(range),
+            ctx,
+            tx,
+        );
+
+        // ignored if not following
+        let mut current = page.file_detail.clone();
+        current.size_byte += 7;
+        let next = ByteRange::tail(current.size_byte, 7);
+        page.complete_follow(current.clone(), Some(next), b"line 3\n".to_vec());
+        assert_eq!(page.object.bytes, b"line 1\nline 2\n".to_vec());
+
+        page.toggle_follow();
+        let id = page.follow_id.unwrap();
+        assert!(page.is_following(id));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEventType::FollowPreviewObject(FollowPreviewSpec { id: i, .. })) if i == id
+        ));
+
+        page.complete_follow(current.clone(), Some(next), b"line 3\n".to_vec());
+        assert_eq!(page.object.bytes, b"line 1\nline 2\nline 3\n".to_vec());
+        assert_eq!(page.file_detail.size_byte, current.size_byte);
+        assert_eq!(
+            page.current_loaded_range(),
+            ByteRange::tail(current.size_byte, 21)
+        );
+
+        page.toggle_follow();
+        assert!(!page.is_following(id));
+    }
+
+    #[tokio::test]
+    async fn test_complete_follow_caps_buffer() {
+        let mut ctx = AppContext::default();
+        ctx.config.preview.max_size_byte = 16;
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let tx = Sender::new(tx);
+
+        let file_detail = file_detail();
+        let object = object(&["line 1", "line 2", ""]);
+        let range = ByteRange::tail(file_detail.size_byte, 14);
+        let mut page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            None,
+            object,
+            Some(range),
+            Rc::new(ctx),
+            tx,
+        );
+        page.toggle_follow();
+
+        let mut current = page.file_detail.clone();
+        current.size_byte += 7;
+        let next = ByteRange::tail(current.size_byte, 7);
+        page.complete_follow(current.clone(), Some(next), b"line 3\n".to_vec());
+
+        // the oldest line is dropped to stay within the limit
+        assert_eq!(page.object.bytes, b"line 2\nline 3\n".to_vec());
+        assert_eq!(
+            page.current_loaded_range(),
+            ByteRange::tail(current.size_byte, 14)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_toggle_follow_versioned_object() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let tx = Sender::new(tx);
+
+        let file_detail = file_detail();
+        let object = object(&["line 1", "line 2", ""]);
+        let range = ByteRange::tail(file_detail.size_byte, 14);
+        let mut page = ObjectPreviewPage::new(
+            object_key(),
+            file_detail,
+            Some("version-1".into()),
+            object,
+            Some(range),
+            ctx,
+            tx,
+        );
+
+        page.toggle_follow();
+        assert!(page.follow_id.is_none());
+        assert!(matches!(rx.try_recv(), Ok(AppEventType::NotifyWarn(_))));
+    }
+
+    #[test]
+    fn test_build_truncated_message() {
+        let range = ByteRange::head(3 * 1024 * 1024, 1024 * 1024);
+        let actual = build_truncated_message(range, 3 * 1024 * 1024);
+        let expected = " Truncated: showing 0 B - 1 MiB of 3 MiB";
+        assert_eq!(actual, expected);
+    }
+
     fn parse_datetime(s: &str) -> DateTime<Local> {
         NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
             .unwrap()
@@ -573,6 +2388,13 @@ mod tests {
         Sender::new(tx)
     }
 
+    fn object_key() -> ObjectKey {
+        ObjectKey {
+            bucket_name: "bucket-1".to_string(),
+            object_path: vec!["file.txt".to_string()],
+        }
+    }
+
     fn file_detail() -> FileDetail {
         FileDetail {
             name: "file.txt".to_string(),
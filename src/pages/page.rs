@@ -4,15 +4,20 @@ use ratatui::{crossterm::event::KeyEvent, layout::Rect, Frame};
 
 use crate::{
     app::AppContext,
-    event::Sender,
-    help::{Spans, SpansWithPriority},
+    archive::ArchiveEntry,
+    bookmark::Bookmark,
+    event::{DiffSpec, Sender},
+    help::{BuildHelpsItem, Spans, SpansWithPriority},
+    history::Location,
     keys::{UserEvent, UserEventMapper},
-    object::{BucketItem, FileDetail, ObjectItem, ObjectKey, RawObject},
+    object::{BucketItem, ByteRange, FileDetail, ObjectItem, ObjectKey, RawObject},
     pages::{
-        bucket_list::BucketListPage, help::HelpPage, initializing::InitializingPage,
-        object_detail::ObjectDetailPage, object_list::ObjectListPage,
-        object_preview::ObjectPreviewPage,
+        bookmark_list::BookmarkListPage, bucket_list::BucketListPage, help::HelpPage,
+        initializing::InitializingPage, object_detail::ObjectDetailPage,
+        object_diff::ObjectDiffPage, object_list::ObjectListPage,
+        object_preview::ObjectPreviewPage, recent_location_list::RecentLocationListPage,
     },
+    table::TableData,
     widget::ScrollListState,
 };
 
@@ -23,6 +28,9 @@ pub enum Page {
     ObjectList(Box<ObjectListPage>),
     ObjectDetail(Box<ObjectDetailPage>),
     ObjectPreview(Box<ObjectPreviewPage>),
+    ObjectDiff(Box<ObjectDiffPage>),
+    BookmarkList(Box<BookmarkListPage>),
+    RecentLocationList(Box<RecentLocationListPage>),
     Help(Box<HelpPage>),
 }
 
@@ -34,10 +42,28 @@ impl Page {
             Page::ObjectList(page) => page.handle_key(user_events, key_event),
             Page::ObjectDetail(page) => page.handle_key(user_events, key_event),
             Page::ObjectPreview(page) => page.handle_key(user_events, key_event),
+            Page::ObjectDiff(page) => page.handle_key(user_events, key_event),
+            Page::BookmarkList(page) => page.handle_key(user_events, key_event),
+            Page::RecentLocationList(page) => page.handle_key(user_events, key_event),
             Page::Help(page) => page.handle_key(user_events, key_event),
         }
     }
 
+    /// Returns the key binding section of the focused view, the keys are resolved in it.
+    pub fn key_section(&self) -> &'static str {
+        match self {
+            Page::Initializing(page) => page.key_section(),
+            Page::BucketList(page) => page.key_section(),
+            Page::ObjectList(page) => page.key_section(),
+            Page::ObjectDetail(page) => page.key_section(),
+            Page::ObjectPreview(page) => page.key_section(),
+            Page::ObjectDiff(page) => page.key_section(),
+            Page::BookmarkList(page) => page.key_section(),
+            Page::RecentLocationList(page) => page.key_section(),
+            Page::Help(page) => page.key_section(),
+        }
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         match self {
             Page::Initializing(page) => page.render(f, area),
@@ -45,6 +71,9 @@ impl Page {
             Page::ObjectList(page) => page.render(f, area),
             Page::ObjectDetail(page) => page.render(f, area),
             Page::ObjectPreview(page) => page.render(f, area),
+            Page::ObjectDiff(page) => page.render(f, area),
+            Page::BookmarkList(page) => page.render(f, area),
+            Page::RecentLocationList(page) => page.render(f, area),
             Page::Help(page) => page.render(f, area),
         }
     }
@@ -57,10 +86,27 @@ impl Page {
             Page::ObjectList(page) => page.helps(mapper),
             Page::ObjectDetail(page) => page.helps(mapper),
             Page::ObjectPreview(page) => page.helps(mapper),
+            Page::ObjectDiff(page) => page.helps(mapper),
+            Page::BookmarkList(page) => page.helps(mapper),
+            Page::RecentLocationList(page) => page.helps(mapper),
             Page::Help(page) => page.helps(mapper),
         }
     }
 
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        match self {
+            Page::Initializing(page) => page.help_items(),
+            Page::BucketList(page) => page.help_items(),
+            Page::ObjectList(page) => page.help_items(),
+            Page::ObjectDetail(page) => page.help_items(),
+            Page::ObjectPreview(page) => page.help_items(),
+            Page::ObjectDiff(page) => page.help_items(),
+            Page::BookmarkList(page) => page.help_items(),
+            Page::RecentLocationList(page) => page.help_items(),
+            Page::Help(page) => page.help_items(),
+        }
+    }
+
     pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
         // todo: no need to generate spans every time
         match self {
@@ -69,6 +115,9 @@ impl Page {
             Page::ObjectList(page) => page.short_helps(mapper),
             Page::ObjectDetail(page) => page.short_helps(mapper),
             Page::ObjectPreview(page) => page.short_helps(mapper),
+            Page::ObjectDiff(page) => page.short_helps(mapper),
+            Page::BookmarkList(page) => page.short_helps(mapper),
+            Page::RecentLocationList(page) => page.short_helps(mapper),
             Page::Help(page) => page.short_helps(mapper),
         }
     }
@@ -116,21 +165,101 @@ impl Page {
     }
 
     pub fn of_object_preview(
+        object_key: ObjectKey,
         file_detail: FileDetail,
         file_version_id: Option<String>,
         object: RawObject,
+        range: Option<ByteRange>,
         ctx: Rc<AppContext>,
         tx: Sender,
     ) -> Self {
         Self::ObjectPreview(Box::new(ObjectPreviewPage::new(
+            object_key,
+            file_detail,
+            file_version_id,
+            object,
+            range,
+            ctx,
+            tx,
+        )))
+    }
+
+    pub fn of_archive_preview(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+        entries: Vec<ArchiveEntry>,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectPreview(Box::new(ObjectPreviewPage::new_archive(
+            object_key,
+            file_detail,
+            file_version_id,
+            entries,
+            ctx,
+            tx,
+        )))
+    }
+
+    pub fn of_table_preview(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+        data: TableData,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectPreview(Box::new(ObjectPreviewPage::new_table(
+            object_key,
             file_detail,
             file_version_id,
+            data,
+            ctx,
+            tx,
+        )))
+    }
+
+    pub fn of_archive_entry_preview(
+        object_key: ObjectKey,
+        file_detail: FileDetail,
+        object: RawObject,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectPreview(Box::new(ObjectPreviewPage::new_archive_entry(
+            object_key,
+            file_detail,
             object,
             ctx,
             tx,
         )))
     }
 
+    pub fn of_object_diff(
+        spec: DiffSpec,
+        old_object: RawObject,
+        new_object: RawObject,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectDiff(Box::new(ObjectDiffPage::new(
+            spec, old_object, new_object, ctx, tx,
+        )))
+    }
+
+    pub fn of_bookmark_list(bookmarks: Vec<Bookmark>, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        Self::BookmarkList(Box::new(BookmarkListPage::new(bookmarks, ctx, tx)))
+    }
+
+    pub fn of_recent_location_list(
+        locations: Vec<Location>,
+        ctx: Rc<AppContext>,
+        tx: Sender,
+    ) -> Self {
+        Self::RecentLocationList(Box::new(RecentLocationListPage::new(locations, ctx, tx)))
+    }
+
     pub fn of_help(helps: Vec<Spans>, ctx: Rc<AppContext>, tx: Sender) -> Self {
         Self::Help(Box::new(HelpPage::new(helps, ctx, tx)))
     }
@@ -142,6 +271,13 @@ impl Page {
         }
     }
 
+    pub fn as_mut_object_list(&mut self) -> &mut ObjectListPage {
+        match self {
+            Self::ObjectList(page) => &mut *page,
+            page => panic!("Page is not ObjectList: {page:?}"),
+        }
+    }
+
     pub fn as_object_detail(&self) -> &ObjectDetailPage {
         match self {
             Self::ObjectDetail(page) => page,
@@ -181,7 +317,11 @@ impl PageStack {
     }
 
     pub fn push(&mut self, page: Page) {
-        if self.stack.len() == 1 && matches!(self.stack[0], Page::Initializing(_)) {
+        // the recent locations can be opened on the initializing page and closed to go back to it
+        if self.stack.len() == 1
+            && matches!(self.stack[0], Page::Initializing(_))
+            && !matches!(page, Page::RecentLocationList(_))
+        {
             self.stack.pop();
         }
         self.stack.push(page);
@@ -207,14 +347,24 @@ impl PageStack {
         self.stack.iter()
     }
 
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Page> {
+        self.stack.iter_mut()
+    }
+
     pub fn breadcrumb(&self) -> Vec<String> {
+        self.current_dir_object_key()
+            .map(|key| key.paths())
+            .unwrap_or_default()
+    }
+
+    // the directory of the last object list, which is also the directory of the object detail or preview on it
+    pub fn current_dir_object_key(&self) -> Option<&ObjectKey> {
         self.iter()
             .filter_map(|page| match page {
                 Page::ObjectList(page) => Some(page),
                 _ => None,
             })
             .next_back()
-            .map(|page| page.current_dir_object_key().paths())
-            .unwrap_or_default()
+            .map(|page| page.current_dir_object_key())
     }
 }
@@ -0,0 +1,268 @@
+use std::rc::Rc;
+
+use ratatui::{
+    crossterm::event::KeyEvent, layout::Rect, style::Style, text::Line, widgets::ListItem, Frame,
+};
+
+use crate::{
+    app::AppContext,
+    color::ColorTheme,
+    event::{AppEventType, Sender},
+    handle_user_events,
+    help::{
+        build_help_spans, build_short_help_spans, BuildHelpsItem, BuildShortHelpsItem, Spans,
+        SpansWithPriority,
+    },
+    history::Location,
+    keys::{UserEvent, UserEventMapper},
+    widget::{ScrollList, ScrollListState},
+};
+
+const ELLIPSIS: &str = "...";
+
+#[derive(Debug)]
+pub struct RecentLocationListPage {
+    locations: Vec<Location>,
+
+    list_state: ScrollListState,
+
+    ctx: Rc<AppContext>,
+    tx: Sender,
+}
+
+impl RecentLocationListPage {
+    pub fn new(locations: Vec<Location>, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        let items_len = locations.len();
+        Self {
+            locations,
+            list_state: ScrollListState::new(items_len),
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, user_events: Vec<UserEvent>, _key_event: KeyEvent) {
+        handle_user_events! { user_events =>
+            UserEvent::RecentLocationListSelect if self.non_empty() => {
+                let location = self.locations[self.list_state.selected].clone();
+                self.tx.send(AppEventType::OpenLocation(location));
+            }
+            UserEvent::RecentLocationListDown if self.non_empty() => {
+                self.list_state.select_next();
+            }
+            UserEvent::RecentLocationListUp if self.non_empty() => {
+                self.list_state.select_prev();
+            }
+            UserEvent::RecentLocationListGoToTop if self.non_empty() => {
+                self.list_state.select_first();
+            }
+            UserEvent::RecentLocationListGoToBottom if self.non_empty() => {
+                self.list_state.select_last();
+            }
+            UserEvent::RecentLocationListBack => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            UserEvent::Help => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            UserEvent::CommandLine => {
+                self.tx.send(AppEventType::OpenCommandLine);
+            }
+            UserEvent::CommandPalette => {
+                self.tx.send(AppEventType::OpenCommandPalette);
+            }
+        }
+    }
+
+    pub fn key_section(&self) -> &'static str {
+        "recent_location_list"
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let list_items = build_list_items(
+            &self.locations,
+            &self.ctx.theme,
+            self.list_state.offset,
+            self.list_state.selected,
+            area,
+        );
+
+        let list = ScrollList::new(list_items)
+            .title("Recent Locations")
+            .theme(&self.ctx.theme);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn helps(&self, mapper: &UserEventMapper) -> Vec<Spans> {
+        build_help_spans(self.help_items(), mapper, self.ctx.theme.help_key_fg)
+    }
+
+    pub fn help_items(&self) -> Vec<BuildHelpsItem> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildHelpsItem::new(UserEvent::Quit, "Quit app"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListDown, "Select next item"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListUp, "Select previous item"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListGoToTop, "Go to top"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListGoToBottom, "Go to bottom"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListSelect, "Open location"),
+            BuildHelpsItem::new(UserEvent::RecentLocationListBack, "Close recent locations"),
+        ];
+        helps
+    }
+
+    pub fn short_helps(&self, mapper: &UserEventMapper) -> Vec<SpansWithPriority> {
+        #[rustfmt::skip]
+        let helps = vec![
+            BuildShortHelpsItem::single(UserEvent::Quit, "Quit", 0),
+            BuildShortHelpsItem::group(vec![UserEvent::RecentLocationListDown, UserEvent::RecentLocationListUp], "Select", 1),
+            BuildShortHelpsItem::group(vec![UserEvent::RecentLocationListGoToTop, UserEvent::RecentLocationListGoToBottom], "Top/Bottom", 4),
+            BuildShortHelpsItem::single(UserEvent::RecentLocationListSelect, "Open", 2),
+            BuildShortHelpsItem::single(UserEvent::RecentLocationListBack, "Close", 3),
+            BuildShortHelpsItem::single(UserEvent::Help, "Help", 0),
+        ];
+        build_short_help_spans(helps, mapper)
+    }
+}
+
+impl RecentLocationListPage {
+    fn non_empty(&self) -> bool {
+        !self.locations.is_empty()
+    }
+}
+
+fn build_list_items<'a>(
+    locations: &'a [Location],
+    theme: &'a ColorTheme,
+    offset: usize,
+    selected: usize,
+    area: Rect,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize) - 2 /* border */;
+    locations
+        .iter()
+        .skip(offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(idx, location)| {
+            let selected = idx + offset == selected;
+            build_list_item(location, selected, area.width, theme)
+        })
+        .collect()
+}
+
+fn build_list_item<'a>(
+    location: &'a Location,
+    selected: bool,
+    width: u16,
+    theme: &'a ColorTheme,
+) -> ListItem<'a> {
+    let content = match &location.profile {
+        Some(profile) => format!("{} ({})", location.s3_uri(), profile),
+        None => location.s3_uri(),
+    };
+
+    let content_w = (width as usize) - 4 /* border + pad */;
+    let pad_content = console::pad_str(
+        &content,
+        content_w,
+        console::Alignment::Left,
+        Some(ELLIPSIS),
+    )
+    .to_string();
+    let line = Line::from(vec![" ".into(), pad_content.into(), " ".into()]);
+
+    let style = if selected {
+        Style::default()
+            .bg(theme.list_selected_bg)
+            .fg(theme.list_selected_fg)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{object::ObjectKey, set_cells};
+
+    use super::*;
+    use ratatui::{
+        backend::TestBackend, buffer::Buffer, crossterm::event::KeyCode, style::Color, Terminal,
+    };
+
+    #[tokio::test]
+    async fn test_render() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = sender();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let mut page = RecentLocationListPage::new(locations(), ctx, tx);
+            let area = Rect::new(0, 0, 40, 6);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Recent Locations─────────────── 1 / 2 ┐",
+            "│  s3://bucket-1/logs/2024/ (prod)     │",
+            "│  s3://bucket-2/                      │",
+            "│                                      │",
+            "│                                      │",
+            "└──────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..38, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_select() {
+        let ctx = Rc::default();
+        let (tx, mut rx) = sender();
+        let mut page = RecentLocationListPage::new(locations(), ctx, tx);
+
+        page.handle_key(
+            vec![UserEvent::RecentLocationListGoToBottom],
+            KeyEvent::from(KeyCode::Char('G')),
+        );
+        page.handle_key(
+            vec![UserEvent::RecentLocationListSelect],
+            KeyEvent::from(KeyCode::Enter),
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(AppEventType::OpenLocation(location)) if location == locations()[1]
+        ));
+    }
+
+    fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
+        let backend = TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+        Ok(terminal)
+    }
+
+    fn sender() -> (Sender, tokio::sync::mpsc::UnboundedReceiver<AppEventType>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (Sender::new(tx), rx)
+    }
+
+    fn locations() -> Vec<Location> {
+        vec![
+            Location {
+                profile: Some("prod".into()),
+                object_key: ObjectKey::with_prefix("bucket-1", "logs/2024/".into()),
+            },
+            Location {
+                profile: None,
+                object_key: ObjectKey::bucket("bucket-2"),
+            },
+        ]
+    }
+}
@@ -0,0 +1,225 @@
+use quick_xml::{events::Event, Reader, Writer};
+use yaml_rust2::{YamlEmitter, YamlLoader};
+
+use crate::{
+    error::{AppError, Result},
+    util::extension_from_file_name,
+};
+
+const XML_INDENT_SIZE: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrettyFormat {
+    Json,
+    Yaml,
+    Xml,
+}
+
+impl PrettyFormat {
+    pub fn from_file_name(file_name: &str) -> Option<PrettyFormat> {
+        if !file_name.contains('.') {
+            return None;
+        }
+        match extension_from_file_name(file_name).to_lowercase().as_str() {
+            "json" | "geojson" => Some(PrettyFormat::Json),
+            "yaml" | "yml" => Some(PrettyFormat::Yaml),
+            "xml" | "svg" => Some(PrettyFormat::Xml),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PrettyFormat::Json => "JSON",
+            PrettyFormat::Yaml => "YAML",
+            PrettyFormat::Xml => "XML",
+        }
+    }
+
+    pub fn pretty_print(&self, s: &str) -> Result<String> {
+        match self {
+            PrettyFormat::Json => pretty_print_json(s),
+            PrettyFormat::Yaml => pretty_print_yaml(s),
+            PrettyFormat::Xml => pretty_print_xml(s),
+        }
+    }
+}
+
+fn pretty_print_json(s: &str) -> Result<String> {
+    let value: serde_json::Value =
+        serde_json::from_str(s).map_err(|e| AppError::new("Failed to parse JSON", e))?;
+    serde_json::to_string_pretty(&value).map_err(|e| AppError::new("Failed to format JSON", e))
+}
+
+fn pretty_print_yaml(s: &str) -> Result<String> {
+    let docs =
+        YamlLoader::load_from_str(s).map_err(|e| AppError::new("Failed to parse YAML", e))?;
+    let mut out = String::new();
+    for doc in &docs {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let mut emitter = YamlEmitter::new(&mut out);
+        emitter.multiline_strings(true);
+        emitter
+            .dump(doc)
+            .map_err(|e| AppError::new("Failed to format YAML", e))?;
+    }
+    if docs.len() == 1 {
+        // the document start marker is only needed to separate multiple documents
+        if let Some(body) = out.strip_prefix("---\n") {
+            return Ok(body.into());
+        }
+    }
+    Ok(out)
+}
+
+fn pretty_print_xml(s: &str) -> Result<String> {
+    let mut reader = Reader::from_str(s);
+    reader.config_mut().trim_text(true);
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', XML_INDENT_SIZE);
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| AppError::new("Failed to parse XML", e))?
+        {
+            Event::Eof => break,
+            event => writer
+                .write_event(event)
+                .map_err(|e| AppError::new("Failed to format XML", e))?,
+        }
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| AppError::new("Failed to format XML", e))
+}
+
+/// A foldable object or array in pretty-printed JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldNode {
+    // line index of the opening bracket
+    pub start: usize,
+    // line index of the closing bracket
+    pub end: usize,
+    pub depth: usize,
+    // the number of direct children
+    pub items: usize,
+}
+
+/// Returns the foldable nodes of JSON pretty-printed by `pretty_print`, ordered by their start line.
+pub fn json_fold_nodes(pretty: &str) -> Vec<FoldNode> {
+    let mut nodes = Vec::new();
+    // (start, items) of the open nodes
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    for (i, line) in pretty.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('}') || trimmed.starts_with(']') {
+            if let Some((start, items)) = stack.pop() {
+                nodes.push(FoldNode {
+                    start,
+                    end: i,
+                    depth: stack.len(),
+                    items,
+                });
+            }
+            continue;
+        }
+        if let Some((_, items)) = stack.last_mut() {
+            *items += 1;
+        }
+        if trimmed.ends_with('{') || trimmed.ends_with('[') {
+            stack.push((i, 0));
+        }
+    }
+    nodes.sort_by_key(|n| n.start);
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("foo.json", Some(PrettyFormat::Json))]
+    #[case("foo.YML", Some(PrettyFormat::Yaml))]
+    #[case("foo.xml", Some(PrettyFormat::Xml))]
+    #[case("foo.txt", None)]
+    #[case("json", None)]
+    fn test_from_file_name(#[case] file_name: &str, #[case] expected: Option<PrettyFormat>) {
+        assert_eq!(PrettyFormat::from_file_name(file_name), expected);
+    }
+
+    #[test]
+    fn test_pretty_print_json() {
+        let actual = PrettyFormat::Json
+            .pretty_print(r#"{"b":[1,2],"a":{}}"#)
+            .unwrap();
+        let expected = "{\n  \"b\": [\n    1,\n    2\n  ],\n  \"a\": {}\n}";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pretty_print_yaml() {
+        let actual = PrettyFormat::Yaml
+            .pretty_print("a: {b: 1, c: [x, z]}")
+            .unwrap();
+        let expected = "a:\n  b: 1\n  c:\n    - x\n    - z";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pretty_print_xml() {
+        let actual = PrettyFormat::Xml
+            .pretty_print("<a><b x=\"1\">text</b><c/></a>")
+            .unwrap();
+        let expected = "<a>\n  <b x=\"1\">text</b>\n  <c/>\n</a>";
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(PrettyFormat::Json, r#"{"a": [1, 2"#)]
+    #[case(PrettyFormat::Yaml, "a: [1, 2")]
+    #[case(PrettyFormat::Xml, "<a><b></a>")]
+    fn test_pretty_print_invalid(#[case] format: PrettyFormat, #[case] s: &str) {
+        assert!(format.pretty_print(s).is_err());
+    }
+
+    #[test]
+    fn test_json_fold_nodes() {
+        let pretty = PrettyFormat::Json
+            .pretty_print(r#"{"a":[1,{"b":2}],"c":[],"d":3}"#)
+            .unwrap();
+        // {
+        //   "a": [
+        //     1,
+        //     {
+        //       "b": 2
+        //     }
+        //   ],
+        //   "c": [],
+        //   "d": 3
+        // }
+        let actual = json_fold_nodes(&pretty);
+        let expected = vec![
+            FoldNode {
+                start: 0,
+                end: 9,
+                depth: 0,
+                items: 3,
+            },
+            FoldNode {
+                start: 1,
+                end: 6,
+                depth: 1,
+                items: 2,
+            },
+            FoldNode {
+                start: 3,
+                end: 5,
+                depth: 2,
+                items: 1,
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,4 +1,4 @@
-use ratatui::{backend::Backend, Terminal};
+use ratatui::{backend::Backend, crossterm::event::KeyEvent, Terminal};
 
 use crate::{
     app::{App, Notification},
@@ -22,50 +22,18 @@ pub async fn run<B: Backend, C: Client>(
 
         match event {
             AppEventType::Key(key_event) => {
-                let user_events = app.mapper.find_events(key_event);
-
-                handle_user_events! { user_events =>
-                    UserEvent::Quit => {
-                        // Exit regardless of status
+                for (key_event, user_events) in app.resolve_key_event(key_event) {
+                    if handle_key_input(app, user_events, key_event) {
                         return Ok(());
                     }
                 }
-
-                if app.loading() {
-                    // Ignore key inputs while loading (except quit)
-                    continue;
-                }
-
-                if app.is_showing_notification()
-                    && matches!(app.page_stack.current_page(), Page::Initializing(_))
-                {
-                    return Ok(());
-                }
-
-                if matches!(app.current_notification(), Notification::Error(_)) {
-                    // Clear message and cancel key input
-                    app.clear_notification();
-                    continue;
-                }
-
-                if matches!(
-                    app.current_notification(),
-                    Notification::Info(_) | Notification::Success(_) | Notification::Warn(_)
-                ) {
-                    // Clear message and pass key input as is
-                    app.clear_notification();
-                }
-
-                handle_user_events! { user_events =>
-                    UserEvent::DumpApp => {
-                        app.dump_app();
-                        continue;
+            }
+            AppEventType::KeySequenceTimeout(id) => {
+                for (key_event, user_events) in app.key_sequence_timeout(id) {
+                    if handle_key_input(app, user_events, key_event) {
+                        return Ok(());
                     }
                 }
-
-                app.page_stack
-                    .current_page_mut()
-                    .handle_user_events(user_events, key_event);
             }
             AppEventType::Resize => {
                 // do nothing (only trigger redraw)
@@ -73,6 +41,12 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::Initialize(bucket, prefix) => {
                 app.initialize(bucket, prefix);
             }
+            AppEventType::OpenMfaTokenCodeDialog(bucket, prefix) => {
+                app.open_mfa_token_code_dialog(bucket, prefix);
+            }
+            AppEventType::InputMfaTokenCode(code) => {
+                app.input_mfa_token_code(code);
+            }
             AppEventType::CompleteInitialize(result) => {
                 app.complete_initialize(result);
             }
@@ -88,6 +62,12 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::GoToPath(object_key) => {
                 app.go_to_path(object_key);
             }
+            AppEventType::LoadGoToPathSuggestions(input) => {
+                app.load_go_to_path_suggestions(input);
+            }
+            AppEventType::CompleteLoadGoToPathSuggestions(result) => {
+                app.complete_load_go_to_path_suggestions(result);
+            }
             AppEventType::CompleteLoadObjects(result) => {
                 app.complete_load_objects(result);
             }
@@ -145,6 +125,36 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::CompletePreviewObject(result) => {
                 app.complete_preview_object(result);
             }
+            AppEventType::LoadPreviewRange(object_key, version_id, range) => {
+                app.load_preview_range(object_key, version_id, range);
+            }
+            AppEventType::CompleteLoadPreviewRange(result) => {
+                app.complete_load_preview_range(result);
+            }
+            AppEventType::CompletePreviewArchive(result) => {
+                app.complete_preview_archive(result);
+            }
+            AppEventType::CompletePreviewTable(result) => {
+                app.complete_preview_table(result);
+            }
+            AppEventType::OpenArchiveEntry(object_key, source, entry) => {
+                app.open_archive_entry(object_key, source, entry);
+            }
+            AppEventType::CompleteOpenArchiveEntry(result) => {
+                app.complete_open_archive_entry(result);
+            }
+            AppEventType::ExtractArchiveEntries(object_key, source, entries) => {
+                app.extract_archive_entries(object_key, source, entries);
+            }
+            AppEventType::CompleteExtractArchiveEntries(result) => {
+                app.complete_extract_archive_entries(result);
+            }
+            AppEventType::FollowPreviewObject(spec) => {
+                app.follow_preview_object(spec);
+            }
+            AppEventType::CompleteFollowPreviewObject(result) => {
+                app.complete_follow_preview_object(result);
+            }
             AppEventType::StartSaveObject(name, obj) => {
                 app.start_save_object(name, obj);
             }
@@ -172,15 +182,33 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::CopyObject(object_key, object_item) => {
                 app.copy_object(object_key, object_item);
             }
+            AppEventType::StartDiffWithClipboard(object_key, object_item) => {
+                app.start_diff_with_clipboard(object_key, object_item);
+            }
+            AppEventType::OpenDiff(spec) => {
+                app.open_diff(spec);
+            }
+            AppEventType::DiffObjects(spec) => {
+                app.diff_objects(spec);
+            }
+            AppEventType::CompleteDiffObjects(result) => {
+                app.complete_diff_objects(result);
+            }
             AppEventType::StartPasteObject(dest_dir_key) => {
                 app.start_paste_object(dest_dir_key);
             }
+            AppEventType::StartPasteToOtherPane(object_key, object_item, mode) => {
+                app.start_paste_to_other_pane(object_key, object_item, mode);
+            }
             AppEventType::OpenPasteConfirmDialog(spec) => {
                 app.open_paste_confirm_dialog(spec);
             }
             AppEventType::PasteObject(spec) => {
                 app.paste_object(spec);
             }
+            AppEventType::PasteObjects(spec, objs) => {
+                app.paste_objects(spec, objs);
+            }
             AppEventType::CompletePasteObject(result) => {
                 app.complete_paste_object(result);
             }
@@ -211,6 +239,54 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::OpenHelp => {
                 app.open_help();
             }
+            AppEventType::OpenCommandLine => {
+                app.open_command_line();
+            }
+            AppEventType::OpenCommandPalette => {
+                app.open_command_palette();
+            }
+            AppEventType::OpenBookmarks => {
+                app.open_bookmarks();
+            }
+            AppEventType::OpenBookmark(bookmark) => {
+                app.open_bookmark(bookmark);
+            }
+            AppEventType::AddBookmark(name, object_key) => {
+                app.add_bookmark(name, object_key);
+            }
+            AppEventType::RemoveBookmark(name) => {
+                app.remove_bookmark(name);
+            }
+            AppEventType::OpenRecentLocations => {
+                app.open_recent_locations();
+            }
+            AppEventType::OpenLocation(location) => {
+                app.open_location(location);
+            }
+            AppEventType::HistoryBack => {
+                app.history_back();
+            }
+            AppEventType::HistoryForward => {
+                app.history_forward();
+            }
+            AppEventType::ToggleDualPane => {
+                app.toggle_dual_pane();
+            }
+            AppEventType::SwitchPane => {
+                app.switch_pane();
+            }
+            AppEventType::SwitchProfile(profile, object_key) => {
+                app.switch_profile(profile, object_key);
+            }
+            AppEventType::CompleteSwitchProfile(result) => {
+                app.complete_switch_profile(result);
+            }
+            AppEventType::ReloadCredentials => {
+                app.reload_credentials();
+            }
+            AppEventType::CompleteReloadCredentials(result) => {
+                app.complete_reload_credentials(result);
+            }
             AppEventType::CopyToClipboard(name, value) => {
                 app.copy_to_clipboard(name, value);
             }
@@ -226,6 +302,90 @@ pub async fn run<B: Backend, C: Client>(
             AppEventType::NotifyError(e) => {
                 app.error_notification(e);
             }
+            AppEventType::NotifyRequestError(e) => {
+                app.request_error_notification(e);
+            }
+        }
+    }
+}
+
+// Returns true if the app should quit
+fn handle_key_input<C: Client>(
+    app: &mut App<C>,
+    user_events: Vec<UserEvent>,
+    key_event: KeyEvent,
+) -> bool {
+    handle_user_events! { user_events =>
+        UserEvent::Quit => {
+            // Exit regardless of status
+            return true;
+        }
+    }
+
+    if app.loading() {
+        // Ignore key inputs while loading (except quit)
+        return false;
+    }
+
+    if app.is_showing_mfa_token_code_dialog() {
+        app.handle_mfa_token_code_dialog_events(user_events, key_event);
+        return false;
+    }
+    if app.is_showing_reauth_dialog() {
+        app.handle_reauth_dialog_events(user_events);
+        return false;
+    }
+    if app.is_showing_bulk_report_dialog() {
+        app.handle_bulk_report_dialog_events(user_events);
+        return false;
+    }
+
+    if app.is_showing_notification()
+        && matches!(app.page_stack.current_page(), Page::Initializing(_))
+    {
+        if !user_events.contains(&UserEvent::InitializingRecentLocations) {
+            return true;
         }
+        // the recent locations may be available even if the initialization failed
+        app.clear_notification();
+    }
+
+    if matches!(app.current_notification(), Notification::Error(_)) {
+        // Clear message and cancel key input
+        app.clear_notification();
+        return false;
+    }
+
+    if matches!(
+        app.current_notification(),
+        Notification::Info(_) | Notification::Success(_) | Notification::Warn(_)
+    ) {
+        // Clear message and pass key input as is
+        app.clear_notification();
     }
+
+    if app.is_showing_command_line() {
+        app.handle_command_line_events(user_events, key_event);
+        return false;
+    }
+
+    if app.is_showing_command_palette() {
+        if let Some(user_event) = app.handle_command_palette_events(user_events, key_event) {
+            // execute the selected action as if its key was pressed
+            return handle_key_input(app, vec![user_event], key_event);
+        }
+        return false;
+    }
+
+    handle_user_events! { user_events =>
+        UserEvent::DumpApp => {
+            app.dump_app();
+            return false;
+        }
+    }
+
+    app.page_stack
+        .current_page_mut()
+        .handle_user_events(user_events, key_event);
+    false
 }
@@ -0,0 +1,146 @@
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::error::{AppError, Result};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub case_sensitive: bool,
+    pub regex: bool,
+}
+
+/// A match of the search in a line, `range` is the byte range in the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub line: usize,
+    pub range: Range<usize>,
+}
+
+pub fn build_search_regex(query: &str, options: SearchOptions) -> Result<Regex> {
+    let pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+        .map_err(|e| AppError::new("Invalid regular expression", e))
+}
+
+/// Returns all non-empty matches in the lines, ordered by their position.
+pub fn find_matches<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+    regex: &Regex,
+) -> Vec<SearchMatch> {
+    let mut matches = Vec::new();
+    for (line, s) in lines.into_iter().enumerate() {
+        let ms = regex
+            .find_iter(s.as_ref())
+            .filter(|m| !m.is_empty())
+            .map(|m| SearchMatch {
+                line,
+                range: m.range(),
+            });
+        matches.extend(ms);
+    }
+    matches
+}
+
+const LIST_FILTER_REGEX_PREFIX: &str = "re:";
+
+/// A filter of list items by name.
+///
+/// A filter starting with `re:` is treated as a regular expression, otherwise as a plain substring.
+/// An invalid regular expression matches nothing.
+#[derive(Debug, Clone)]
+pub enum ListFilter {
+    Text(String),
+    Regex(Option<Regex>),
+}
+
+impl ListFilter {
+    pub fn new(filter: &str) -> Self {
+        match filter.strip_prefix(LIST_FILTER_REGEX_PREFIX) {
+            Some(pattern) => ListFilter::Regex(Regex::new(pattern).ok()),
+            None => ListFilter::Text(filter.to_string()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, ListFilter::Text(s) if s.is_empty())
+    }
+
+    /// Returns the byte range of the first match in the text.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        match self {
+            ListFilter::Text(s) => text.find(s.as_str()).map(|i| i..i + s.len()),
+            ListFilter::Regex(Some(regex)) => regex.find(text).map(|m| m.range()),
+            ListFilter::Regex(None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("foo", false, false, vec![(0, 0..3), (0, 8..11), (1, 4..7)])]
+    #[case("foo", true, false, vec![(0, 0..3), (1, 4..7)])]
+    #[case("f.o", false, false, vec![])]
+    #[case("f.o", false, true, vec![(0, 0..3), (0, 8..11), (1, 4..7)])]
+    #[case("o*", false, true, vec![(0, 1..3), (0, 9..11), (1, 1..2), (1, 5..7)])]
+    fn test_find_matches(
+        #[case] query: &str,
+        #[case] case_sensitive: bool,
+        #[case] regex: bool,
+        #[case] expected: Vec<(usize, Range<usize>)>,
+    ) {
+        let lines = ["foo bar FOO", "bob foo"];
+        let options = SearchOptions {
+            case_sensitive,
+            regex,
+        };
+        let regex = build_search_regex(query, options).unwrap();
+        let actual = find_matches(lines, &regex);
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(line, range)| SearchMatch { line, range })
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_build_search_regex_invalid() {
+        let options = SearchOptions {
+            case_sensitive: false,
+            regex: true,
+        };
+        assert!(build_search_regex("(foo", options).is_err());
+        let options = SearchOptions {
+            case_sensitive: false,
+            regex: false,
+        };
+        assert!(build_search_regex("(foo", options).is_ok());
+    }
+
+    #[rstest]
+    #[case("", "2024-01.csv", Some(0..0))]
+    #[case("01", "2024-01.csv", Some(5..7))]
+    #[case("^2024", "2024-01.csv", None)]
+    #[case("re:^2024-", "2024-01.csv", Some(0..5))]
+    #[case("re:\\d+\\.csv$", "2024-01.csv", Some(5..11))]
+    #[case("re:^2023", "2024-01.csv", None)]
+    #[case("re:(2024", "2024-01.csv", None)]
+    fn test_list_filter_find(
+        #[case] filter: &str,
+        #[case] text: &str,
+        #[case] expected: Option<Range<usize>>,
+    ) {
+        assert_eq!(ListFilter::new(filter).find(text), expected);
+    }
+}
@@ -0,0 +1,601 @@
+use std::sync::Arc;
+
+use bytes::{Buf, Bytes};
+use indexmap::IndexSet;
+use parquet::{
+    basic::ConvertedType,
+    errors::ParquetError,
+    file::{
+        metadata::{FooterTail, ParquetMetaData, ParquetMetaDataReader},
+        properties::ReaderProperties,
+        reader::{ChunkReader, Length, RowGroupReader},
+        serialized_reader::SerializedRowGroupReader,
+        FOOTER_SIZE,
+    },
+    record::Field,
+    schema::types::Type,
+};
+
+use crate::{
+    error::{AppError, Result},
+    object::ByteRange,
+    util::extension_from_file_name,
+};
+
+/// The size of the end of the file to load to find the metadata of a Parquet file.
+/// If the metadata is larger than this, it is loaded with another request.
+pub const PARQUET_TAIL_SIZE: usize = 64 * 1024;
+
+const JSON_LINES_VALUE_COLUMN: &str = "(value)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Csv,
+    Tsv,
+    JsonLines,
+    Parquet,
+}
+
+impl TableFormat {
+    pub fn from_file_name(file_name: &str) -> Option<TableFormat> {
+        if !file_name.contains('.') {
+            return None;
+        }
+        match extension_from_file_name(file_name).to_lowercase().as_str() {
+            "csv" => Some(TableFormat::Csv),
+            "tsv" => Some(TableFormat::Tsv),
+            "jsonl" | "ndjson" => Some(TableFormat::JsonLines),
+            "parquet" => Some(TableFormat::Parquet),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TableFormat::Csv => "CSV",
+            TableFormat::Tsv => "TSV",
+            TableFormat::JsonLines => "JSON Lines",
+            TableFormat::Parquet => "Parquet",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableColumn {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableData {
+    pub format: TableFormat,
+    pub columns: Vec<TableColumn>,
+    pub rows: Vec<Vec<String>>,
+    // the number of rows in the whole object, if known
+    pub total_rows: Option<usize>,
+    // true if the rows are only a part of the object
+    pub truncated: bool,
+}
+
+/// Parses the loaded bytes of a CSV, TSV or JSON Lines object up to `max_rows` rows (`0` means no limit).
+///
+/// If `partial` is true, the bytes are only the beginning of the object and the last incomplete line is ignored.
+pub fn parse_table(
+    format: TableFormat,
+    bytes: &[u8],
+    partial: bool,
+    max_rows: usize,
+) -> Result<TableData> {
+    let bytes = if partial {
+        match bytes.iter().rposition(|b| *b == b'\n') {
+            Some(i) => &bytes[..=i],
+            None => &[],
+        }
+    } else {
+        bytes
+    };
+    let max_rows = if max_rows == 0 { usize::MAX } else { max_rows };
+
+    let (columns, rows, truncated) = match format {
+        TableFormat::Csv => parse_delimited(bytes, b',', true, max_rows)?,
+        TableFormat::Tsv => parse_delimited(bytes, b'\t', false, max_rows)?,
+        TableFormat::JsonLines => parse_json_lines(bytes, max_rows)?,
+        TableFormat::Parquet => {
+            return Err(AppError::msg("Parquet must be loaded by its row groups"));
+        }
+    };
+    let total_rows = (!partial && !truncated).then_some(rows.len());
+    Ok(TableData {
+        format,
+        columns,
+        rows,
+        total_rows,
+        truncated: partial || truncated,
+    })
+}
+
+type ParsedRows = (Vec<TableColumn>, Vec<Vec<String>>, bool);
+
+fn parse_delimited(
+    bytes: &[u8],
+    delimiter: u8,
+    quoting: bool,
+    max_rows: usize,
+) -> Result<ParsedRows> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .quoting(quoting)
+        .flexible(true)
+        .from_reader(bytes);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| AppError::new("Failed to parse table", e))?
+        .clone();
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut truncated = false;
+    for record in reader.records() {
+        if rows.len() >= max_rows {
+            truncated = true;
+            break;
+        }
+        let record = record.map_err(|e| AppError::new("Failed to parse table", e))?;
+        rows.push(record.iter().map(String::from).collect());
+    }
+
+    let column_count = rows.iter().map(Vec::len).fold(headers.len(), usize::max);
+    let columns = (0..column_count)
+        .map(|i| {
+            let name = headers
+                .get(i)
+                .map(String::from)
+                .unwrap_or_else(|| format!("column{}", i + 1));
+            let values = rows.iter().filter_map(|row| row.get(i)).map(String::as_str);
+            TableColumn {
+                name,
+                data_type: infer_text_type(values).into(),
+            }
+        })
+        .collect();
+    Ok((columns, rows, truncated))
+}
+
+fn infer_text_type<'a>(values: impl Iterator<Item = &'a str>) -> &'static str {
+    let mut data_type = None;
+    for value in values.filter(|v| !v.is_empty()) {
+        let t = if value.parse::<i64>().is_ok() {
+            "integer"
+        } else if value.parse::<f64>().is_ok() {
+            "float"
+        } else if value.parse::<bool>().is_ok() {
+            "boolean"
+        } else {
+            return "string";
+        };
+        data_type = match (data_type, t) {
+            (None, t) => Some(t),
+            (Some(a), b) if a == b => Some(a),
+            (Some("integer"), "float") | (Some("float"), "integer") => Some("float"),
+            _ => return "string",
+        };
+    }
+    data_type.unwrap_or("string")
+}
+
+fn parse_json_lines(bytes: &[u8], max_rows: usize) -> Result<ParsedRows> {
+    let text = String::from_utf8_lossy(bytes);
+
+    let mut values = Vec::new();
+    let mut truncated = false;
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if values.len() >= max_rows {
+            truncated = true;
+            break;
+        }
+        let value: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| AppError::new("Failed to parse table", e))?;
+        values.push(value);
+    }
+
+    let mut names: IndexSet<&str> = IndexSet::new();
+    for value in &values {
+        match value {
+            serde_json::Value::Object(map) => names.extend(map.keys().map(String::as_str)),
+            _ => {
+                names.insert(JSON_LINES_VALUE_COLUMN);
+            }
+        }
+    }
+
+    let cell = |value: &serde_json::Value, name: &str| -> Option<serde_json::Value> {
+        match value {
+            serde_json::Value::Object(map) => map.get(name).cloned(),
+            v if name == JSON_LINES_VALUE_COLUMN => Some(v.clone()),
+            _ => None,
+        }
+    };
+
+    let columns = names
+        .iter()
+        .map(|name| {
+            let values = values.iter().filter_map(|v| cell(v, name));
+            TableColumn {
+                name: name.to_string(),
+                data_type: infer_json_type(values).into(),
+            }
+        })
+        .collect();
+    let rows = values
+        .iter()
+        .map(|value| {
+            names
+                .iter()
+                .map(|name| match cell(value, name) {
+                    Some(serde_json::Value::String(s)) => s,
+                    Some(v) => v.to_string(),
+                    None => String::new(),
+                })
+                .collect()
+        })
+        .collect();
+    Ok((columns, rows, truncated))
+}
+
+fn infer_json_type(values: impl Iterator<Item = serde_json::Value>) -> &'static str {
+    let mut data_type = None;
+    for value in values {
+        let t = match value {
+            serde_json::Value::Null => continue,
+            serde_json::Value::Bool(_) => "boolean",
+            serde_json::Value::Number(_) => "number",
+            serde_json::Value::String(_) => "string",
+            serde_json::Value::Array(_) => "array",
+            serde_json::Value::Object(_) => "object",
+        };
+        data_type = match data_type {
+            None => Some(t),
+            Some(a) if a == t => Some(a),
+            _ => return "mixed",
+        };
+    }
+    data_type.unwrap_or("null")
+}
+
+/// Returns the range of the metadata of a Parquet file from the bytes loaded from the end of the file.
+pub fn parquet_metadata_range(tail: &[u8], tail_start: usize) -> Result<ByteRange> {
+    let Some(footer) = tail.len().checked_sub(FOOTER_SIZE).map(|i| &tail[i..]) else {
+        return Err(AppError::msg("Invalid Parquet file: too small"));
+    };
+    let footer: [u8; FOOTER_SIZE] = footer.try_into().unwrap();
+    let footer = FooterTail::try_new(&footer).map_err(parquet_error)?;
+    if footer.is_encrypted_footer() {
+        return Err(AppError::msg("Encrypted Parquet files are not supported"));
+    }
+    let end = tail_start + tail.len() - FOOTER_SIZE;
+    let len = footer.metadata_length();
+    if len > end {
+        return Err(AppError::msg("Invalid Parquet file: corrupt footer"));
+    }
+    Ok(ByteRange {
+        start: end - len,
+        end,
+    })
+}
+
+pub fn parse_parquet_metadata(bytes: &[u8]) -> Result<ParquetMetaData> {
+    ParquetMetaDataReader::decode_metadata(bytes).map_err(parquet_error)
+}
+
+/// Returns the number of the first row groups required to read `max_rows` rows (`0` means all row groups),
+/// and the range of bytes that contains them.
+pub fn parquet_row_groups_range(
+    metadata: &ParquetMetaData,
+    max_rows: usize,
+) -> (usize, Option<ByteRange>) {
+    let max_rows = if max_rows == 0 { usize::MAX } else { max_rows };
+
+    let mut count = 0;
+    let mut rows = 0;
+    for row_group in metadata.row_groups() {
+        if rows >= max_rows {
+            break;
+        }
+        rows += row_group.num_rows() as usize;
+        count += 1;
+    }
+
+    let range = metadata.row_groups()[..count]
+        .iter()
+        .flat_map(|row_group| row_group.columns())
+        .map(|column| {
+            let (start, len) = column.byte_range();
+            ByteRange {
+                start: start as usize,
+                end: (start + len) as usize,
+            }
+        })
+        .reduce(|a, b| ByteRange {
+            start: a.start.min(b.start),
+            end: a.end.max(b.end),
+        });
+    (count, range)
+}
+
+/// Reads the rows of the first `row_group_count` row groups from the bytes of `range` in a Parquet file.
+pub fn read_parquet_table(
+    metadata: &ParquetMetaData,
+    row_group_count: usize,
+    range: Option<ByteRange>,
+    bytes: Vec<u8>,
+    max_rows: usize,
+) -> Result<TableData> {
+    let max_rows = if max_rows == 0 { usize::MAX } else { max_rows };
+
+    let reader = Arc::new(RangeChunkReader {
+        start: range.map(|r| r.start).unwrap_or_default() as u64,
+        bytes: Bytes::from(bytes),
+    });
+    let props = Arc::new(ReaderProperties::builder().build());
+
+    let mut rows = Vec::new();
+    'row_groups: for row_group in &metadata.row_groups()[..row_group_count] {
+        let row_group_reader =
+            SerializedRowGroupReader::new(Arc::clone(&reader), row_group, None, Arc::clone(&props))
+                .map_err(parquet_error)?;
+        for row in row_group_reader.get_row_iter(None).map_err(parquet_error)? {
+            if rows.len() >= max_rows {
+                break 'row_groups;
+            }
+            let row = row.map_err(parquet_error)?;
+            rows.push(
+                row.get_column_iter()
+                    .map(|(_, f)| format_field(f))
+                    .collect(),
+            );
+        }
+    }
+
+    let columns = metadata
+        .file_metadata()
+        .schema_descr()
+        .root_schema()
+        .get_fields()
+        .iter()
+        .map(|field| TableColumn {
+            name: field.name().into(),
+            data_type: parquet_type_name(field),
+        })
+        .collect();
+    let total_rows = metadata.file_metadata().num_rows() as usize;
+    Ok(TableData {
+        format: TableFormat::Parquet,
+        columns,
+        truncated: rows.len() < total_rows,
+        rows,
+        total_rows: Some(total_rows),
+    })
+}
+
+fn format_field(field: &Field) -> String {
+    match field {
+        Field::Str(s) => s.clone(),
+        f => f.to_string(),
+    }
+}
+
+fn parquet_type_name(field: &Type) -> String {
+    let converted_type = field.get_basic_info().converted_type();
+    let base = if field.is_primitive() {
+        field.get_physical_type().to_string()
+    } else {
+        "GROUP".into()
+    };
+    if converted_type == ConvertedType::NONE {
+        base
+    } else {
+        format!("{base} ({converted_type})")
+    }
+}
+
+fn parquet_error(e: ParquetError) -> AppError {
+    AppError::new("Failed to read Parquet file", e)
+}
+
+// serves the bytes loaded from `start` as if they were at the same position in the whole file
+struct RangeChunkReader {
+    start: u64,
+    bytes: Bytes,
+}
+
+impl RangeChunkReader {
+    fn slice(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        let begin = start
+            .checked_sub(self.start)
+            .map(|i| i as usize)
+            .filter(|i| i + length <= self.bytes.len())
+            .ok_or_else(|| ParquetError::EOF(format!("range {start}+{length} is not loaded")))?;
+        Ok(self.bytes.slice(begin..begin + length))
+    }
+}
+
+impl Length for RangeChunkReader {
+    fn len(&self) -> u64 {
+        self.start + self.bytes.len() as u64
+    }
+}
+
+impl ChunkReader for RangeChunkReader {
+    type T = bytes::buf::Reader<Bytes>;
+
+    fn get_read(&self, start: u64) -> parquet::errors::Result<Self::T> {
+        let length = (self.len().saturating_sub(start)) as usize;
+        Ok(self.slice(start, length)?.reader())
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        self.slice(start, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::{
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::parser::parse_message_type,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let bytes = b"id,name,score\n1,foo,1.5\n2,\"bar, baz\",2\n3,qux";
+        let actual = parse_table(TableFormat::Csv, bytes, true, 0).unwrap();
+
+        assert_eq!(
+            actual.columns,
+            vec![
+                column("id", "integer"),
+                column("name", "string"),
+                column("score", "float")
+            ]
+        );
+        assert_eq!(
+            actual.rows,
+            vec![vec!["1", "foo", "1.5"], vec!["2", "bar, baz", "2"]]
+        );
+        assert_eq!(actual.total_rows, None);
+        assert!(actual.truncated);
+    }
+
+    #[test]
+    fn test_parse_tsv_with_max_rows() {
+        let bytes = b"a\tb\n1\t\"x\n2\ty\n3\tz\n";
+        let actual = parse_table(TableFormat::Tsv, bytes, false, 2).unwrap();
+
+        assert_eq!(actual.rows, vec![vec!["1", "\"x"], vec!["2", "y"]]);
+        assert!(actual.truncated);
+    }
+
+    #[test]
+    fn test_parse_json_lines() {
+        let bytes = br#"{"id": 1, "name": "foo", "tags": ["a"]}
+
+{"id": 2, "extra": null}
+"#;
+        let actual = parse_table(TableFormat::JsonLines, bytes, false, 0).unwrap();
+
+        assert_eq!(
+            actual.columns,
+            vec![
+                column("id", "number"),
+                column("name", "string"),
+                column("tags", "array"),
+                column("extra", "null"),
+            ]
+        );
+        assert_eq!(
+            actual.rows,
+            vec![vec!["1", "foo", "[\"a\"]", ""], vec!["2", "", "", "null"]]
+        );
+        assert_eq!(actual.total_rows, Some(2));
+        assert!(!actual.truncated);
+    }
+
+    #[test]
+    fn test_parse_json_lines_invalid() {
+        let actual = parse_table(TableFormat::JsonLines, b"{\"a\": 1}\nnot json\n", false, 0);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_read_parquet_by_ranges() {
+        let bytes = parquet_file(&[&[1, 2, 3], &[4, 5], &[6]]);
+        let size = bytes.len();
+
+        let tail_range = ByteRange::tail(size, 100);
+        let tail = &bytes[tail_range.start..tail_range.end];
+        let metadata_range = parquet_metadata_range(tail, tail_range.start).unwrap();
+        let metadata =
+            parse_parquet_metadata(&bytes[metadata_range.start..metadata_range.end]).unwrap();
+
+        let (count, range) = parquet_row_groups_range(&metadata, 4);
+        assert_eq!(count, 2);
+        let range = range.unwrap();
+        let row_groups = bytes[range.start..range.end].to_vec();
+
+        let actual = read_parquet_table(&metadata, count, Some(range), row_groups, 4).unwrap();
+
+        assert_eq!(
+            actual.columns,
+            vec![column("id", "INT64"), column("name", "BYTE_ARRAY (UTF8)")]
+        );
+        assert_eq!(
+            actual.rows,
+            vec![
+                vec!["1", "name1"],
+                vec!["2", "name2"],
+                vec!["3", "name3"],
+                vec!["4", "name4"],
+            ]
+        );
+        assert_eq!(actual.total_rows, Some(6));
+        assert!(actual.truncated);
+    }
+
+    #[test]
+    fn test_parquet_metadata_range_invalid() {
+        let actual = parquet_metadata_range(b"not a parquet file", 0);
+        assert!(actual.is_err());
+    }
+
+    fn column(name: &str, data_type: &str) -> TableColumn {
+        TableColumn {
+            name: name.into(),
+            data_type: data_type.into(),
+        }
+    }
+
+    fn parquet_file(row_groups: &[&[i64]]) -> Vec<u8> {
+        use parquet::{
+            data_type::{ByteArray, ByteArrayType, Int64Type},
+            file::writer::SerializedColumnWriter,
+        };
+
+        let schema = Arc::new(
+            parse_message_type(
+                "message schema { REQUIRED INT64 id; REQUIRED BYTE_ARRAY name (UTF8); }",
+            )
+            .unwrap(),
+        );
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut bytes = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut bytes, schema, props).unwrap();
+        for ids in row_groups {
+            let mut row_group_writer = writer.next_row_group().unwrap();
+
+            let mut column: SerializedColumnWriter =
+                row_group_writer.next_column().unwrap().unwrap();
+            column
+                .typed::<Int64Type>()
+                .write_batch(ids, None, None)
+                .unwrap();
+            column.close().unwrap();
+
+            let names: Vec<ByteArray> = ids
+                .iter()
+                .map(|i| format!("name{i}").as_str().into())
+                .collect();
+            let mut column = row_group_writer.next_column().unwrap().unwrap();
+            column
+                .typed::<ByteArrayType>()
+                .write_batch(&names, None, None)
+                .unwrap();
+            column.close().unwrap();
+
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+        bytes
+    }
+}
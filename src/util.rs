@@ -55,6 +55,93 @@ pub fn extension_from_file_name(filename: &str) -> String {
         .unwrap_or_default()
 }
 
+/// Matches the pattern as a case-insensitive subsequence of the text.
+///
+/// Returns the score (higher is better) and the char indices of the matched characters.
+/// Consecutive matches and matches at the beginning of words are scored higher.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(usize, Vec<usize>)> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let text: Vec<char> = text.chars().collect();
+    let Some(first) = pattern.first() else {
+        return Some((0, Vec::new()));
+    };
+
+    // try each position of the first character as the start and take the best one
+    (0..text.len())
+        .filter(|&start| eq_ignore_case(text[start], *first))
+        .filter_map(|start| fuzzy_match_from(&pattern, &text, start))
+        .fold(None, |best: Option<(usize, Vec<usize>)>, m| match best {
+            Some(best) if best.0 >= m.0 => Some(best),
+            _ => Some(m),
+        })
+}
+
+fn fuzzy_match_from(pattern: &[char], text: &[char], start: usize) -> Option<(usize, Vec<usize>)> {
+    let mut pattern = pattern.iter().peekable();
+    let mut score = 0;
+    let mut indices: Vec<usize> = Vec::new();
+    for (i, c) in text.iter().enumerate().skip(start) {
+        let Some(p) = pattern.peek() else {
+            break;
+        };
+        if eq_ignore_case(*c, **p) {
+            score += 1;
+            if indices.last().is_some_and(|last| last + 1 == i) {
+                score += 4;
+            }
+            if i == 0 || !text[i - 1].is_alphanumeric() {
+                score += 2;
+            }
+            indices.push(i);
+            pattern.next();
+        }
+    }
+    if pattern.peek().is_some() {
+        return None;
+    }
+    Some((score, indices))
+}
+
+fn eq_ignore_case(c: char, lower: char) -> bool {
+    c.to_lowercase().eq(std::iter::once(lower))
+}
+
+/// Matches the whole text with a glob pattern, `*` matches any characters and `?` matches any single character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // position of the last `*` and the text position it is matched up to
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((sp, st)) => {
+                    // let the last `*` match one more character
+                    star = Some((sp, st + 1));
+                    p = sp + 1;
+                    t = st + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -109,4 +196,37 @@ mod tests {
         assert_eq!(extension_from_file_name("a.txt"), "txt");
         assert_eq!(extension_from_file_name("a.gif.txt"), "txt");
     }
+
+    #[rstest]
+    #[case("", "Download object", Some((0, vec![])))]
+    #[case("dl", "Download object", Some((4, vec![0, 4])))]
+    #[case("down", "Download object", Some((18, vec![0, 1, 2, 3])))]
+    #[case("DO", "Download object", Some((8, vec![0, 1])))]
+    #[case("oo", "Download object", Some((4, vec![5, 9])))]
+    #[case("dob", "Download object", Some((9, vec![0, 1, 10])))]
+    #[case("obj", "Download object", Some((13, vec![9, 10, 11])))]
+    #[case("xyz", "Download object", None)]
+    #[case("objects", "Download object", None)]
+    fn test_fuzzy_match(
+        #[case] pattern: &str,
+        #[case] text: &str,
+        #[case] expected: Option<(usize, Vec<usize>)>,
+    ) {
+        assert_eq!(fuzzy_match(pattern, text), expected);
+    }
+
+    #[rstest]
+    #[case("*.csv", "2024-01.csv", true)]
+    #[case("*.csv", "2024-01.csv.gz", false)]
+    #[case("2024-??.csv", "2024-01.csv", true)]
+    #[case("2024-?.csv", "2024-01.csv", false)]
+    #[case("*-*-*", "a-b-c", true)]
+    #[case("*-*-*", "a-b", false)]
+    #[case("a*", "a", true)]
+    #[case("*", "", true)]
+    #[case("", "a", false)]
+    #[case("foo.txt", "foo.txt", true)]
+    fn test_glob_match(#[case] pattern: &str, #[case] text: &str, #[case] expected: bool) {
+        assert_eq!(glob_match(pattern, text), expected);
+    }
 }
@@ -1,10 +1,15 @@
+mod archive_preview;
 mod bar;
+mod bulk_report_dialog;
+mod command_palette;
 mod common;
 mod confirm_dialog;
 mod copy_detail_dialog;
 mod dialog;
+mod diff_preview;
 mod divider;
 mod header;
+mod hex_preview;
 mod image_preview;
 mod input_dialog;
 mod loading_dialog;
@@ -13,14 +18,21 @@ mod scroll_lines;
 mod scroll_list;
 mod sort_list_dialog;
 mod status;
+mod suggestion_list;
+mod table_preview;
 mod text_preview;
 
+pub use archive_preview::{ArchivePreview, ArchivePreviewState};
 pub use bar::Bar;
+pub use bulk_report_dialog::{BulkReportDialog, BulkReportDialogState};
+pub use command_palette::{CommandPalette, CommandPaletteItem, CommandPaletteState};
 pub use confirm_dialog::{ConfirmDialog, ConfirmDialogState};
 pub use copy_detail_dialog::{CopyDetailDialog, CopyDetailDialogState};
 pub use dialog::Dialog;
+pub use diff_preview::{DiffPreview, DiffPreviewState};
 pub use divider::Divider;
 pub use header::Header;
+pub use hex_preview::{align_row, parse_byte_pattern, parse_offset, HexPreview, HexPreviewState};
 pub use image_preview::{ImagePicker, ImagePreview, ImagePreviewState};
 pub use input_dialog::{InputDialog, InputDialogState};
 pub use loading_dialog::LoadingDialog;
@@ -32,4 +44,9 @@ pub use sort_list_dialog::{
     ObjectListSortDialogState, ObjectListSortType,
 };
 pub use status::{Status, StatusType};
-pub use text_preview::{EncodingDialog, EncodingDialogState, TextPreview, TextPreviewState};
+pub use suggestion_list::{SuggestionList, SuggestionListState};
+pub use table_preview::{TablePreview, TablePreviewState};
+pub use text_preview::{
+    decode_text, guess_encoding, is_binary, EncodingDialog, EncodingDialogState, EncodingType,
+    TextPreview, TextPreviewState,
+};
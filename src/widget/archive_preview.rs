@@ -0,0 +1,302 @@
+use std::collections::HashSet;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{ListItem, StatefulWidget},
+};
+
+use crate::{
+    archive::ArchiveEntry,
+    color::ColorTheme,
+    config::UiObjectListConfig,
+    format::{format_datetime, format_size_byte, format_version},
+    widget::{ScrollList, ScrollListState},
+};
+
+const ELLIPSIS: &str = "...";
+
+#[derive(Debug)]
+pub struct ArchivePreviewState {
+    entries: Vec<ArchiveEntry>,
+    marked: HashSet<usize>,
+    pub list_state: ScrollListState,
+}
+
+impl ArchivePreviewState {
+    pub fn new(entries: Vec<ArchiveEntry>) -> ArchivePreviewState {
+        let list_state = ScrollListState::new(entries.len());
+        ArchivePreviewState {
+            entries,
+            marked: HashSet::new(),
+            list_state,
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&ArchiveEntry> {
+        self.entries.get(self.list_state.selected)
+    }
+
+    pub fn toggle_mark(&mut self) {
+        let i = self.list_state.selected;
+        if self.entries.get(i).is_none_or(|e| e.is_dir) {
+            return;
+        }
+        if !self.marked.remove(&i) {
+            self.marked.insert(i);
+        }
+        self.list_state.select_next();
+    }
+
+    /// Returns the marked entries, or the selected entry if nothing is marked.
+    pub fn target_entries(&self) -> Vec<ArchiveEntry> {
+        if self.marked.is_empty() {
+            return self
+                .selected_entry()
+                .filter(|e| !e.is_dir)
+                .cloned()
+                .into_iter()
+                .collect();
+        }
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.marked.contains(i))
+            .map(|(_, e)| e.clone())
+            .collect()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+struct ArchivePreviewColor {
+    selected_bg: Color,
+    selected_fg: Color,
+    marked: Color,
+}
+
+impl ArchivePreviewColor {
+    fn new(theme: &ColorTheme) -> ArchivePreviewColor {
+        ArchivePreviewColor {
+            selected_bg: theme.list_selected_bg,
+            selected_fg: theme.list_selected_fg,
+            marked: theme.list_filter_match,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ArchivePreview<'a> {
+    file_name: &'a str,
+    file_version_id: Option<&'a str>,
+    config: &'a UiObjectListConfig,
+
+    color: ArchivePreviewColor,
+    theme: &'a ColorTheme,
+}
+
+impl<'a> ArchivePreview<'a> {
+    pub fn new(
+        file_name: &'a str,
+        file_version_id: Option<&'a str>,
+        config: &'a UiObjectListConfig,
+        theme: &'a ColorTheme,
+    ) -> Self {
+        Self {
+            file_name,
+            file_version_id,
+            config,
+            color: ArchivePreviewColor::new(theme),
+            theme,
+        }
+    }
+}
+
+impl StatefulWidget for ArchivePreview<'_> {
+    type State = ArchivePreviewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let title = if let Some(version_id) = self.file_version_id {
+            format!(
+                "Preview [{} (Version ID: {})]",
+                self.file_name,
+                format_version(version_id)
+            )
+        } else {
+            format!("Preview [{}]", self.file_name)
+        };
+
+        let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+        let items = state
+            .entries
+            .iter()
+            .enumerate()
+            .skip(state.list_state.offset)
+            .take(show_item_count)
+            .map(|(i, entry)| {
+                let line = build_entry_line(
+                    entry,
+                    state.marked.contains(&i),
+                    area.width,
+                    self.config,
+                    &self.color,
+                );
+                let style = if i == state.list_state.selected {
+                    Style::default()
+                        .bg(self.color.selected_bg)
+                        .fg(self.color.selected_fg)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        ScrollList::new(items)
+            .title(title)
+            .theme(self.theme)
+            .render(area, buf, &mut state.list_state);
+    }
+}
+
+fn build_entry_line<'a>(
+    entry: &'a ArchiveEntry,
+    marked: bool,
+    width: u16,
+    config: &UiObjectListConfig,
+    color: &ArchivePreviewColor,
+) -> Line<'a> {
+    let mark = if marked {
+        "*".fg(color.marked).bold()
+    } else {
+        " ".into()
+    };
+    let date = entry
+        .last_modified
+        .map(|t| format_datetime(&t, &config.date_format))
+        .unwrap_or_default();
+    let size = if entry.is_dir {
+        String::new()
+    } else {
+        format_size_byte(entry.size_byte)
+    };
+    let date_w: usize = config.date_width;
+    let size_w: usize = 10;
+    let path_w: usize = (width as usize).saturating_sub(
+        date_w + size_w + 11 /* spaces and mark */ + 4, /* border + pad */
+    );
+
+    let pad_path = console::pad_str(
+        &entry.path,
+        path_w,
+        console::Alignment::Left,
+        Some(ELLIPSIS),
+    )
+    .to_string();
+    let pad_date = console::pad_str(&date, date_w, console::Alignment::Left, None).to_string();
+    let pad_size = console::pad_str(&size, size_w, console::Alignment::Right, None).to_string();
+
+    let path = if entry.is_dir {
+        pad_path.bold()
+    } else {
+        pad_path.into()
+    };
+    Line::from(vec![
+        mark,
+        " ".into(),
+        path,
+        "    ".into(),
+        pad_date.into(),
+        "    ".into(),
+        pad_size.into(),
+        " ".into(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, NaiveDateTime};
+    use ratatui::{backend::TestBackend, style::Modifier, Terminal};
+
+    use crate::{archive::EntryLocation, set_cells};
+
+    use super::*;
+
+    #[test]
+    fn test_render_archive_preview() -> std::io::Result<()> {
+        let theme = ColorTheme::default();
+        let config = UiObjectListConfig::default();
+        let mut state = ArchivePreviewState::new(vec![
+            entry("dir/", 0, true),
+            entry("dir/a.txt", 1024, false),
+            entry("dir/b.txt", 2048, false),
+        ]);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 6))?;
+        let mut render = |state: &mut ArchivePreviewState| {
+            terminal
+                .draw(|f| {
+                    let preview = ArchivePreview::new("archive.zip", None, &config, &theme);
+                    f.render_stateful_widget(preview, f.area(), state);
+                })
+                .map(|_| ())
+        };
+        render(&mut state)?;
+        state.list_state.select_next();
+        state.toggle_mark();
+        render(&mut state)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [archive.zip]────────────────────────────── 3 / 3 ┐",
+            "│   dir/                2024-01-02 13:01:02                │",
+            "│ * dir/a.txt           2024-01-02 13:01:02         1 KiB  │",
+            "│   dir/b.txt           2024-01-02 13:01:02         2 KiB  │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // directory
+            (4..20, [1]) => modifier: Modifier::BOLD,
+            // mark
+            ([2], [2]) => fg: Color::Red, modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [3]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        assert_eq!(
+            state
+                .target_entries()
+                .iter()
+                .map(|e| e.path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["dir/a.txt"]
+        );
+
+        Ok(())
+    }
+
+    fn entry(path: &str, size_byte: usize, is_dir: bool) -> ArchiveEntry {
+        ArchiveEntry {
+            path: path.to_string(),
+            size_byte,
+            last_modified: Some(parse_datetime("2024-01-02 13:01:02")),
+            is_dir,
+            location: EntryLocation::Tar { offset: 0 },
+        }
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+}
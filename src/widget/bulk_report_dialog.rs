@@ -0,0 +1,254 @@
+use itsuki::zero_indexed_enum;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    color::ColorTheme,
+    object::BulkReport,
+    widget::{common::calc_centered_dialog_rect, Dialog, Divider},
+};
+
+const MAX_FAILED_LINES: usize = 5;
+
+#[derive(Default)]
+#[zero_indexed_enum]
+enum ActionType {
+    #[default]
+    Retry,
+    SaveReport,
+    Close,
+}
+
+#[derive(Debug, Default)]
+pub struct BulkReportDialogState {
+    selected: ActionType,
+}
+
+impl BulkReportDialogState {
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.prev();
+    }
+
+    pub fn is_retry(&self) -> bool {
+        self.selected == ActionType::Retry
+    }
+
+    pub fn is_save_report(&self) -> bool {
+        self.selected == ActionType::SaveReport
+    }
+}
+
+#[derive(Debug, Default)]
+struct BulkReportDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    error: Color,
+    selected: Color,
+    divider: Color,
+}
+
+impl BulkReportDialogColor {
+    fn new(theme: &ColorTheme) -> BulkReportDialogColor {
+        BulkReportDialogColor {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            error: theme.status_error,
+            selected: theme.dialog_selected,
+            divider: theme.divider,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkReportDialog<'a> {
+    description: String,
+    report: &'a BulkReport,
+
+    color: BulkReportDialogColor,
+}
+
+impl<'a> BulkReportDialog<'a> {
+    pub fn new(description: String, report: &'a BulkReport) -> BulkReportDialog<'a> {
+        BulkReportDialog {
+            description,
+            report,
+            color: BulkReportDialogColor::default(),
+        }
+    }
+
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = BulkReportDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for BulkReportDialog<'_> {
+    type State = BulkReportDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = 70;
+
+        let mut lines = build_summary_lines(&self.description, self.report, &self.color);
+        lines.extend(build_divider_lines(&self.color, dialog_width));
+        lines.extend(build_select_lines(state, &self.color));
+
+        let dialog_height = lines.len() as u16 + 2 /* border */;
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Summary");
+        let content = Paragraph::new(lines).centered().block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .padding(Padding::horizontal(1))
+                .bg(self.color.bg)
+                .fg(self.color.block),
+        );
+
+        let dialog = Dialog::new(Box::new(content), self.color.bg);
+        dialog.render_ref(dialog_area, buf);
+    }
+}
+
+fn build_summary_lines<'a>(
+    description: &str,
+    report: &'a BulkReport,
+    color: &BulkReportDialogColor,
+) -> Vec<Line<'a>> {
+    let mut lines = vec![
+        Line::from(description.to_string().bold()),
+        Line::from(""),
+        Line::from(format!(
+            "Succeeded: {}    Failed: {}    Skipped: {}",
+            report.succeeded_count,
+            report.failed.len(),
+            report.skipped.len()
+        )),
+    ];
+    if report.credentials_expired {
+        lines.push(Line::from(
+            "Stopped because the credentials have expired".fg(color.error),
+        ));
+    }
+    if !report.failed.is_empty() {
+        lines.push(Line::from(""));
+        for failed in report.failed.iter().take(MAX_FAILED_LINES) {
+            let line = Line::from(vec![
+                failed.obj.key.as_str().fg(color.text),
+                ": ".into(),
+                failed.error.as_str().fg(color.error),
+            ]);
+            lines.push(line.left_aligned());
+        }
+        if report.failed.len() > MAX_FAILED_LINES {
+            let rest = report.failed.len() - MAX_FAILED_LINES;
+            lines.push(Line::from(format!("... and {rest} more")).left_aligned());
+        }
+    }
+    lines
+}
+
+fn build_divider_lines(color: &BulkReportDialogColor, dialog_width: u16) -> Vec<Line<'_>> {
+    let line = Divider::default()
+        .color(color.divider)
+        .to_line(dialog_width - 6);
+    vec![line]
+}
+
+fn build_select_lines<'a>(
+    state: &'a BulkReportDialogState,
+    color: &'a BulkReportDialogColor,
+) -> Vec<Line<'a>> {
+    let item = |label: &'static str, action: ActionType| {
+        if state.selected == action {
+            label.fg(color.selected).bold()
+        } else {
+            label.fg(color.text)
+        }
+    };
+    let line = Line::from(vec![
+        item("Retry failed", ActionType::Retry),
+        "    ".into(),
+        item("Save report", ActionType::SaveReport),
+        "    ".into(),
+        item("Close", ActionType::Close),
+    ]);
+    vec![line]
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+
+    use crate::{
+        object::{DownloadObjectInfo, FailedObject},
+        set_cells,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_render_bulk_report_dialog() {
+        let theme = ColorTheme::default();
+        let report = BulkReport {
+            succeeded_count: 8,
+            succeeded_size_byte: 800,
+            failed: vec![FailedObject {
+                obj: DownloadObjectInfo {
+                    key: "dir/a.txt".to_string(),
+                    size_byte: 100,
+                },
+                error: "request timed out".to_string(),
+            }],
+            skipped: vec![DownloadObjectInfo {
+                key: "dir/b.txt".to_string(),
+                size_byte: 100,
+            }],
+            credentials_expired: false,
+        };
+        let mut state = BulkReportDialogState::default();
+        state.select_next();
+        let dialog =
+            BulkReportDialog::new("Download s3://bucket/dir/".to_string(), &report).theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 74, 10));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "  ╭Summary─────────────────────────────────────────────────────────────╮  ",
+            "  │                      Download s3://bucket/dir/                     │  ",
+            "  │                                                                    │  ",
+            "  │               Succeeded: 8    Failed: 1    Skipped: 1              │  ",
+            "  │                                                                    │  ",
+            "  │ dir/a.txt: request timed out                                       │  ",
+            "  │  ────────────────────────────────────────────────────────────────  │  ",
+            "  │                Retry failed    Save report    Close                │  ",
+            "  ╰────────────────────────────────────────────────────────────────────╯  ",
+            "                                                                          ",
+        ]);
+        set_cells! { expected =>
+            // description is bold
+            (25..50, [1]) => modifier: Modifier::BOLD,
+            // error message
+            (15..32, [5]) => fg: Color::Red,
+            // divider
+            (5..69, [6]) => fg: Color::DarkGray,
+            // selected action
+            (35..46, [7]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
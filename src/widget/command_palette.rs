@@ -0,0 +1,361 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        block::Title, Block, BorderType, List, ListItem, Padding, Paragraph, StatefulWidget,
+        Widget, WidgetRef,
+    },
+};
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+use crate::{
+    color::ColorTheme,
+    keys::UserEvent,
+    util::fuzzy_match,
+    widget::{common::calc_centered_dialog_rect, Dialog},
+};
+
+const MAX_DIALOG_WIDTH: u16 = 80;
+const MAX_LIST_HEIGHT: u16 = 12;
+
+#[derive(Debug, Clone)]
+pub struct CommandPaletteItem {
+    pub event: UserEvent,
+    pub description: String,
+    // bound keys, e.g. `<j> <Down>`
+    pub keys: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CommandPaletteState {
+    items: Vec<CommandPaletteItem>,
+    input: Input,
+    // indices of the matched items, and the char indices of the matched characters in the description
+    matches: Vec<(usize, Vec<usize>)>,
+    selected: usize,
+    offset: usize,
+    cursor: (u16, u16),
+}
+
+impl CommandPaletteState {
+    pub fn new(items: Vec<CommandPaletteItem>) -> Self {
+        let mut state = Self {
+            items,
+            ..Default::default()
+        };
+        state.update_matches();
+        state
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        let event = &ratatui::crossterm::event::Event::Key(key);
+        if self.input.handle_event(event).is_some_and(|c| c.value) {
+            self.update_matches();
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_event(&self) -> Option<UserEvent> {
+        self.matches
+            .get(self.selected)
+            .map(|(i, _)| self.items[*i].event)
+    }
+
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    fn update_matches(&mut self) {
+        let query = self.input.value();
+        let mut matches: Vec<(usize, usize, Vec<usize>)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                fuzzy_match(query, &item.description).map(|(score, indices)| (i, score, indices))
+            })
+            .collect();
+        // stable sort keeps the original order of the items with the same score
+        matches.sort_by(|(_, s1, _), (_, s2, _)| s2.cmp(s1));
+        self.matches = matches
+            .into_iter()
+            .map(|(i, _, indices)| (i, indices))
+            .collect();
+        self.selected = 0;
+        self.offset = 0;
+    }
+}
+
+#[derive(Debug, Default)]
+struct CommandPaletteColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+    matched: Color,
+    keys: Color,
+}
+
+impl CommandPaletteColor {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+            matched: theme.list_filter_match,
+            keys: theme.help_key_fg,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    color: CommandPaletteColor,
+}
+
+impl CommandPalette {
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = CommandPaletteColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for CommandPalette {
+    type State = CommandPaletteState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(MAX_DIALOG_WIDTH);
+        let list_height = area
+            .height
+            .saturating_sub(4 /* border + input */ + 2 /* margin */)
+            .min(MAX_LIST_HEIGHT);
+        let dialog_height = list_height + 4;
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let list_height = list_height as usize;
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + list_height {
+            state.offset = state.selected + 1 - list_height;
+        }
+
+        let content_width = dialog_width.saturating_sub(4) as usize;
+        let list_items: Vec<ListItem> = state
+            .matches
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(list_height)
+            .map(|(i, (item_index, indices))| {
+                let item = &state.items[*item_index];
+                self.build_list_item(item, indices, i == state.selected, content_width)
+            })
+            .collect();
+
+        let title = Title::from("Command Palette");
+        let count = format!(" {} / {} ", state.matches.len(), state.items.len());
+        let content = CommandPaletteContent {
+            input: state.input.value(),
+            list: List::new(list_items),
+            block: Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .title_bottom(Line::from(count).right_aligned())
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+            color: &self.color,
+        };
+        let dialog = Dialog::new(Box::new(content), self.color.bg);
+        dialog.render_ref(dialog_area, buf);
+
+        let input_max_width = content_width.saturating_sub(2 /* prompt */);
+        let cursor_x = dialog_area.x + 4 + state.input.visual_cursor().min(input_max_width) as u16;
+        let cursor_y = dialog_area.y + 1;
+        state.cursor = (cursor_x, cursor_y);
+    }
+}
+
+impl CommandPalette {
+    fn build_list_item<'a>(
+        &self,
+        item: &'a CommandPaletteItem,
+        indices: &[usize],
+        selected: bool,
+        width: usize,
+    ) -> ListItem<'a> {
+        let base_style = if selected {
+            Style::default()
+                .fg(self.color.selected)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(self.color.text)
+        };
+        let mut spans: Vec<Span> = item
+            .description
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if indices.contains(&i) {
+                    Span::styled(c.to_string(), base_style.fg(self.color.matched))
+                } else {
+                    Span::styled(c.to_string(), base_style)
+                }
+            })
+            .collect();
+
+        let description_width = console::measure_text_width(&item.description);
+        let keys_width = console::measure_text_width(&item.keys);
+        let pad = width.saturating_sub(description_width + keys_width);
+        spans.push(Span::raw(" ".repeat(pad)));
+        spans.push(Span::styled(
+            item.keys.as_str(),
+            Style::default().fg(self.color.keys),
+        ));
+        ListItem::new(Line::from(spans))
+    }
+}
+
+struct CommandPaletteContent<'a> {
+    input: &'a str,
+    list: List<'a>,
+    block: Block<'a>,
+    color: &'a CommandPaletteColor,
+}
+
+impl WidgetRef for CommandPaletteContent<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render_ref(area, buf);
+
+        let [input_area, divider_area, list_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .areas(inner);
+
+        // show the last characters of the input if it is too long
+        let input_max_width = (input_area.width as usize).saturating_sub(2);
+        let input_chars = self.input.chars().count();
+        let input_view: String = self
+            .input
+            .chars()
+            .skip(input_chars.saturating_sub(input_max_width))
+            .collect();
+        let input = Line::from(vec!["> ".bold(), input_view.fg(self.color.text)]);
+        Paragraph::new(input).render(input_area, buf);
+
+        let divider = "─".repeat(divider_area.width as usize);
+        Paragraph::new(divider).render(divider_area, buf);
+
+        WidgetRef::render_ref(&self.list, list_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_command_palette() {
+        let theme = ColorTheme::default();
+        let mut state = CommandPaletteState::new(items());
+        let palette = CommandPalette::default().theme(&theme);
+
+        for c in "do".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 44, 10));
+        palette.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                            ",
+            "  ╭Command Palette───────────────────────╮  ",
+            "  │ > do                                 │  ",
+            "  │ ──────────────────────────────────── │  ",
+            "  │ Download object                  <s> │  ",
+            "  │ Download object as               <S> │  ",
+            "  │                                      │  ",
+            "  │                                      │  ",
+            "  ╰─────────────────────────────── 2 / 4 ╯  ",
+            "                                            ",
+        ]);
+        set_cells! { expected =>
+            (4..6, [2]) => modifier: Modifier::BOLD,
+            (4..19, [4]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+            (4..6, [4]) => fg: Color::Red, modifier: Modifier::BOLD,
+            (4..6, [5]) => fg: Color::Red,
+            (37..40, [4]) => fg: Color::Yellow,
+            (37..40, [5]) => fg: Color::Yellow,
+        }
+
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor(), (8, 2));
+    }
+
+    #[test]
+    fn test_command_palette_select() {
+        let mut state = CommandPaletteState::new(items());
+        assert_eq!(
+            state.selected_event(),
+            Some(UserEvent::ObjectListDownloadObject)
+        );
+
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected_event(), Some(UserEvent::ObjectListFilter));
+
+        // the selection is reset when the query is changed
+        for c in "sort".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        assert_eq!(state.selected_event(), Some(UserEvent::ObjectListSort));
+        state.select_next();
+        assert_eq!(state.selected_event(), Some(UserEvent::ObjectListSort));
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        assert_eq!(state.selected_event(), None);
+    }
+
+    fn items() -> Vec<CommandPaletteItem> {
+        let item = |event, description: &str, keys: &str| CommandPaletteItem {
+            event,
+            description: description.into(),
+            keys: keys.into(),
+        };
+        vec![
+            item(
+                UserEvent::ObjectListDownloadObject,
+                "Download object",
+                "<s>",
+            ),
+            item(
+                UserEvent::ObjectListDownloadObjectAs,
+                "Download object as",
+                "<S>",
+            ),
+            item(UserEvent::ObjectListFilter, "Filter object list", "</>"),
+            item(UserEvent::ObjectListSort, "Sort object list", "<o>"),
+        ]
+    }
+}
@@ -0,0 +1,443 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Margin, Rect},
+    style::{Color, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Padding, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    color::ColorTheme,
+    diff::{DiffLine, DiffLineKind, DiffRow, LineDiff},
+    util::digits,
+    widget::ScrollBar,
+};
+
+#[derive(Debug, Default)]
+pub struct DiffPreviewState {
+    diff: LineDiff,
+    side_by_side: bool,
+    rows: Vec<DiffRow>,
+    // indexes of the rows of the hunk headers
+    hunk_rows: Vec<usize>,
+    line_number_digits: usize,
+    max_text_width: usize,
+    offset: usize,
+    h_offset: usize,
+    // number of rows in the view
+    height: usize,
+}
+
+impl DiffPreviewState {
+    pub fn new(old: &str, new: &str, side_by_side: bool) -> DiffPreviewState {
+        let mut state = DiffPreviewState {
+            side_by_side,
+            ..Default::default()
+        };
+        state.set_texts(old, new);
+        state
+    }
+
+    /// Recomputes the diff, keeping the current position as much as possible.
+    pub fn set_texts(&mut self, old: &str, new: &str) {
+        self.diff = LineDiff::new(old, new);
+        let max_number = old.lines().count().max(new.lines().count());
+        self.line_number_digits = digits(max_number);
+        self.max_text_width = self
+            .diff
+            .hunks
+            .iter()
+            .flat_map(|h| &h.lines)
+            .map(|l| l.text.chars().count())
+            .max()
+            .unwrap_or_default();
+        self.update_rows();
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn insertions(&self) -> usize {
+        self.diff.insertions
+    }
+
+    pub fn deletions(&self) -> usize {
+        self.diff.deletions
+    }
+
+    pub fn hunk_count(&self) -> usize {
+        self.hunk_rows.len()
+    }
+
+    /// Returns the index of the hunk at the top of the view.
+    pub fn current_hunk(&self) -> Option<usize> {
+        self.hunk_rows.iter().rposition(|row| *row <= self.offset)
+    }
+
+    /// Switches between the unified view and the side-by-side view, keeping the current hunk.
+    pub fn toggle_side_by_side(&mut self) {
+        let hunk = self.current_hunk();
+        self.side_by_side = !self.side_by_side;
+        self.update_rows();
+        self.offset = hunk.map(|i| self.hunk_rows[i]).unwrap_or_default();
+    }
+
+    pub fn scroll_forward(&mut self) {
+        self.offset = (self.offset + 1).min(self.max_offset());
+    }
+
+    pub fn scroll_backward(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    pub fn scroll_page_forward(&mut self) {
+        self.offset = (self.offset + self.height.max(1)).min(self.max_offset());
+    }
+
+    pub fn scroll_page_backward(&mut self) {
+        self.offset = self.offset.saturating_sub(self.height.max(1));
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    pub fn scroll_to_end(&mut self) {
+        self.offset = self.max_offset();
+    }
+
+    pub fn scroll_right(&mut self) {
+        if self.h_offset < self.max_text_width.saturating_sub(1) {
+            self.h_offset += 1;
+        }
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.h_offset = self.h_offset.saturating_sub(1);
+    }
+
+    /// Moves to the next hunk, returns false if there is no next hunk.
+    pub fn next_hunk(&mut self) -> bool {
+        match self.hunk_rows.iter().find(|row| **row > self.offset) {
+            Some(row) => {
+                self.offset = *row;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves to the previous hunk, returns false if there is no previous hunk.
+    pub fn prev_hunk(&mut self) -> bool {
+        match self.hunk_rows.iter().rfind(|row| **row < self.offset) {
+            Some(row) => {
+                self.offset = *row;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn update_rows(&mut self) {
+        self.rows = if self.side_by_side {
+            self.diff.side_by_side_rows()
+        } else {
+            self.diff.unified_rows()
+        };
+        self.hunk_rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| matches!(row, DiffRow::Hunk(_)))
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    fn max_offset(&self) -> usize {
+        self.rows.len().saturating_sub(1)
+    }
+}
+
+#[derive(Debug, Default)]
+struct DiffPreviewColor {
+    block: Color,
+    bar: Color,
+    line_number: Color,
+    divider: Color,
+    insert: Color,
+    delete: Color,
+    hunk: Color,
+}
+
+impl DiffPreviewColor {
+    fn new(theme: &ColorTheme) -> DiffPreviewColor {
+        DiffPreviewColor {
+            block: theme.fg,
+            bar: theme.fg,
+            line_number: theme.preview_line_number,
+            divider: theme.divider,
+            insert: theme.diff_insert,
+            delete: theme.diff_delete,
+            hunk: theme.diff_hunk,
+        }
+    }
+
+    fn line(&self, kind: DiffLineKind) -> Color {
+        match kind {
+            DiffLineKind::Equal => Color::Reset,
+            DiffLineKind::Insert => self.insert,
+            DiffLineKind::Delete => self.delete,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DiffPreview<'a> {
+    old_name: &'a str,
+    new_name: &'a str,
+
+    color: DiffPreviewColor,
+}
+
+impl<'a> DiffPreview<'a> {
+    pub fn new(old_name: &'a str, new_name: &'a str, theme: &'a ColorTheme) -> Self {
+        Self {
+            old_name,
+            new_name,
+            color: DiffPreviewColor::new(theme),
+        }
+    }
+}
+
+impl StatefulWidget for DiffPreview<'_> {
+    type State = DiffPreviewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.height = area.height.saturating_sub(2 /* border */) as usize;
+
+        let title = format!("Diff [{}] → [{}]", self.old_name, self.new_name);
+        let summary = match state.current_hunk() {
+            Some(hunk) => format!(
+                " {} / {} hunks (+{} -{}) ",
+                hunk + 1,
+                state.hunk_count(),
+                state.insertions(),
+                state.deletions()
+            ),
+            None => format!(" {} hunks ", state.hunk_count()),
+        };
+        let block = Block::bordered()
+            .title(Line::from(title).left_aligned())
+            .title(summary)
+            .title_alignment(Alignment::Right)
+            .padding(Padding::horizontal(1))
+            .fg(self.color.block);
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        if state.diff.is_empty() {
+            Paragraph::new("No differences").render(inner, buf);
+            return;
+        }
+
+        let rows = state.rows.iter().skip(state.offset).take(state.height);
+        if state.side_by_side {
+            let [left, divider, right] = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+            ])
+            .areas(inner);
+            let (old_lines, new_lines): (Vec<Line>, Vec<Line>) = rows
+                .map(|row| match row {
+                    DiffRow::Pair(old, new) => (
+                        build_side_line(old.as_ref(), true, state, &self.color),
+                        build_side_line(new.as_ref(), false, state, &self.color),
+                    ),
+                    DiffRow::Hunk(header) => {
+                        let line = Line::from(header.clone().fg(self.color.hunk));
+                        (line.clone(), line)
+                    }
+                    DiffRow::Line(_) => (Line::default(), Line::default()),
+                })
+                .unzip();
+            let divider_lines = vec![Line::from(" │ ").fg(self.color.divider); old_lines.len()];
+            Paragraph::new(old_lines).render(left, buf);
+            Paragraph::new(divider_lines).render(divider, buf);
+            Paragraph::new(new_lines).render(right, buf);
+        } else {
+            let lines: Vec<Line> = rows
+                .map(|row| match row {
+                    DiffRow::Line(line) => build_unified_line(line, state, &self.color),
+                    DiffRow::Hunk(header) => Line::from(header.clone().fg(self.color.hunk)),
+                    DiffRow::Pair(_, _) => Line::default(),
+                })
+                .collect();
+            Paragraph::new(lines).render(inner, buf);
+        }
+
+        let area = area.inner(Margin::new(2, 1));
+        let scrollbar_area = Rect::new(area.right(), area.top(), 1, area.height);
+        if state.rows.len() > state.height {
+            let scroll_bar = ScrollBar::new(state.rows.len(), state.offset).color(self.color.bar);
+            Widget::render(scroll_bar, scrollbar_area, buf);
+        }
+    }
+}
+
+// e.g. ` 2  3 +text`
+fn build_unified_line(
+    line: &DiffLine,
+    state: &DiffPreviewState,
+    color: &DiffPreviewColor,
+) -> Line<'static> {
+    let digits = state.line_number_digits;
+    let number = |n: Option<usize>| match n {
+        Some(n) => format!("{n:>digits$}"),
+        None => " ".repeat(digits),
+    };
+    let prefix = match line.kind {
+        DiffLineKind::Equal => " ",
+        DiffLineKind::Insert => "+",
+        DiffLineKind::Delete => "-",
+    };
+    let text = line.text.chars().skip(state.h_offset).collect::<String>();
+    Line::from(vec![
+        format!("{} {} ", number(line.old_number), number(line.new_number)).fg(color.line_number),
+        Span::raw(format!("{prefix}{text}")).fg(color.line(line.kind)),
+    ])
+}
+
+// e.g. ` 3 text`
+fn build_side_line(
+    line: Option<&DiffLine>,
+    old: bool,
+    state: &DiffPreviewState,
+    color: &DiffPreviewColor,
+) -> Line<'static> {
+    let Some(line) = line else {
+        return Line::default();
+    };
+    let number = if old {
+        line.old_number
+    } else {
+        line.new_number
+    };
+    let number = number.map(|n| n.to_string()).unwrap_or_default();
+    let text = line.text.chars().skip(state.h_offset).collect::<String>();
+    Line::from(vec![
+        format!("{:>digits$} ", number, digits = state.line_number_digits).fg(color.line_number),
+        Span::raw(text).fg(color.line(line.kind)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_unified() -> std::io::Result<()> {
+        let theme = ColorTheme::default();
+        let mut state = DiffPreviewState::new("a\nb\nc\n", "a\nB\nc\nd\n", false);
+        let mut terminal = Terminal::new(TestBackend::new(44, 9))?;
+
+        render(&mut terminal, &mut state, &theme)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Diff [old] → [new]─── 1 / 1 hunks (+2 -1) ┐",
+            "│ @@ -1,3 +1,4 @@                          │",
+            "│ 1 1  a                                   │",
+            "│ 2   -b                                   │",
+            "│   2 +B                                   │",
+            "│ 3 3  c                                   │",
+            "│   4 +d                                   │",
+            "│                                          │",
+            "└──────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..17, [1]) => fg: Color::Cyan,
+            (2..6, [2, 3, 4, 5, 6]) => fg: Color::DarkGray,
+            (6..8, [3]) => fg: Color::Red,
+            (6..8, [4, 6]) => fg: Color::Green,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_side_by_side() -> std::io::Result<()> {
+        let theme = ColorTheme::default();
+        let mut state = DiffPreviewState::new("a\nb\nc\n", "a\nB\nc\nd\n", true);
+        let mut terminal = Terminal::new(TestBackend::new(44, 9))?;
+
+        render(&mut terminal, &mut state, &theme)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Diff [old] → [new]─── 1 / 1 hunks (+2 -1) ┐",
+            "│ @@ -1,3 +1,4 @@     │ @@ -1,3 +1,4 @@    │",
+            "│ 1 a                 │ 1 a                │",
+            "│ 2 b                 │ 2 B                │",
+            "│ 3 c                 │ 3 c                │",
+            "│                     │ 4 d                │",
+            "│                                          │",
+            "│                                          │",
+            "└──────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..17, [1]) => fg: Color::Cyan,
+            (24..39, [1]) => fg: Color::Cyan,
+            (21..24, 1..6) => fg: Color::DarkGray,
+            (2..4, [2, 3, 4]) => fg: Color::DarkGray,
+            (4..5, [3]) => fg: Color::Red,
+            (24..26, [2, 3, 4, 5]) => fg: Color::DarkGray,
+            (26..27, [3, 5]) => fg: Color::Green,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hunk_navigation() {
+        let old = (1..=20)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = old.replace("2\n", "two\n").replace("19", "nineteen");
+        let mut state = DiffPreviewState::new(&old, &new, false);
+        state.height = 5;
+
+        assert_eq!(state.hunk_count(), 2);
+        assert_eq!(state.current_hunk(), Some(0));
+
+        assert!(state.next_hunk());
+        assert_eq!(state.current_hunk(), Some(1));
+        assert!(!state.next_hunk());
+
+        state.toggle_side_by_side();
+        assert_eq!(state.current_hunk(), Some(1));
+
+        assert!(state.prev_hunk());
+        assert_eq!(state.current_hunk(), Some(0));
+        assert!(!state.prev_hunk());
+    }
+
+    fn render(
+        terminal: &mut Terminal<TestBackend>,
+        state: &mut DiffPreviewState,
+        theme: &ColorTheme,
+    ) -> std::io::Result<()> {
+        terminal.draw(|f| {
+            let preview = DiffPreview::new("old", "new", theme);
+            f.render_stateful_widget(preview, f.area(), state);
+        })?;
+        Ok(())
+    }
+}
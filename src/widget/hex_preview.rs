@@ -0,0 +1,506 @@
+use std::{ops::Range, sync::Arc};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Margin, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Padding, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    color::ColorTheme,
+    error::{AppError, Result},
+    format::format_version,
+    object::RawObject,
+    widget::ScrollBar,
+};
+
+const BYTES_PER_ROW: usize = 16;
+const MIN_OFFSET_DIGITS: usize = 8;
+
+#[derive(Debug)]
+pub struct HexPreviewState {
+    object: Arc<RawObject>,
+    // offset of the first loaded byte in the object
+    start: usize,
+    // size of the whole object
+    size: usize,
+    // offset of the first byte of the top row, always a multiple of BYTES_PER_ROW
+    top: usize,
+    // number of rows in the view
+    height: usize,
+    // offsets of the last match of the search
+    matched: Option<Range<usize>>,
+    // offset to jump to after the next bytes are loaded
+    pending_jump: Option<usize>,
+    search_input: String,
+}
+
+impl HexPreviewState {
+    pub fn new(object: Arc<RawObject>, start: usize, size: usize) -> HexPreviewState {
+        HexPreviewState {
+            object,
+            start,
+            size,
+            top: align_row(start),
+            height: 0,
+            matched: None,
+            pending_jump: None,
+            search_input: String::new(),
+        }
+    }
+
+    /// Replaces the loaded bytes, keeping the current position if it is still loaded.
+    pub fn set_object(&mut self, object: Arc<RawObject>, start: usize) {
+        self.object = object;
+        self.start = start;
+        self.top = self.top.clamp(self.first_row(), self.max_top());
+        if let Some(offset) = self.pending_jump.take() {
+            self.jump_to(offset);
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn search_input(&self) -> &str {
+        &self.search_input
+    }
+
+    pub fn scroll_forward(&mut self) {
+        self.top = (self.top + BYTES_PER_ROW).min(self.max_top());
+    }
+
+    pub fn scroll_backward(&mut self) {
+        self.top = self.top.saturating_sub(BYTES_PER_ROW).max(self.first_row());
+    }
+
+    pub fn scroll_page_forward(&mut self) {
+        self.top = (self.top + self.page_size()).min(self.max_top());
+    }
+
+    pub fn scroll_page_backward(&mut self) {
+        self.top = self
+            .top
+            .saturating_sub(self.page_size())
+            .max(self.first_row());
+    }
+
+    pub fn is_at_top(&self) -> bool {
+        self.top <= self.first_row()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.top >= self.max_top()
+    }
+
+    /// Scrolls to show the row that contains `offset`, or returns false if the offset is not loaded.
+    pub fn jump_to(&mut self, offset: usize) -> bool {
+        if !(self.start..self.end()).contains(&offset) {
+            return false;
+        }
+        let row = align_row(offset);
+        if row < self.top || row >= self.top + self.page_size() {
+            self.top = row.min(self.max_top());
+        }
+        true
+    }
+
+    /// Jumps to `offset` after the next bytes are loaded.
+    pub fn set_pending_jump(&mut self, offset: usize) {
+        self.pending_jump = Some(offset);
+    }
+
+    /// Finds the pattern in the loaded bytes after (or before) the last match, wrapping around at the edge.
+    pub fn find(&mut self, input: &str, pattern: &[u8], forward: bool) -> Option<usize> {
+        self.search_input = input.into();
+
+        let bytes = &self.object.bytes;
+        let current = match &self.matched {
+            Some(m) if m.start >= self.start => Some(m.start - self.start),
+            _ => None,
+        };
+        let top = self.top.saturating_sub(self.start);
+        let found = if forward {
+            let from = current.map_or(top, |i| i + 1).min(bytes.len());
+            let wrapped_end = (from + pattern.len()).saturating_sub(1).min(bytes.len());
+            find_bytes(&bytes[from..], pattern)
+                .map(|i| from + i)
+                .or_else(|| find_bytes(&bytes[..wrapped_end], pattern))
+        } else {
+            let to = current.unwrap_or(top).min(bytes.len());
+            let end = (to + pattern.len()).saturating_sub(1).min(bytes.len());
+            rfind_bytes(&bytes[..end], pattern)
+                .or_else(|| rfind_bytes(&bytes[to..], pattern).map(|i| to + i))
+        };
+
+        self.matched = found.map(|i| {
+            let offset = self.start + i;
+            offset..offset + pattern.len()
+        });
+        let offset = self.matched.as_ref()?.start;
+        self.jump_to(offset);
+        Some(offset)
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.object.bytes.len()
+    }
+
+    fn first_row(&self) -> usize {
+        align_row(self.start)
+    }
+
+    fn last_row(&self) -> usize {
+        align_row(self.end().saturating_sub(1)).max(self.first_row())
+    }
+
+    fn max_top(&self) -> usize {
+        let visible_rows = self.height.max(1) - 1;
+        self.last_row()
+            .saturating_sub(visible_rows * BYTES_PER_ROW)
+            .max(self.first_row())
+    }
+
+    fn page_size(&self) -> usize {
+        self.height.max(1) * BYTES_PER_ROW
+    }
+
+    fn byte_at(&self, offset: usize) -> Option<u8> {
+        offset
+            .checked_sub(self.start)
+            .and_then(|i| self.object.bytes.get(i))
+            .copied()
+    }
+
+    fn is_matched(&self, offset: usize) -> bool {
+        self.matched.as_ref().is_some_and(|m| m.contains(&offset))
+    }
+}
+
+#[derive(Debug, Default)]
+struct HexPreviewColor {
+    block: Color,
+    bar: Color,
+    offset: Color,
+}
+
+impl HexPreviewColor {
+    fn new(theme: &ColorTheme) -> HexPreviewColor {
+        HexPreviewColor {
+            block: theme.fg,
+            bar: theme.fg,
+            offset: theme.preview_line_number,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HexPreview<'a> {
+    file_name: &'a str,
+    file_version_id: Option<&'a str>,
+
+    color: HexPreviewColor,
+}
+
+impl<'a> HexPreview<'a> {
+    pub fn new(
+        file_name: &'a str,
+        file_version_id: Option<&'a str>,
+        theme: &'a ColorTheme,
+    ) -> Self {
+        Self {
+            file_name,
+            file_version_id,
+            color: HexPreviewColor::new(theme),
+        }
+    }
+}
+
+impl StatefulWidget for HexPreview<'_> {
+    type State = HexPreviewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.height = area.height.saturating_sub(2 /* border */) as usize;
+        state.top = state.top.clamp(state.first_row(), state.max_top());
+
+        let title = if let Some(version_id) = self.file_version_id {
+            format!(
+                "Preview [{} (Version ID: {})] (hex)",
+                self.file_name,
+                format_version(version_id)
+            )
+        } else {
+            format!("Preview [{}] (hex)", self.file_name)
+        };
+        let digits = offset_digits(state.size);
+        let position = format!(
+            " {:0digits$x} / {:0digits$x} ",
+            state.top,
+            state.size,
+            digits = digits
+        );
+        let block = Block::bordered()
+            .title(Line::from(title).left_aligned())
+            .title(position)
+            .title_alignment(Alignment::Right)
+            .padding(Padding::horizontal(1))
+            .fg(self.color.block);
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let lines: Vec<Line> = (0..state.height)
+            .map(|i| state.top + i * BYTES_PER_ROW)
+            .take_while(|offset| *offset < state.end())
+            .map(|offset| build_row(state, offset, digits, self.color.offset))
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+
+        let rows = (state.last_row() - state.first_row()) / BYTES_PER_ROW + 1;
+        let area = area.inner(Margin::new(2, 1));
+        let scrollbar_area = Rect::new(area.right(), area.top(), 1, area.height);
+        if rows > state.height {
+            let offset = (state.top - state.first_row()) / BYTES_PER_ROW;
+            let scroll_bar = ScrollBar::new(rows, offset).color(self.color.bar);
+            Widget::render(scroll_bar, scrollbar_area, buf);
+        }
+    }
+}
+
+// e.g. `00000010  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 00  |Hello, world!...|`
+fn build_row(
+    state: &HexPreviewState,
+    row: usize,
+    digits: usize,
+    offset_color: Color,
+) -> Line<'static> {
+    let mut spans = vec![
+        format!("{:0digits$x}", row, digits = digits).fg(offset_color),
+        Span::raw("  "),
+    ];
+    let mut ascii = Vec::with_capacity(BYTES_PER_ROW);
+    for offset in row..row + BYTES_PER_ROW {
+        if offset == row + BYTES_PER_ROW / 2 {
+            spans.push(Span::raw(" "));
+        }
+        let style = if state.is_matched(offset) {
+            Style::default().reversed()
+        } else {
+            Style::default()
+        };
+        match state.byte_at(offset) {
+            Some(b) => {
+                let hex_style = if b == 0 { style.dim() } else { style };
+                spans.push(Span::styled(format!("{b:02x}"), hex_style));
+                spans.push(Span::raw(" "));
+                ascii.push(Span::styled(printable_char(b).to_string(), style));
+            }
+            None => {
+                spans.push(Span::raw("   "));
+                ascii.push(Span::raw(" "));
+            }
+        }
+    }
+    spans.push(Span::raw(" |"));
+    spans.extend(ascii);
+    spans.push(Span::raw("|"));
+    Line::from(spans)
+}
+
+fn printable_char(b: u8) -> char {
+    if b.is_ascii_graphic() || b == b' ' {
+        b as char
+    } else {
+        '.'
+    }
+}
+
+fn offset_digits(size: usize) -> usize {
+    format!("{:x}", size).len().max(MIN_OFFSET_DIGITS)
+}
+
+pub fn align_row(offset: usize) -> usize {
+    offset - offset % BYTES_PER_ROW
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return None;
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return None;
+    }
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Parses an offset in decimal, or in hexadecimal with the `0x` prefix.
+pub fn parse_offset(s: &str) -> Result<usize> {
+    let s = s.trim();
+    let result = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    result.map_err(|e| AppError::new(format!("Invalid offset: {s}"), e))
+}
+
+/// Parses a byte pattern, either pairs of hex digits like `de ad be ef` and `0xcafebabe`, or a quoted text like `"PK"`.
+pub fn parse_byte_pattern(s: &str) -> Result<Vec<u8>> {
+    let s = s.trim();
+    if let Some(text) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        if text.is_empty() {
+            return Err(AppError::msg("Byte pattern is empty"));
+        }
+        return Ok(text.as_bytes().to_vec());
+    }
+    let digits: String = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if digits.is_empty()
+        || !digits.len().is_multiple_of(2)
+        || !digits.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return Err(AppError::msg(format!("Invalid byte pattern: {s}")));
+    }
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+        .collect();
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, style::Modifier, Terminal};
+    use rstest::rstest;
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_hex_preview() -> std::io::Result<()> {
+        let theme = ColorTheme::default();
+        let bytes = b"Hello, world!\n\x00\x01PK\x03\x04 end".to_vec();
+        let object = Arc::new(RawObject { bytes });
+        let mut state = HexPreviewState::new(object, 0, 25);
+
+        let mut terminal = Terminal::new(TestBackend::new(82, 4))?;
+        render(&mut terminal, &mut state, &theme)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.bin] (hex)─────────────────────────────────── 00000000 / 00000019 ┐",
+            "│ 00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...| │",
+            "│ 00000010  50 4b 03 04 20 65 6e 64                           |PK.. end        | │",
+            "└────────────────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // offset
+            (2..10, [1, 2]) => fg: Color::DarkGray,
+            // NUL byte
+            (55..57, [1]) => modifier: Modifier::DIM,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        assert_eq!(state.find("\"PK\"", b"PK", true), Some(16));
+        render(&mut terminal, &mut state, &theme)?;
+
+        set_cells! { expected =>
+            // matched bytes
+            (12..14, [2]) => modifier: Modifier::REVERSED,
+            (15..17, [2]) => modifier: Modifier::REVERSED,
+            (63..65, [2]) => modifier: Modifier::REVERSED,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scroll_and_jump() {
+        let object = Arc::new(RawObject {
+            bytes: vec![0; BYTES_PER_ROW * 10],
+        });
+        // the rows from 0x100 to 0x190 are loaded
+        let mut state = HexPreviewState::new(object, 0x100, 0x1000);
+        state.height = 4;
+
+        assert!(state.is_at_top());
+        state.scroll_page_forward();
+        assert_eq!(state.top, 0x140);
+        state.scroll_page_forward();
+        assert_eq!(state.top, 0x160);
+        assert!(state.is_at_end());
+
+        assert!(state.jump_to(0x105));
+        assert_eq!(state.top, 0x100);
+        assert!(!state.jump_to(0x1a0));
+
+        state.set_pending_jump(0x1a5);
+        let object = Arc::new(RawObject {
+            bytes: vec![0; BYTES_PER_ROW * 10],
+        });
+        state.set_object(object, 0x1a0);
+        assert_eq!(state.top, 0x1a0);
+    }
+
+    #[test]
+    fn test_find_wraps_around() {
+        let object = Arc::new(RawObject {
+            bytes: b"abcabcabc".to_vec(),
+        });
+        let mut state = HexPreviewState::new(object, 0, 9);
+
+        assert_eq!(state.find("abc", b"abc", true), Some(0));
+        assert_eq!(state.find("abc", b"abc", true), Some(3));
+        assert_eq!(state.find("abc", b"abc", true), Some(6));
+        assert_eq!(state.find("abc", b"abc", true), Some(0));
+        assert_eq!(state.find("abc", b"abc", false), Some(6));
+        assert_eq!(state.find("abc", b"abc", false), Some(3));
+        assert_eq!(state.find("xyz", b"xyz", true), None);
+    }
+
+    #[rstest]
+    #[case("1024", Some(1024))]
+    #[case("0x1f", Some(31))]
+    #[case(" 0XFF ", Some(255))]
+    #[case("abc", None)]
+    #[case("-1", None)]
+    fn test_parse_offset(#[case] s: &str, #[case] expected: Option<usize>) {
+        assert_eq!(parse_offset(s).ok(), expected);
+    }
+
+    #[rstest]
+    #[case("de ad be ef", Some(vec![0xde, 0xad, 0xbe, 0xef]))]
+    #[case("0xCAFEBABE", Some(vec![0xca, 0xfe, 0xba, 0xbe]))]
+    #[case("\"PK\"", Some(b"PK".to_vec()))]
+    #[case("abc", None)]
+    #[case("zz", None)]
+    #[case("\"\"", None)]
+    #[case("", None)]
+    fn test_parse_byte_pattern(#[case] s: &str, #[case] expected: Option<Vec<u8>>) {
+        assert_eq!(parse_byte_pattern(s).ok(), expected);
+    }
+
+    fn render(
+        terminal: &mut Terminal<TestBackend>,
+        state: &mut HexPreviewState,
+        theme: &ColorTheme,
+    ) -> std::io::Result<()> {
+        terminal.draw(|f| {
+            let preview = HexPreview::new("file.bin", None, theme);
+            f.render_stateful_widget(preview, f.area(), state);
+        })?;
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
 pub struct InputDialogState {
     input: Input,
     cursor: (u16, u16),
+    dialog_area: Rect,
 }
 
 impl InputDialogState {
@@ -47,6 +48,10 @@ impl InputDialogState {
         self.cursor
     }
 
+    pub fn dialog_area(&self) -> Rect {
+        self.dialog_area
+    }
+
     pub fn handle_key_event(&mut self, key: KeyEvent) {
         let event = &ratatui::crossterm::event::Event::Key(key);
         self.input.handle_event(event);
@@ -126,6 +131,7 @@ impl StatefulWidget for InputDialog {
         let cursor_x = dialog_area.x + state.input.visual_cursor().min(input_max_width) as u16 + 2;
         let cursor_y = dialog_area.y + 1;
         state.cursor = (cursor_x, cursor_y);
+        state.dialog_area = dialog_area;
     }
 }
 
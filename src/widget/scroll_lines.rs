@@ -1,12 +1,15 @@
+use std::ops::Range;
+
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::{block::BlockExt, Block, Borders, Padding, Paragraph, StatefulWidget, Widget, Wrap},
 };
+use unicode_width::UnicodeWidthStr;
 
-use crate::{color::ColorTheme, util::digits};
+use crate::{color::ColorTheme, search::SearchMatch, util::digits};
 
 #[derive(Debug, Default)]
 enum ScrollEvent {
@@ -20,6 +23,7 @@ enum ScrollEvent {
     End,
     Right,
     Left,
+    Reveal(usize, Range<usize>),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -43,12 +47,17 @@ impl Default for ScrollLinesOptions {
 #[derive(Debug, Default)]
 pub struct ScrollLinesState {
     lines: Vec<Line<'static>>,
+    // set only when the line numbers are not sequential, e.g. some lines are folded
+    line_numbers: Option<Vec<usize>>,
     max_digits: usize,
     max_line_width: usize,
     v_offset: usize,
     h_offset: usize,
     options: ScrollLinesOptions,
     scroll_event: ScrollEvent,
+    // ordered by their position
+    matches: Vec<SearchMatch>,
+    current_match: Option<usize>,
 }
 
 impl ScrollLinesState {
@@ -65,6 +74,12 @@ impl ScrollLinesState {
         }
     }
 
+    pub fn with_line_numbers(mut self, line_numbers: Vec<usize>) -> Self {
+        self.max_digits = digits(line_numbers.iter().max().copied().unwrap_or_default());
+        self.line_numbers = Some(line_numbers);
+        self
+    }
+
     pub fn scroll_forward(&mut self) {
         self.scroll_event = ScrollEvent::Forward;
     }
@@ -106,15 +121,51 @@ impl ScrollLinesState {
         self.options.number = !self.options.number;
     }
 
+    /// Scrolls to make the byte range of the line visible if it is not.
+    pub fn reveal(&mut self, line: usize, range: Range<usize>) {
+        self.scroll_event = ScrollEvent::Reveal(line, range);
+    }
+
+    pub fn lines(&self) -> &[Line<'static>] {
+        &self.lines
+    }
+
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    pub fn current_match(&self) -> Option<usize> {
+        self.current_match
+    }
+
+    pub fn set_matches(&mut self, matches: Vec<SearchMatch>, current_match: Option<usize>) {
+        self.matches = matches;
+        self.current_match = current_match;
+    }
+
+    pub fn set_current_match(&mut self, current_match: Option<usize>) {
+        self.current_match = current_match;
+    }
+
     pub fn current_options(&self) -> ScrollLinesOptions {
         self.options
     }
+
+    pub fn v_offset(&self) -> usize {
+        self.v_offset
+    }
+
+    pub fn set_v_offset(&mut self, offset: usize) {
+        self.v_offset = offset.min(self.lines.len().saturating_sub(1));
+    }
 }
 
 #[derive(Debug, Default)]
 struct ScrollLinesColor {
     block: Color,
     line_number: Color,
+    search_match: Style,
+    search_current_match: Style,
 }
 
 impl ScrollLinesColor {
@@ -122,6 +173,12 @@ impl ScrollLinesColor {
         Self {
             block: theme.fg,
             line_number: theme.preview_line_number,
+            search_match: Style::default()
+                .bg(theme.preview_search_match_bg)
+                .fg(theme.preview_search_match_fg),
+            search_current_match: Style::default()
+                .bg(theme.preview_search_current_match_bg)
+                .fg(theme.preview_search_current_match_fg),
         }
     }
 }
@@ -173,7 +230,7 @@ impl StatefulWidget for ScrollLines {
             show_lines_count,
             self.color.line_number,
         );
-        let lines_paragraph = build_lines_paragraph(state, show_lines_count, self.color.block);
+        let lines_paragraph = build_lines_paragraph(state, show_lines_count, &self.color);
 
         self.block.map(|b| b.fg(self.color.block)).render(area, buf);
         line_numbers_paragraph.render(chunks[0], buf);
@@ -196,12 +253,16 @@ fn build_line_numbers_paragraph(
         state.options.wrap,
     );
     let lines_count = state.lines.len();
-    let line_numbers_content: Vec<Line> = ((state.v_offset + 1)..)
+    let line_numbers_content: Vec<Line> = (state.v_offset..)
         .zip(line_heights)
-        .flat_map(|(line, line_height)| {
-            if line > lines_count {
+        .flat_map(|(i, line_height)| {
+            if i >= lines_count {
                 vec![Line::raw("")]
             } else {
+                let line = match &state.line_numbers {
+                    Some(numbers) => numbers[i],
+                    None => i + 1,
+                };
                 let line_number = format!("{:>width$}", line, width = state.max_digits);
                 let number_line: Line = line_number.fg(line_number_color).into();
                 let empty_lines = (0..(line_height - 1)).map(|_| Line::raw(""));
@@ -218,24 +279,47 @@ fn build_line_numbers_paragraph(
     )
 }
 
-fn build_lines_paragraph(
-    state: &ScrollLinesState,
+fn build_lines_paragraph<'a>(
+    state: &'a ScrollLinesState,
     show_lines_count: usize,
-    block_color: Color,
-) -> Paragraph<'_> {
+    color: &ScrollLinesColor,
+) -> Paragraph<'a> {
+    // only the matches in the visible lines are highlighted
+    let first_match = state.matches.partition_point(|m| m.line < state.v_offset);
     let lines_content: Vec<Line> = state
         .lines
         .iter()
+        .enumerate()
         .skip(state.v_offset)
         .take(show_lines_count)
-        .cloned()
+        .map(|(i, line)| {
+            let highlights: Vec<(Range<usize>, Style)> = state.matches[first_match..]
+                .iter()
+                .enumerate()
+                .take_while(|(_, m)| m.line <= i)
+                .filter(|(_, m)| m.line == i)
+                .map(|(j, m)| {
+                    let style = if state.current_match == Some(first_match + j) {
+                        color.search_current_match
+                    } else {
+                        color.search_match
+                    };
+                    (m.range.clone(), style)
+                })
+                .collect();
+            if highlights.is_empty() {
+                line.clone()
+            } else {
+                highlight_line(line, &highlights)
+            }
+        })
         .collect();
 
     let lines_paragraph = Paragraph::new(lines_content).block(
         Block::default()
             .borders(Borders::NONE)
             .padding(Padding::horizontal(1))
-            .fg(block_color),
+            .fg(color.block),
     );
 
     if state.options.wrap {
@@ -328,6 +412,35 @@ fn handle_scroll_events(state: &mut ScrollLinesState, width: usize, height: usiz
                 state.h_offset = state.h_offset.saturating_sub(1);
             }
         }
+        ScrollEvent::Reveal(line, ref range) => {
+            let visible_lines_count = wrapped_line_width_iter(
+                &state.lines,
+                state.v_offset,
+                width,
+                height,
+                state.options.wrap,
+            )
+            .scan(0, |total_h, h| {
+                *total_h += h;
+                (*total_h <= height).then_some(())
+            })
+            .count()
+            .max(1);
+            if line < state.v_offset || line >= state.v_offset + visible_lines_count {
+                state.v_offset = line.min(state.lines.len().saturating_sub(1));
+            }
+            if !state.options.wrap {
+                if let Some(content) = state.lines.get(line).map(line_content) {
+                    let start = content.get(..range.start).map_or(0, |s| s.width());
+                    let end = content.get(..range.end).map_or(start, |s| s.width());
+                    if start < state.h_offset {
+                        state.h_offset = start;
+                    } else if end > state.h_offset + width {
+                        state.h_offset = (end - width).min(start);
+                    }
+                }
+            }
+        }
     }
     // reset the scroll event
     state.scroll_event = ScrollEvent::None;
@@ -374,6 +487,48 @@ fn wrapped_reversed_line_width_iter<'a>(
         })
 }
 
+/// Returns the text of the line, the byte offsets in it correspond to the spans of the line.
+pub fn line_content(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+// splits the spans of the line at the boundaries of the ranges and applies the styles
+fn highlight_line(line: &Line<'static>, highlights: &[(Range<usize>, Style)]) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut span_start = 0;
+    for span in &line.spans {
+        let content = span.content.as_ref();
+        let span_end = span_start + content.len();
+        let mut pos = span_start;
+        for (range, style) in highlights {
+            let start = range.start.max(pos);
+            let end = range.end.min(span_end);
+            if start >= end
+                || !content.is_char_boundary(start - span_start)
+                || !content.is_char_boundary(end - span_start)
+            {
+                continue;
+            }
+            if pos < start {
+                let s = &content[pos - span_start..start - span_start];
+                spans.push(Span::styled(s.to_string(), span.style));
+            }
+            let s = &content[start - span_start..end - span_start];
+            spans.push(Span::styled(s.to_string(), span.style.patch(*style)));
+            pos = end;
+        }
+        if pos < span_end {
+            let s = &content[pos - span_start..];
+            spans.push(Span::styled(s.to_string(), span.style));
+        }
+        span_start = span_end;
+    }
+    Line {
+        spans,
+        ..line.clone()
+    }
+}
+
 fn line_to_string(line: &Line) -> String {
     line.styled_graphemes(Style::default())
         .map(|g| g.symbol)
@@ -711,6 +866,82 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_scroll_lines_search_matches() {
+        let mut state = state(true, true);
+        let matches = [(0, 8..11), (1, 8..11), (4, 8..11), (13, 24..27)]
+            .into_iter()
+            .map(|(line, range)| SearchMatch { line, range })
+            .collect();
+        state.set_matches(matches, Some(2));
+        state.reveal(4, 8..11);
+
+        let buf = render_scroll_lines(&mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌TITLE─────────────┐",
+            "│  5 aaa bbb ccc   │",
+            "│    ddd eee       │",
+            "│  6 aaaaaaaa      │",
+            "│    bbbbbbbb      │",
+            "│  7               │",
+            "└──────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2, 3], [1, 3, 5]) => fg: Color::DarkGray,
+            (13..16, [1]) => bg: Color::Red, fg: Color::Black,
+        }
+
+        assert_eq!(buf, expected);
+
+        state.set_current_match(Some(0));
+        state.reveal(0, 8..11);
+
+        let buf = render_scroll_lines(&mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌TITLE─────────────┐",
+            "│  1 aaa bbb ccc   │",
+            "│    ddd           │",
+            "│  2 aaa bbb ccc   │",
+            "│  3 aaa           │",
+            "│  4 aaa bbb       │",
+            "└──────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2, 3], [1, 3, 4, 5]) => fg: Color::DarkGray,
+            (13..16, [1]) => bg: Color::Red, fg: Color::Black,
+            (13..16, [3]) => bg: Color::Yellow, fg: Color::Black,
+        }
+
+        assert_eq!(buf, expected);
+
+        state.toggle_wrap();
+        state.set_current_match(Some(3));
+        state.reveal(13, 24..27);
+
+        let buf = render_scroll_lines(&mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌TITLE─────────────┐",
+            "│ 14 d eee fff ggg │",
+            "│ 15               │",
+            "│ 16               │",
+            "│                  │",
+            "│                  │",
+            "└──────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2, 3], [1, 2, 3]) => fg: Color::DarkGray,
+            (15..18, [1]) => bg: Color::Red, fg: Color::Black,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
     fn state(number: bool, wrap: bool) -> ScrollLinesState {
         let lines: Vec<Line> = [
             "aaa bbb ccc ddd",
@@ -2,6 +2,7 @@ use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Margin, Rect},
     style::{Color, Stylize},
+    text::Line,
     widgets::{Block, List, ListItem, Padding, StatefulWidget, Widget},
 };
 
@@ -130,17 +131,32 @@ impl ScrollListColor {
 #[derive(Debug)]
 pub struct ScrollList<'a> {
     items: Vec<ListItem<'a>>,
+    title: Option<String>,
+    header: Option<Line<'a>>,
     color: ScrollListColor,
 }
 
-impl ScrollList<'_> {
-    pub fn new(items: Vec<ListItem>) -> ScrollList {
+impl<'a> ScrollList<'a> {
+    pub fn new(items: Vec<ListItem<'a>>) -> ScrollList<'a> {
         ScrollList {
             items,
+            title: None,
+            header: None,
             color: Default::default(),
         }
     }
 
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets a line that is fixed at the top of the list.
+    pub fn header(mut self, header: Line<'a>) -> Self {
+        self.header = Some(header);
+        self
+    }
+
     pub fn theme(mut self, theme: &ColorTheme) -> Self {
         self.color = ScrollListColor::new(theme);
         self
@@ -151,20 +167,41 @@ impl StatefulWidget for ScrollList<'_> {
     type State = ScrollListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        state.height = area.height as usize - 2 /* border */;
+        let header_height = if self.header.is_some() { 1 } else { 0 };
+        state.height = area.height as usize - 2 /* border */ - header_height as usize;
 
         let title = format_list_count(state.total, state.selected);
-        let list = List::new(self.items).block(
-            Block::bordered()
-                .title(title)
-                .title_alignment(Alignment::Right)
-                .padding(Padding::horizontal(1))
-                .fg(self.color.block),
+        let mut block = Block::bordered()
+            .title(title)
+            .title_alignment(Alignment::Right)
+            .padding(Padding::horizontal(1))
+            .fg(self.color.block);
+        if let Some(title) = self.title {
+            block = block.title(Line::from(title).left_aligned());
+        }
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        if let Some(header) = self.header {
+            let header_area = Rect::new(inner.x, inner.y, inner.width, header_height);
+            Widget::render(header, header_area, buf);
+        }
+        let list_area = Rect::new(
+            inner.x,
+            inner.y + header_height,
+            inner.width,
+            inner.height.saturating_sub(header_height),
         );
-        Widget::render(list, area, buf);
+        let list = List::new(self.items);
+        Widget::render(list, list_area, buf);
 
         let area = area.inner(Margin::new(2, 1));
-        let scrollbar_area = Rect::new(area.right(), area.top(), 1, area.height);
+        let scrollbar_area = Rect::new(
+            area.right(),
+            area.top() + header_height,
+            1,
+            area.height.saturating_sub(header_height),
+        );
 
         if state.total > (scrollbar_area.height as usize) {
             let scroll_bar = ScrollBar::new(state.total, state.offset).color(self.color.bar);
@@ -184,8 +221,6 @@ fn format_list_count(total_count: usize, selected: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use ratatui::text::Line;
-
     use super::*;
 
     #[test]
@@ -49,6 +49,10 @@ impl BucketListSortDialogState {
         self.selected = BucketListSortType::Default;
     }
 
+    pub fn select(&mut self, sort_type: BucketListSortType) {
+        self.selected = sort_type;
+    }
+
     pub fn selected(&self) -> BucketListSortType {
         self.selected
     }
@@ -131,6 +135,10 @@ impl ObjectListSortDialogState {
         self.selected = ObjectListSortType::Default;
     }
 
+    pub fn select(&mut self, sort_type: ObjectListSortType) {
+        self.selected = sort_type;
+    }
+
     pub fn selected(&self) -> ObjectListSortType {
         self.selected
     }
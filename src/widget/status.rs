@@ -1,6 +1,6 @@
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Stylize},
     text::Line,
     widgets::{Block, Padding, Paragraph, Widget},
@@ -44,6 +44,7 @@ impl StatusColor {
 #[derive(Debug)]
 pub struct Status {
     status_type: StatusType,
+    pending_keys: String,
     color: StatusColor,
 }
 
@@ -51,10 +52,16 @@ impl Status {
     pub fn new(status_type: StatusType) -> Self {
         Status {
             status_type,
+            pending_keys: String::new(),
             color: StatusColor::default(),
         }
     }
 
+    pub fn pending_keys(mut self, pending_keys: impl Into<String>) -> Self {
+        self.pending_keys = pending_keys.into();
+        self
+    }
+
     pub fn theme(mut self, theme: &ColorTheme) -> Self {
         self.color = StatusColor::new(theme);
         self
@@ -64,17 +71,30 @@ impl Status {
 impl Widget for Status {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let pad = Padding::horizontal(2);
-        let msg = self.build_msg(area, pad);
-        let paragraph = Paragraph::new(msg).block(Block::default().padding(pad));
-        paragraph.render(area, buf);
+        let block = Block::default().padding(pad);
+        let inner = block.inner(area);
+
+        // the keys typed so far of a key sequence are shown at the right end
+        let pending_keys_width = self.pending_keys.chars().count() as u16;
+        let [msg_area, pending_keys_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(pending_keys_width)])
+                .spacing(if pending_keys_width > 0 { 1 } else { 0 })
+                .areas(inner);
+
+        let pending_keys = Line::from(self.pending_keys.clone())
+            .fg(self.color.help)
+            .bold();
+        let msg = self.build_msg(msg_area.width as usize);
+        Widget::render(block, area, buf);
+        Paragraph::new(msg).render(msg_area, buf);
+        Paragraph::new(pending_keys).render(pending_keys_area, buf);
     }
 }
 
 impl Status {
-    fn build_msg(self, area: Rect, pad: Padding) -> Line<'static> {
+    fn build_msg(self, max_width: usize) -> Line<'static> {
         match self.status_type {
             StatusType::Help(spans) => {
-                let max_width = (area.width - pad.left - pad.right) as usize;
                 let delimiter = ", ";
                 let spans = prune_spans_to_fit_width(&spans, max_width, delimiter);
                 Line::from(spans).fg(self.color.help)
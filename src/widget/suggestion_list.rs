@@ -0,0 +1,219 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    widgets::{Block, BorderType, List, ListItem, Padding, StatefulWidget, WidgetRef},
+};
+
+use crate::{color::ColorTheme, widget::Dialog};
+
+const MAX_LIST_HEIGHT: u16 = 8;
+const ELLIPSIS: &str = "...";
+
+#[derive(Debug, Default)]
+pub struct SuggestionListState {
+    items: Vec<String>,
+    // `None` means that the input is being edited
+    selected: Option<usize>,
+    offset: usize,
+}
+
+impl SuggestionListState {
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.items = items;
+        self.selected = None;
+        self.offset = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.set_items(Vec::new());
+    }
+
+    pub fn select_next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected = match self.selected {
+            Some(i) if i + 1 < self.items.len() => Some(i + 1),
+            Some(i) => Some(i),
+            None => Some(0),
+        };
+    }
+
+    pub fn select_prev(&mut self) {
+        // going up from the first item returns to the input
+        self.selected = match self.selected {
+            Some(0) | None => None,
+            Some(i) => Some(i - 1),
+        };
+    }
+
+    pub fn selected_item(&self) -> Option<&str> {
+        self.selected.map(|i| self.items[i].as_str())
+    }
+
+    /// Returns the selected item, or the first one if no item is selected.
+    pub fn completion(&self) -> Option<&str> {
+        self.selected_item()
+            .or_else(|| self.items.first().map(String::as_str))
+    }
+}
+
+#[derive(Debug, Default)]
+struct SuggestionListColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+}
+
+impl SuggestionListColor {
+    fn new(theme: &ColorTheme) -> SuggestionListColor {
+        SuggestionListColor {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+        }
+    }
+}
+
+/// Dropdown list of the suggestions shown under the anchor (e.g. the input dialog).
+#[derive(Debug, Default)]
+pub struct SuggestionList {
+    anchor: Rect,
+    color: SuggestionListColor,
+}
+
+impl SuggestionList {
+    pub fn new(anchor: Rect) -> Self {
+        SuggestionList {
+            anchor,
+            ..Default::default()
+        }
+    }
+
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = SuggestionListColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for SuggestionList {
+    type State = SuggestionListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let y = self.anchor.bottom();
+        let list_height = area
+            .bottom()
+            .saturating_sub(y + 2 /* border */)
+            .min(MAX_LIST_HEIGHT)
+            .min(state.items.len() as u16);
+        if list_height == 0 {
+            return;
+        }
+        let list_area = Rect::new(self.anchor.x, y, self.anchor.width, list_height + 2);
+
+        let list_height = list_height as usize;
+        if let Some(selected) = state.selected {
+            if selected < state.offset {
+                state.offset = selected;
+            } else if selected >= state.offset + list_height {
+                state.offset = selected + 1 - list_height;
+            }
+        }
+
+        let content_width = list_area.width.saturating_sub(4) as usize;
+        let list_items: Vec<ListItem> = state
+            .items
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(list_height)
+            .map(|(i, item)| {
+                let item = console::truncate_str(item, content_width, ELLIPSIS).into_owned();
+                let style = if state.selected == Some(i) {
+                    Style::default()
+                        .fg(self.color.selected)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(self.color.text)
+                };
+                ListItem::new(item).style(style)
+            })
+            .collect();
+
+        let list = List::new(list_items).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(list), self.color.bg);
+        dialog.render_ref(list_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_suggestion_list() {
+        let theme = ColorTheme::default();
+        let mut state = SuggestionListState::default();
+        state.set_items(vec![
+            "logs/".into(),
+            "logs-archive/".into(),
+            "lost+found/".into(),
+        ]);
+        state.select_next();
+        state.select_next();
+
+        let anchor = Rect::new(2, 1, 24, 3);
+        let list = SuggestionList::new(anchor).theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 28, 9));
+        list.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                            ",
+            "                            ",
+            "                            ",
+            "                            ",
+            "  ╭──────────────────────╮  ",
+            "  │ logs/                │  ",
+            "  │ logs-archive/        │  ",
+            "  │ lost+found/          │  ",
+            "  ╰──────────────────────╯  ",
+        ]);
+        set_cells! { expected =>
+            (4..24, [6]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_select() {
+        let mut state = SuggestionListState::default();
+        assert_eq!(state.completion(), None);
+
+        state.set_items(vec!["a/".into(), "b/".into()]);
+        assert_eq!(state.selected_item(), None);
+        assert_eq!(state.completion(), Some("a/"));
+
+        state.select_next();
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected_item(), Some("b/"));
+
+        state.select_prev();
+        state.select_prev();
+        assert_eq!(state.selected_item(), None);
+    }
+}
@@ -0,0 +1,320 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{ListItem, StatefulWidget},
+};
+
+use crate::{
+    color::ColorTheme,
+    format::format_version,
+    table::{TableData, TableFormat},
+    widget::{ScrollList, ScrollListState},
+};
+
+const ELLIPSIS: &str = "...";
+const MAX_COLUMN_WIDTH: usize = 40;
+const COLUMN_SEPARATOR: &str = " │ ";
+
+#[derive(Debug)]
+pub struct TableView {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    widths: Vec<usize>,
+    column_offset: usize,
+    pub list_state: ScrollListState,
+}
+
+impl TableView {
+    fn new(header: Vec<String>, rows: Vec<Vec<String>>) -> TableView {
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| row.iter().map(|cell| sanitize(cell)).collect())
+            .collect();
+        let widths = (0..header.len())
+            .map(|i| {
+                rows.iter()
+                    .filter_map(|row| row.get(i))
+                    .chain(Some(&header[i]))
+                    .map(|s| console::measure_text_width(s))
+                    .max()
+                    .unwrap_or_default()
+                    .clamp(1, MAX_COLUMN_WIDTH)
+            })
+            .collect();
+        let list_state = ScrollListState::new(rows.len());
+        TableView {
+            header,
+            rows,
+            widths,
+            column_offset: 0,
+            list_state,
+        }
+    }
+
+    pub fn scroll_right(&mut self) {
+        if self.column_offset + 1 < self.header.len() {
+            self.column_offset += 1;
+        }
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.column_offset = self.column_offset.saturating_sub(1);
+    }
+
+    fn build_line<'a>(&self, cells: impl Iterator<Item = &'a str>) -> String {
+        cells
+            .zip(&self.widths)
+            .skip(self.column_offset)
+            .map(|(cell, w)| console::pad_str(cell, *w, console::Alignment::Left, Some(ELLIPSIS)))
+            .collect::<Vec<_>>()
+            .join(COLUMN_SEPARATOR)
+    }
+}
+
+#[derive(Debug)]
+pub struct TablePreviewState {
+    format: TableFormat,
+    row_count: usize,
+    total_rows: Option<usize>,
+    truncated: bool,
+    data_view: TableView,
+    schema_view: TableView,
+    show_schema: bool,
+}
+
+impl TablePreviewState {
+    pub fn new(data: TableData) -> TablePreviewState {
+        let schema_rows = data
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| vec![(i + 1).to_string(), c.name.clone(), c.data_type.clone()])
+            .collect();
+        let schema_view = TableView::new(
+            vec!["#".into(), "Column".into(), "Type".into()],
+            schema_rows,
+        );
+        let header = data.columns.into_iter().map(|c| c.name).collect();
+        let row_count = data.rows.len();
+        let data_view = TableView::new(header, data.rows);
+        TablePreviewState {
+            format: data.format,
+            row_count,
+            total_rows: data.total_rows,
+            truncated: data.truncated,
+            data_view,
+            schema_view,
+            show_schema: false,
+        }
+    }
+
+    pub fn format(&self) -> TableFormat {
+        self.format
+    }
+
+    pub fn toggle_schema(&mut self) {
+        self.show_schema = !self.show_schema;
+    }
+
+    pub fn current_view_mut(&mut self) -> &mut TableView {
+        if self.show_schema {
+            &mut self.schema_view
+        } else {
+            &mut self.data_view
+        }
+    }
+
+    fn summary(&self) -> String {
+        let format = self.format.name();
+        if self.show_schema {
+            return format!("{format} schema, {} columns", self.schema_view.rows.len());
+        }
+        match self.total_rows {
+            Some(total) if total != self.row_count => {
+                format!("{format}, {} of {} rows", self.row_count, total)
+            }
+            Some(total) => format!("{format}, {total} rows"),
+            None if self.truncated => format!("{format}, first {} rows", self.row_count),
+            None => format!("{format}, {} rows", self.row_count),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TablePreviewColor {
+    selected_bg: Color,
+    selected_fg: Color,
+}
+
+impl TablePreviewColor {
+    fn new(theme: &ColorTheme) -> TablePreviewColor {
+        TablePreviewColor {
+            selected_bg: theme.list_selected_bg,
+            selected_fg: theme.list_selected_fg,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TablePreview<'a> {
+    file_name: &'a str,
+    file_version_id: Option<&'a str>,
+
+    color: TablePreviewColor,
+    theme: &'a ColorTheme,
+}
+
+impl<'a> TablePreview<'a> {
+    pub fn new(
+        file_name: &'a str,
+        file_version_id: Option<&'a str>,
+        theme: &'a ColorTheme,
+    ) -> Self {
+        Self {
+            file_name,
+            file_version_id,
+            color: TablePreviewColor::new(theme),
+            theme,
+        }
+    }
+}
+
+impl StatefulWidget for TablePreview<'_> {
+    type State = TablePreviewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let name = if let Some(version_id) = self.file_version_id {
+            format!(
+                "{} (Version ID: {})",
+                self.file_name,
+                format_version(version_id)
+            )
+        } else {
+            self.file_name.to_string()
+        };
+        let title = format!("Preview [{}] ({})", name, state.summary());
+
+        let view = state.current_view_mut();
+        let show_item_count = (area.height as usize).saturating_sub(3 /* border + header */);
+        let header = view.build_line(view.header.iter().map(String::as_str));
+        let items = view
+            .rows
+            .iter()
+            .enumerate()
+            .skip(view.list_state.offset)
+            .take(show_item_count)
+            .map(|(i, row)| {
+                let line = view.build_line(row.iter().map(String::as_str));
+                let style = if i == view.list_state.selected {
+                    Style::default()
+                        .bg(self.color.selected_bg)
+                        .fg(self.color.selected_fg)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        ScrollList::new(items)
+            .title(title)
+            .header(Line::from(header.bold()))
+            .theme(self.theme)
+            .render(area, buf, &mut view.list_state);
+    }
+}
+
+fn sanitize(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, style::Modifier, Terminal};
+
+    use crate::{set_cells, table::TableColumn};
+
+    use super::*;
+
+    #[test]
+    fn test_render_table_preview() -> std::io::Result<()> {
+        let theme = ColorTheme::default();
+        let mut state = TablePreviewState::new(TableData {
+            format: TableFormat::Csv,
+            columns: vec![column("id", "integer"), column("name", "string")],
+            rows: vec![
+                vec!["1".into(), "foo".into()],
+                vec!["2".into(), "bar\nbaz".into()],
+            ],
+            total_rows: Some(2),
+            truncated: false,
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(50, 6))?;
+        render(&mut terminal, &mut state, &theme)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [data.csv] (CSV, 2 rows)───────── 1 / 2 ┐",
+            "│ id │ name                                      │",
+            "│ 1  │ foo                                       │",
+            "│ 2  │ bar baz                                   │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // header
+            (2..14, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..48, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        state.current_view_mut().scroll_right();
+        state.toggle_schema();
+        state.current_view_mut().scroll_right();
+        render(&mut terminal, &mut state, &theme)?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [data.csv] (CSV schema, 2 columns)1 / 2 ┐",
+            "│ Column │ Type                                  │",
+            "│ id     │ integer                               │",
+            "│ name   │ string                                │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // header
+            (2..18, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..48, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    fn render(
+        terminal: &mut Terminal<TestBackend>,
+        state: &mut TablePreviewState,
+        theme: &ColorTheme,
+    ) -> std::io::Result<()> {
+        terminal.draw(|f| {
+            let preview = TablePreview::new("data.csv", None, theme);
+            f.render_stateful_widget(preview, f.area(), state);
+        })?;
+        Ok(())
+    }
+
+    fn column(name: &str, data_type: &str) -> TableColumn {
+        TableColumn {
+            name: name.into(),
+            data_type: data_type.into(),
+        }
+    }
+}
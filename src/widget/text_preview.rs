@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::{cmp::min, collections::BTreeSet};
 
 use ansi_to_tui::IntoText;
 use chardetng::EncodingDetector;
@@ -23,12 +23,15 @@ use syntect::{
 use crate::{
     color::ColorTheme,
     config::Config,
+    error::AppError,
     format::format_version,
     object::{FileDetail, RawObject},
-    util::extension_from_file_name,
+    pretty::{json_fold_nodes, FoldNode, PrettyFormat},
+    search::{build_search_regex, find_matches, SearchOptions},
+    util::{digits, extension_from_file_name},
     widget::{
-        common::calc_centered_dialog_rect, Dialog, ScrollLines, ScrollLinesOptions,
-        ScrollLinesState,
+        common::calc_centered_dialog_rect, scroll_lines::line_content, Dialog, ScrollLines,
+        ScrollLinesOptions, ScrollLinesState,
     },
 };
 
@@ -379,6 +382,33 @@ impl Widget for EncodingDialog<'_> {
 pub struct TextPreviewState {
     pub scroll_lines_state: ScrollLinesState,
     pub encoding: EncodingType,
+    pretty: bool,
+    // set only when pretty-printed JSON is shown
+    fold: Option<JsonFoldState>,
+    // empty if not searching
+    search_query: String,
+    search_options: SearchOptions,
+    // line at the top of the view when the search started
+    search_origin: usize,
+    // original line index
+    mark: Option<usize>,
+}
+
+#[derive(Debug)]
+struct JsonFoldState {
+    lines: Vec<Line<'static>>,
+    nodes: Vec<FoldItem>,
+    // start lines of the folded nodes
+    folded: BTreeSet<usize>,
+    // original line index of each visible line
+    visible: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct FoldItem {
+    node: FoldNode,
+    // the text of the closing line, e.g. `],`
+    closing: String,
 }
 
 impl TextPreviewState {
@@ -390,22 +420,21 @@ impl TextPreviewState {
         auto_detect_encoding: bool,
         default_encoding: EncodingType,
     ) -> (Self, Option<EncodingType>, Option<String>) {
-        let mut guessed_encoding = None;
-        if auto_detect_encoding {
-            let mut detector = EncodingDetector::new();
-            detector.feed(
-                &object.bytes[..min(ENCODING_GUESS_BYTES_SIZE_LIMIT, object.bytes.len())],
-                true,
-            );
-            let (encoding, ok) = detector.guess_assess(None, true);
-            if ok {
-                guessed_encoding = Some(EncodingType::from(encoding.name()).unwrap());
-            }
-        }
+        let guessed_encoding = if auto_detect_encoding {
+            guess_encoding(&object.bytes)
+        } else {
+            None
+        };
 
         let mut state = Self {
             scroll_lines_state: ScrollLinesState::new(vec![], ScrollLinesOptions::default()),
             encoding: guessed_encoding.unwrap_or(default_encoding),
+            pretty: false,
+            fold: None,
+            search_query: String::new(),
+            search_options: SearchOptions::default(),
+            search_origin: 0,
+            mark: None,
         };
         let warn_msg = state.update_lines(file_detail, object, highlight, highlight_theme_name);
         (state, guessed_encoding, warn_msg)
@@ -415,6 +444,23 @@ impl TextPreviewState {
         self.encoding = encoding;
     }
 
+    pub fn toggle_pretty(
+        &mut self,
+        file_detail: &FileDetail,
+        object: &RawObject,
+        highlight: bool,
+        highlight_theme_name: &str,
+    ) -> Option<String> {
+        if PrettyFormat::from_file_name(&file_detail.name).is_none() {
+            let extension = extension_from_file_name(&file_detail.name);
+            return Some(format!("Formatting is not supported for `.{extension}`"));
+        }
+        self.pretty = !self.pretty;
+        // line indexes are changed by formatting
+        self.mark = None;
+        self.update_lines(file_detail, object, highlight, highlight_theme_name)
+    }
+
     pub fn update_lines(
         &mut self,
         file_detail: &FileDetail,
@@ -423,7 +469,28 @@ impl TextPreviewState {
         highlight_theme_name: &str,
     ) -> Option<String> {
         let mut warn_msg = None;
-        let s = self.to_preview_string(&object.bytes);
+        let mut s = self.to_preview_string(&object.bytes);
+
+        let mut fold_nodes = None;
+        if self.pretty {
+            let format = PrettyFormat::from_file_name(&file_detail.name);
+            match format.map(|f| (f, f.pretty_print(&s))) {
+                Some((format, Ok(pretty))) => {
+                    if format == PrettyFormat::Json {
+                        fold_nodes = Some(json_fold_nodes(&pretty));
+                    }
+                    s = pretty;
+                }
+                Some((format, Err(e))) => {
+                    // If the text cannot be parsed, display the original text
+                    warn_msg = Some(format!("{} as {}", e.msg, format.name()));
+                    self.pretty = false;
+                }
+                None => {
+                    self.pretty = false;
+                }
+            }
+        }
 
         let lines: Vec<Line<'static>> =
             match build_highlighted_lines(&s, &file_detail.name, highlight, highlight_theme_name) {
@@ -431,29 +498,311 @@ impl TextPreviewState {
                 Err(msg) => {
                     // If there is an error, display the original text
                     if let Some(msg) = msg {
-                        warn_msg = Some(msg);
+                        warn_msg.get_or_insert(msg);
                     }
                     s.lines().map(drop_control_chars).map(Line::raw).collect()
                 }
             };
 
-        let options = self.scroll_lines_state.current_options();
-        self.scroll_lines_state = ScrollLinesState::new(lines, options);
+        self.fold = fold_nodes.map(|nodes| {
+            let raw_lines: Vec<&str> = s.lines().collect();
+            let nodes = nodes
+                .into_iter()
+                .map(|node| FoldItem {
+                    node,
+                    closing: raw_lines[node.end].trim().into(),
+                })
+                .collect();
+            JsonFoldState {
+                lines: lines.clone(),
+                nodes,
+                folded: BTreeSet::new(),
+                visible: Vec::new(),
+            }
+        });
+        if self.fold.is_some() {
+            self.rebuild_folded_lines(0);
+        } else {
+            let options = self.scroll_lines_state.current_options();
+            self.scroll_lines_state = ScrollLinesState::new(lines, options);
+            self.update_search_matches(0).ok();
+        }
 
         warn_msg
     }
 
-    fn to_preview_string(&self, bytes: &[u8]) -> String {
-        let encoding: &encoding_rs::Encoding = self.encoding.into();
-        let (s, _, _) = encoding.decode(bytes);
-        // tab is not rendered correctly, so replace it
-        let s = s.replace('\t', "    ");
-        if s.ends_with('\n') {
-            s.trim_end().into()
+    pub fn is_foldable(&self) -> bool {
+        self.fold.is_some()
+    }
+
+    /// Folds the innermost node that contains the line at the top of the view, or unfolds it if it is folded.
+    pub fn toggle_fold(&mut self) {
+        let Some(fold) = &mut self.fold else {
+            return;
+        };
+        let top = self.scroll_lines_state.v_offset();
+        let line = fold.visible.get(top).copied().unwrap_or_default();
+        if !fold.folded.remove(&line) {
+            let node = fold
+                .nodes
+                .iter()
+                .map(|item| item.node)
+                .filter(|n| n.start <= line && line <= n.end)
+                .max_by_key(|n| n.start);
+            if let Some(node) = node {
+                fold.folded.insert(node.start);
+                self.rebuild_folded_lines(node.start);
+                return;
+            }
+        }
+        self.rebuild_folded_lines(line);
+    }
+
+    /// Folds all nodes except the root, or unfolds all nodes if any node is folded.
+    pub fn toggle_fold_all(&mut self) {
+        let Some(fold) = &mut self.fold else {
+            return;
+        };
+        if fold.folded.is_empty() {
+            fold.folded = fold
+                .nodes
+                .iter()
+                .filter(|item| item.node.depth > 0)
+                .map(|item| item.node.start)
+                .collect();
+        } else {
+            fold.folded.clear();
+        }
+        self.rebuild_folded_lines(0);
+    }
+
+    // rebuilds the visible lines and scrolls to the visible line that contains `top_line`
+    fn rebuild_folded_lines(&mut self, top_line: usize) {
+        let Some(fold) = &mut self.fold else {
+            return;
+        };
+        let mut lines = Vec::new();
+        let mut visible = Vec::new();
+        let mut i = 0;
+        while i < fold.lines.len() {
+            let mut line = fold.lines[i].clone();
+            visible.push(i);
+            let folded = fold
+                .folded
+                .contains(&i)
+                .then(|| fold.nodes.iter().find(|item| item.node.start == i))
+                .flatten();
+            match folded {
+                Some(item) => {
+                    line.push_span(" … ".dim());
+                    line.push_span(item.closing.clone());
+                    let items = item.node.items;
+                    let unit = if items == 1 { "item" } else { "items" };
+                    line.push_span(format!(" ({items} {unit})").dim());
+                    i = item.node.end + 1;
+                }
+                None => {
+                    i += 1;
+                }
+            }
+            lines.push(line);
+        }
+
+        let top = visible
+            .iter()
+            .rposition(|l| *l <= top_line)
+            .unwrap_or_default();
+        let line_numbers = visible.iter().map(|l| l + 1).collect();
+        fold.visible = visible;
+
+        let options = self.scroll_lines_state.current_options();
+        self.scroll_lines_state =
+            ScrollLinesState::new(lines, options).with_line_numbers(line_numbers);
+        self.scroll_lines_state.set_v_offset(top);
+        self.update_search_matches(top).ok();
+    }
+
+    /// Returns the number of the lines, including the folded lines.
+    pub fn line_count(&self) -> usize {
+        match &self.fold {
+            Some(fold) => fold.lines.len(),
+            None => self.scroll_lines_state.lines().len(),
+        }
+    }
+
+    /// Scrolls to the line (original line index), or to the folded line that contains it.
+    pub fn go_to_line(&mut self, line: usize) {
+        let top = match &self.fold {
+            Some(fold) => fold
+                .visible
+                .iter()
+                .rposition(|l| *l <= line)
+                .unwrap_or_default(),
+            None => line,
+        };
+        self.scroll_lines_state.set_v_offset(top);
+    }
+
+    /// Sets the mark at the line at the top of the view, returns the original line index.
+    pub fn set_mark(&mut self) -> usize {
+        let line = self.current_line();
+        self.mark = Some(line);
+        line
+    }
+
+    /// Jumps to the mark and sets the mark at the previous position, returns false if no mark is set.
+    pub fn jump_to_mark(&mut self) -> bool {
+        let Some(mark) = self.mark else {
+            return false;
+        };
+        self.mark = Some(self.current_line());
+        self.go_to_line(mark);
+        true
+    }
+
+    fn current_line(&self) -> usize {
+        let top = self.scroll_lines_state.v_offset();
+        match &self.fold {
+            Some(fold) => fold.visible.get(top).copied().unwrap_or_default(),
+            None => top,
+        }
+    }
+
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    pub fn search_options(&self) -> SearchOptions {
+        self.search_options
+    }
+
+    pub fn toggle_search_case_sensitive(&mut self) {
+        self.search_options.case_sensitive = !self.search_options.case_sensitive;
+    }
+
+    pub fn toggle_search_regex(&mut self) {
+        self.search_options.regex = !self.search_options.regex;
+    }
+
+    /// Sets the current position as the origin of the following searches.
+    pub fn start_search(&mut self) {
+        self.search_origin = self.scroll_lines_state.v_offset();
+    }
+
+    /// Searches the query and moves to the first match after the origin, returns the number of matches.
+    pub fn search(&mut self, query: &str) -> Result<usize, AppError> {
+        self.search_query = query.into();
+        let result = self.update_search_matches(self.search_origin);
+        if self.scroll_lines_state.current_match().is_some() {
+            self.reveal_current_match();
         } else {
-            s
+            self.scroll_lines_state.set_v_offset(self.search_origin);
         }
+        result.map(|_| self.scroll_lines_state.matches().len())
+    }
+
+    /// Clears the search and moves back to the origin.
+    pub fn cancel_search(&mut self) {
+        self.search_query.clear();
+        self.scroll_lines_state.set_matches(Vec::new(), None);
+        self.scroll_lines_state.set_v_offset(self.search_origin);
     }
+
+    /// Moves to the next (or previous) match, returns false if there is no match.
+    pub fn search_next(&mut self, forward: bool) -> bool {
+        let count = self.scroll_lines_state.matches().len();
+        let Some(current) = self.scroll_lines_state.current_match() else {
+            return false;
+        };
+        let next = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.scroll_lines_state.set_current_match(Some(next));
+        self.reveal_current_match();
+        true
+    }
+
+    fn reveal_current_match(&mut self) {
+        let current = self
+            .scroll_lines_state
+            .current_match()
+            .and_then(|i| self.scroll_lines_state.matches().get(i))
+            .cloned();
+        if let Some(m) = current {
+            self.scroll_lines_state.reveal(m.line, m.range);
+        }
+    }
+
+    // finds the matches in the current lines, and selects the first match after `from_line`
+    fn update_search_matches(&mut self, from_line: usize) -> Result<(), AppError> {
+        if self.search_query.is_empty() {
+            self.scroll_lines_state.set_matches(Vec::new(), None);
+            return Ok(());
+        }
+        let regex = match build_search_regex(&self.search_query, self.search_options) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.scroll_lines_state.set_matches(Vec::new(), None);
+                return Err(e);
+            }
+        };
+        let lines = self.scroll_lines_state.lines().iter().map(line_content);
+        let matches = find_matches(lines, &regex);
+        let current = matches
+            .iter()
+            .position(|m| m.line >= from_line)
+            .or((!matches.is_empty()).then_some(0));
+        self.scroll_lines_state.set_matches(matches, current);
+        Ok(())
+    }
+
+    fn to_preview_string(&self, bytes: &[u8]) -> String {
+        decode_text(bytes, self.encoding)
+    }
+}
+
+/// Decodes the bytes as the text to preview.
+pub fn decode_text(bytes: &[u8], encoding: EncodingType) -> String {
+    let encoding: &encoding_rs::Encoding = encoding.into();
+    let (s, _, _) = encoding.decode(bytes);
+    // tab is not rendered correctly, so replace it
+    let s = s.replace('\t', "    ");
+    if s.ends_with('\n') {
+        s.trim_end().into()
+    } else {
+        s
+    }
+}
+
+pub fn guess_encoding(bytes: &[u8]) -> Option<EncodingType> {
+    let mut detector = EncodingDetector::new();
+    detector.feed(
+        &bytes[..min(ENCODING_GUESS_BYTES_SIZE_LIMIT, bytes.len())],
+        true,
+    );
+    let (encoding, ok) = detector.guess_assess(None, true);
+    ok.then(|| EncodingType::from(encoding.name()).unwrap())
+}
+
+/// Returns true if the beginning of the bytes contains NUL bytes or invalid sequences in the encoding.
+pub fn is_binary(bytes: &[u8], encoding: EncodingType) -> bool {
+    let bytes = &bytes[..min(ENCODING_GUESS_BYTES_SIZE_LIMIT, bytes.len())];
+    // NUL bytes are common in UTF-16 text
+    let utf16 = matches!(encoding, EncodingType::Utf16Be | EncodingType::Utf16Le);
+    if !utf16 && bytes.contains(&0) {
+        return true;
+    }
+    let encoding: &encoding_rs::Encoding = encoding.into();
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let Some(capacity) = decoder.max_utf8_buffer_length_without_replacement(bytes.len()) else {
+        return false;
+    };
+    let mut s = String::with_capacity(capacity);
+    // the bytes may be cut in the middle of a character, so they are not decoded as the last part
+    let (result, _) = decoder.decode_to_string_without_replacement(bytes, &mut s, false);
+    matches!(result, encoding_rs::DecoderResult::Malformed(..))
 }
 
 fn drop_control_chars(s: &str) -> String {
@@ -501,6 +850,7 @@ fn build_highlighted_lines(
 pub struct TextPreview<'a> {
     file_name: &'a str,
     file_version_id: Option<&'a str>,
+    compression_info: Option<String>,
 
     theme: &'a ColorTheme,
 }
@@ -514,16 +864,22 @@ impl<'a> TextPreview<'a> {
         Self {
             file_name,
             file_version_id,
+            compression_info: None,
             theme,
         }
     }
+
+    pub fn compression_info(mut self, compression_info: Option<String>) -> Self {
+        self.compression_info = compression_info;
+        self
+    }
 }
 
 impl StatefulWidget for TextPreview<'_> {
     type State = TextPreviewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let title = if let Some(version_id) = self.file_version_id {
+        let mut title = if let Some(version_id) = self.file_version_id {
             format!(
                 "Preview [{} (Version ID: {})]",
                 self.file_name,
@@ -532,8 +888,26 @@ impl StatefulWidget for TextPreview<'_> {
         } else {
             format!("Preview [{}]", self.file_name)
         };
+        if let Some(info) = self.compression_info {
+            title.push_str(&format!(" ({info})"));
+        }
+        if state.pretty {
+            title.push_str(" (formatted)");
+        }
+        let mut block = Block::bordered().title(title);
+        if !state.search_query.is_empty() {
+            let count = state.scroll_lines_state.matches().len();
+            let counter = match state.scroll_lines_state.current_match() {
+                Some(current) => {
+                    let digits = digits(count);
+                    format!(" {:>digits$} / {} ", current + 1, count)
+                }
+                None => " No matches ".to_string(),
+            };
+            block = block.title(Line::from(counter).right_aligned());
+        }
         ScrollLines::default()
-            .block(Block::bordered().title(title))
+            .block(block)
             .theme(self.theme)
             .render(area, buf, &mut state.scroll_lines_state);
     }